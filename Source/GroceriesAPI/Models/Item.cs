@@ -13,6 +13,8 @@ namespace GroceriesApi.Models
 
         public decimal Value { get; set; }
 
+        public ItemCategory Category { get; set; }
+
         public Item()
         {
             
@@ -0,0 +1,11 @@
+namespace GroceriesApi.Models
+{
+    public enum ItemCategory
+    {
+        Other,
+        Produce,
+        Dairy,
+        Bakery,
+        Pantry
+    }
+}
@@ -0,0 +1,10 @@
+namespace GroceriesApi.Models
+{
+    public class ItemNotFoundException : Exception
+    {
+        public ItemNotFoundException(string message) : base(message)
+        {
+
+        }
+    }
+}
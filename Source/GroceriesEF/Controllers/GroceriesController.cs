@@ -0,0 +1,58 @@
+using Microsoft.AspNetCore.Mvc;
+using GroceriesApi.Models;
+using GroceriesApi.Repositories;
+
+namespace GroceriesApi.Controllers{
+
+    [ApiController]
+    [Route("v1/[controller]")]
+    public class GroceriesController : ControllerBase
+    {
+        private readonly IGroceriesRepository _repository;
+
+        public GroceriesController(IGroceriesRepository repository)
+        {
+            _repository = repository;
+        }
+
+        [HttpGet]
+        public async Task<IActionResult> Get()
+        {
+            var items = await _repository.GetItemsAsync();
+
+            return new OkObjectResult(items);
+        }
+
+        [HttpGet("category")]
+        public async Task<IActionResult> GetByCategory(ItemCategory category)
+        {
+            var items = await _repository.GetItemsByCategoryAsync(category);
+
+            return new OkObjectResult(items);
+        }
+
+        [HttpPut]
+        public async Task<IActionResult> Put(Item item)
+        {
+            await _repository.UpdateItemAsync(item);
+
+            return new OkResult();
+        }
+
+        [HttpPost]
+        public async Task<IActionResult> Post(Item item)
+        {
+            await _repository.AddItemAsync(item);
+
+            return new OkResult();
+        }
+
+        [HttpDelete]
+        public async Task<IActionResult> Delete(int id)
+        {
+            await _repository.DeleteAsync(id);
+
+            return new NoContentResult();
+        }
+    }
+}
@@ -0,0 +1,14 @@
+using Microsoft.EntityFrameworkCore;
+
+namespace GroceriesApi.Models
+{
+    public class GroceriesContext : DbContext
+    {
+        public DbSet<Item> Items { get; set; }
+
+        public GroceriesContext(DbContextOptions<GroceriesContext> options) : base(options)
+        {
+            
+        }
+    }
+}
@@ -0,0 +1,22 @@
+using Microsoft.EntityFrameworkCore;
+using GroceriesApi.Models;
+using GroceriesApi.Repositories;
+
+var builder = WebApplication.CreateBuilder(args);
+
+builder.Services.AddDbContext<GroceriesContext>(options => options.UseSqlite("Data Source=groceries.db"));
+
+builder.Services.AddScoped<IGroceriesRepository, GroceriesRepository>();
+
+builder.Services.AddControllers();
+
+var app = builder.Build();
+
+using (var scope = app.Services.CreateScope())
+{
+    scope.ServiceProvider.GetRequiredService<GroceriesContext>().Database.EnsureCreated();
+}
+
+app.MapControllers();
+
+app.Run();
@@ -0,0 +1,44 @@
+using GroceriesApi.Models;
+using Microsoft.EntityFrameworkCore;
+
+namespace GroceriesApi.Repositories
+{
+    public class GroceriesRepository : IGroceriesRepository
+    {
+        private readonly GroceriesContext _context;
+
+        public GroceriesRepository(GroceriesContext context)
+        {
+            _context = context;
+        }
+
+        public async Task<IEnumerable<Item>> GetItemsAsync()
+        {
+            return await _context.Items.ToListAsync();
+        }
+
+        public async Task<IEnumerable<Item>> GetItemsByCategoryAsync(ItemCategory category)
+        {
+            return await _context.Items.Where(i => i.Category == category).ToListAsync();
+        }
+
+        public async Task AddItemAsync(Item item)
+        {
+            _context.Items.Add(item);
+
+            await _context.SaveChangesAsync();
+        }
+
+        public async Task UpdateItemAsync(Item item)
+        {
+            _context.Items.Update(item);
+
+            await _context.SaveChangesAsync();
+        }
+
+        public async Task DeleteAsync(int id)
+        {
+            await _context.Items.Where(i => i.Id == id).ExecuteDeleteAsync();
+        }
+    }
+}
@@ -0,0 +1,17 @@
+using GroceriesApi.Models;
+
+namespace GroceriesApi.Repositories
+{
+    public interface IGroceriesRepository
+    {
+        Task<IEnumerable<Item>> GetItemsAsync();
+
+        Task<IEnumerable<Item>> GetItemsByCategoryAsync(ItemCategory category);
+
+        Task AddItemAsync(Item item);
+
+        Task UpdateItemAsync(Item item);
+
+        Task DeleteAsync(int id);
+    }
+}
@@ -2,70 +2,287 @@ use warp::{http, Filter};
 use serde::{Serialize, Deserialize};
 use parking_lot::RwLock;
 use std::sync::Arc;
-
+use thiserror::Error;
+use serde_repr::{Serialize_repr, Deserialize_repr};
+use std::sync::LazyLock;
+use std::sync::atomic::AtomicI32;
+use std::sync::atomic::Ordering;
+pub trait Entity {
+    type Key: PartialEq;
+    const NAME: &'static str;
+    const KEY_NAME: &'static str;
+    fn key(&self) -> Self::Key;
+}
+pub trait EntityStore<T: Entity> {
+    fn find(&self, key: &T::Key) -> Result<Option<T>, Error>;
+    fn insert(&self, entity: T) -> Result<(), Error>;
+    fn update(&self, entity: T) -> Result<bool, Error>;
+    fn remove(&self, key: &T::Key) -> Result<bool, Error>;
+}
+impl<T: Entity + Clone> EntityStore<T> for RwLock<Vec<T>> {
+    fn find(&self, key: &T::Key) -> Result<Option<T>, Error> {
+        Ok(self.read().iter().find(|a| a.key() == *key).cloned())
+    }
+    fn insert(&self, entity: T) -> Result<(), Error> {
+        let mut entities = self.write();
+        if entities.iter().any(|a| a.key() == entity.key()) {
+            return Err(
+                Error::DuplicateKey(
+                    format!(
+                        "The instance of entity type '{}' cannot be tracked because another instance with the same key value for {{'{}'}} is already being tracked.",
+                        T::NAME, T::KEY_NAME
+                    ),
+                ),
+            );
+        }
+        entities.push(entity);
+        Ok(())
+    }
+    fn update(&self, entity: T) -> Result<bool, Error> {
+        let mut entities = self.write();
+        match entities.iter_mut().find(|a| a.key() == entity.key()) {
+            Some(x) => {
+                *x = entity;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+    fn remove(&self, key: &T::Key) -> Result<bool, Error> {
+        let mut entities = self.write();
+        let count = entities.len();
+        entities.retain(|a| a.key() != *key);
+        Ok(entities.len() != count)
+    }
+}
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{0}")]
+    Argument(String),
+    #[error("{0}")]
+    ItemNotFound(String),
+    #[error("{0}")]
+    DuplicateKey(String),
+}
+impl warp::reject::Reject for Error {}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize_repr,
+    Deserialize_repr
+)]
+#[repr(i32)]
+pub enum ItemCategory {
+    #[default]
+    Other = 0,
+    Produce = 1,
+    Dairy = 2,
+    Bakery = 3,
+    Pantry = 4,
+}
+impl std::fmt::Display for ItemCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ItemCategory::Other => write!(f, "Other"),
+            ItemCategory::Produce => write!(f, "Produce"),
+            ItemCategory::Dairy => write!(f, "Dairy"),
+            ItemCategory::Bakery => write!(f, "Bakery"),
+            ItemCategory::Pantry => write!(f, "Pantry"),
+        }
+    }
+}
+impl std::str::FromStr for ItemCategory {
+    type Err = Error;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "Other" | "0" => Ok(ItemCategory::Other),
+            "Produce" | "1" => Ok(ItemCategory::Produce),
+            "Dairy" | "2" => Ok(ItemCategory::Dairy),
+            "Bakery" | "3" => Ok(ItemCategory::Bakery),
+            "Pantry" | "4" => Ok(ItemCategory::Pantry),
+            _ => {
+                Err(
+                    Error::Argument(
+                        format!("Requested value '{}' was not found.", value),
+                    ),
+                )
+            }
+        }
+    }
+}
 #[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct DbContext
-{
-   pub items: Arc<RwLock<Vec<Item>>>,
-}
-
-impl DbContext {
-	fn new0() -> Self {
-		DbContext {
-			items: Arc::new(RwLock::new(Vec::new())), 
-		}
-	}
-}
-
-
+pub struct DbContext {
+    pub items: Arc<RwLock<Vec<Item>>>,
+}
+impl Default for DbContext {
+    fn default() -> Self {
+        DbContext {
+            items: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+}
 #[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct Item
-{
-   pub id: i32,
-   pub name: String,
-   pub quantity: i32,
-   pub value: f64,
-}
-
-impl Item {
-	fn new0() -> Self {
-		Item {
-			id: 0, 
-			name: String::from(""), 
-			quantity: 0, 
-			value: 0.0, 
-		}
-	}
-}
-
-async fn get (_context: DbContext) -> Result<impl warp::Reply, warp::Rejection> {
-	let mut result = Vec::new();
-
-                        let r = _context.items.read();
-                        for value in r.iter() {
-                        result.push(value);
-                        }
-
-	Ok(warp::reply::json(&result))
-}
-
-
+pub struct Item {
+    pub id: i32,
+    pub name: String,
+    pub quantity: i32,
+    pub value: f64,
+    pub category: ItemCategory,
+}
+impl Default for Item {
+    fn default() -> Self {
+        Item {
+            id: 0,
+            name: String::from(""),
+            quantity: 0,
+            value: 0.0,
+            category: ItemCategory::default(),
+        }
+    }
+}
+impl Entity for Item {
+    type Key = i32;
+    const NAME: &'static str = "Item";
+    const KEY_NAME: &'static str = "Id";
+    fn key(&self) -> i32 {
+        self.id
+    }
+}
+static CONTEXT: LazyLock<DbContext> = LazyLock::new(|| DbContext::default());
+static IDENTIFIER: AtomicI32 = AtomicI32::new(0);
+pub struct GroceriesRepository;
+impl GroceriesRepository {
+    pub fn get_items_async() -> Result<Vec<Item>, Error> {
+        Ok(CONTEXT.items.read().clone())
+    }
+    pub fn add_item(mut item: Item) -> Result<(), Error> {
+        item.id = IDENTIFIER.fetch_add(1, Ordering::SeqCst);
+        CONTEXT.items.insert(item)?;
+        Ok(())
+    }
+    pub fn update_item(item: Item) -> Result<(), Error> {
+        CONTEXT.items.remove(&item.id)?;
+        CONTEXT.items.insert(item)?;
+        Ok(())
+    }
+    pub fn delete(id: i32) -> Result<(), Error> {
+        CONTEXT.items.remove(&id)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Deserialize)]
+pub struct DeleteQuery {
+    #[serde(rename = "Id")]
+    pub id: i32,
+}
+async fn get() -> Result<impl warp::Reply, warp::Rejection> {
+    let items = GroceriesRepository::get_items_async().map_err(warp::reject::custom)?;
+    Ok(warp::reply::json(&items))
+}
+async fn put(item: Item) -> Result<impl warp::Reply, warp::Rejection> {
+    GroceriesRepository::update_item(item).map_err(warp::reject::custom)?;
+    Ok(warp::reply::with_status(warp::reply(), http::StatusCode::OK))
+}
+async fn post(item: Item) -> Result<impl warp::Reply, warp::Rejection> {
+    GroceriesRepository::add_item(item).map_err(warp::reject::custom)?;
+    Ok(warp::reply::with_status(warp::reply(), http::StatusCode::OK))
+}
+async fn delete(
+    DeleteQuery { id }: DeleteQuery,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    GroceriesRepository::delete(id).map_err(warp::reject::custom)?;
+    Ok(warp::reply::with_status(warp::reply(), http::StatusCode::OK))
+}
+async fn handle_rejection(
+    err: warp::Rejection,
+) -> Result<impl warp::Reply, std::convert::Infallible> {
+    let binding_error = if let Some(error) = err
+        .find::<warp::filters::body::BodyDeserializeError>()
+    {
+        Some(("$", error.to_string()))
+    } else if let Some(error) = err.find::<warp::reject::InvalidQuery>() {
+        Some(("query", error.to_string()))
+    } else {
+        err.find::<warp::reject::MissingHeader>()
+            .map(|error| (error.name(), error.to_string()))
+    };
+    if let Some((key, message)) = binding_error {
+        let problem = serde_json::json!(
+            { "type" : "https://tools.ietf.org/html/rfc9110#section-15.5.1", "title" :
+            "One or more validation errors occurred.", "status" : 400, "errors" : { key :
+            [message] }, }
+        );
+        return Ok(
+            warp::reply::with_status(
+                warp::reply::json(&problem),
+                http::StatusCode::BAD_REQUEST,
+            ),
+        );
+    }
+    let (status, section, title, detail) = if let Some(error) = err.find::<Error>() {
+        (
+            http::StatusCode::INTERNAL_SERVER_ERROR,
+            "15.6.1",
+            "An error occurred while processing your request.",
+            error.to_string(),
+        )
+    } else if err.is_not_found() {
+        (http::StatusCode::NOT_FOUND, "15.5.5", "Not Found", String::new())
+    } else if err.find::<warp::reject::MethodNotAllowed>().is_some() {
+        (
+            http::StatusCode::METHOD_NOT_ALLOWED,
+            "15.5.6",
+            "Method Not Allowed",
+            String::new(),
+        )
+    } else {
+        (
+            http::StatusCode::INTERNAL_SERVER_ERROR,
+            "15.6.1",
+            "An error occurred while processing your request.",
+            String::new(),
+        )
+    };
+    let problem = serde_json::json!(
+        { "type" : format!("https://tools.ietf.org/html/rfc9110#section-{}", section),
+        "title" : title, "status" : status.as_u16(), "detail" : detail, }
+    );
+    Ok(warp::reply::with_status(warp::reply::json(&problem), status))
+}
 #[tokio::main]
 async fn main() {
-	let _context = DbContext::new0();
-	let _context_dbcontext = warp::any().map(move || _context.clone());
-
-	let get = warp::get()
-	.and(warp::path("v1"))
-	.and(warp::path("groceries"))
-	.and(warp::path("get"))
-	.and(warp::path::end())
-	.and(_context_dbcontext.clone())
-	.and_then(get);
-
-	let routes = get;
-
-	warp::serve(routes)
-		.run(([127, 0, 0, 1], 3030))
-		.await;
-}
\ No newline at end of file
+    let get = warp::path("v1")
+        .and(warp::path("groceries"))
+        .and(warp::path("get"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(get);
+    let put = warp::path("v1")
+        .and(warp::path("groceries"))
+        .and(warp::path("put"))
+        .and(warp::path::end())
+        .and(warp::put())
+        .and(warp::body::json::<Item>())
+        .and_then(put);
+    let post = warp::path("v1")
+        .and(warp::path("groceries"))
+        .and(warp::path("post"))
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::json::<Item>())
+        .and_then(post);
+    let delete = warp::path("v1")
+        .and(warp::path("groceries"))
+        .and(warp::path("delete"))
+        .and(warp::path::end())
+        .and(warp::delete())
+        .and(warp::query::<DeleteQuery>())
+        .and_then(delete);
+    let routes = get.or(put).or(post).or(delete).recover(handle_rejection);
+    warp::serve(routes).run(([127, 0, 0, 1], 3030)).await;
+}
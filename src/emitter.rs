@@ -0,0 +1,189 @@
+use syn::parse::Parser;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RustStruct {
+    pub attributes: Vec<String>,
+    pub is_public: bool,
+    pub name: String,
+    pub fields: Vec<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RustEnum {
+    pub attributes: Vec<String>,
+    pub is_public: bool,
+    pub name: String,
+    pub variants: Vec<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RustFunction {
+    pub attributes: Vec<String>,
+    pub signature: String,
+    pub statements: Vec<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RustImpl {
+    pub generics: String,
+    pub trait_name: Option<String>,
+    pub self_type: String,
+    pub items: Vec<String>
+}
+
+impl RustStruct {
+    pub fn new(name: &str) -> Self {
+        RustStruct { name: String::from(name), ..Default::default() }
+    }
+
+    pub fn add_attribute(&mut self, value: String) {
+        self.attributes.push(value);
+    }
+
+    pub fn add_field(&mut self, value: String) {
+        self.fields.push(value);
+    }
+
+    pub fn to_code(&self) -> String {
+
+        let mut code = String::from("\n");
+
+        for elem in self.attributes.iter() {
+            code.push_str(&format!("{}\n", elem));
+        }
+
+        let visibility = if self.is_public { "pub " } else { "" };
+
+        code.push_str(&format!("{}struct {}\n{{\n", visibility, self.name));
+
+        for elem in self.fields.iter() {
+            code.push_str(elem);
+        }
+
+        code.push_str("}\n");
+
+        code
+    }
+}
+
+impl RustEnum {
+    pub fn new(name: &str) -> Self {
+        RustEnum { name: String::from(name), is_public: true, ..Default::default() }
+    }
+
+    pub fn add_attribute(&mut self, value: String) {
+        self.attributes.push(value);
+    }
+
+    pub fn add_variant(&mut self, value: String) {
+        self.variants.push(value);
+    }
+
+    pub fn to_code(&self) -> String {
+
+        let mut code = String::from("\n");
+
+        for elem in self.attributes.iter() {
+            code.push_str(&format!("{}\n", elem));
+        }
+
+        let visibility = if self.is_public { "pub " } else { "" };
+
+        code.push_str(&format!("{}enum {}\n{{\n", visibility, self.name));
+
+        for elem in self.variants.iter() {
+            code.push_str(elem);
+        }
+
+        code.push_str("}\n");
+
+        code
+    }
+}
+
+impl RustImpl {
+    pub fn new(generics: &str, trait_name: Option<&str>, self_type: &str) -> Self {
+        RustImpl { generics: String::from(generics), trait_name: trait_name.map(String::from), self_type: String::from(self_type), items: Vec::new() }
+    }
+
+    pub fn add_item(&mut self, value: String) {
+        self.items.push(value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.iter().all(|a| a.trim().is_empty())
+    }
+
+    pub fn to_code(&self) -> String {
+
+        let target = match &self.trait_name {
+            Some(x) => format!("{} for {}", x, self.self_type),
+            None => self.self_type.clone()
+        };
+
+        format!("\nimpl{} {} {{{}}}\n", self.generics, target, self.items.concat())
+    }
+}
+
+impl RustFunction {
+    pub fn new(signature: &str) -> Self {
+        RustFunction { signature: String::from(signature), ..Default::default() }
+    }
+
+    pub fn add_attribute(&mut self, value: String) {
+        self.attributes.push(value);
+    }
+
+    pub fn add_statement(&mut self, value: String) {
+        self.statements.push(value);
+    }
+
+    pub fn to_item(&self) -> Result<syn::ItemFn, String> {
+
+        let sig: syn::Signature = syn::parse_str(&self.signature).map_err(|e| format!("generated signature is not valid Rust: {}\n    {}", e, self.signature.trim()))?;
+
+        let mut attrs: Vec<syn::Attribute> = Vec::new();
+
+        for elem in self.attributes.iter() {
+            attrs.extend(syn::Attribute::parse_outer.parse_str(elem).map_err(|e| get_fragment_error(&sig.ident, e, elem))?);
+        }
+
+        for elem in self.statements.iter() {
+            syn::Block::parse_within.parse_str(elem).map_err(|e| get_fragment_error(&sig.ident, e, elem))?;
+        }
+
+        let block: syn::Block = syn::parse_str(&format!("{{{}}}", self.statements.concat())).map_err(|e| get_fragment_error(&sig.ident, e, &self.statements.concat()))?;
+
+        Ok(syn::ItemFn { attrs, vis: syn::Visibility::Inherited, sig, block: Box::new(block) })
+    }
+
+    pub fn to_code(&self) -> Result<String, String> {
+
+        self.to_item()?;
+
+        let attributes: String = self.attributes.iter().map(|a| format!("{}\n", a)).collect();
+
+        Ok(format!("\n{}{} {{{}\n}}\n", attributes, self.signature, self.statements.concat()))
+    }
+}
+
+fn get_fragment_error(name: &syn::Ident, error: syn::Error, fragment: &str) -> String {
+
+    let line = fragment.lines().nth(error.span().start().line.saturating_sub(1)).unwrap_or_default();
+
+    format!("generated code for {} is not valid Rust: {}\n    {}", name, error, line.trim())
+}
+
+pub fn format_file(code: &str) -> Result<String, String> {
+
+    match syn::parse_file(code) {
+        Ok(x) => Ok(prettyplease::unparse(&x)),
+        Err(e) => {
+            let start = e.span().start();
+
+            let line = code.lines().nth(start.line.saturating_sub(1)).unwrap_or_default();
+
+            Err(format!("generated code is not valid Rust at {}:{}: {}\n    {}", start.line, start.column + 1, e, line.trim()))
+        }
+    }
+}
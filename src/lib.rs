@@ -0,0 +1,8558 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+use pest::Parser;
+use std::fs;
+use std::path::Path;
+use pest::iterators::{Pair, Pairs};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+mod emitter;
+pub mod migrations;
+pub mod naming;
+pub mod source_map;
+
+use emitter::{RustEnum, RustFunction, RustImpl, RustStruct};
+use naming::{NameKind, get_rust_name};
+
+#[derive(Parser)]
+#[grammar = "grammar.pest"]
+pub struct CSParser;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SourceFile {
+    pub path: String,
+    pub text: String
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct Project {
+    pub models: Vec<SourceFile>,
+    pub repositories: Vec<SourceFile>,
+    pub controllers: Vec<SourceFile>,
+    pub program: Option<SourceFile>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Options {
+    pub source_map: bool,
+    pub source_comments: bool,
+    pub storage: Storage
+}
+
+impl Options {
+    pub fn load(root: &str) -> Result<Self, String> {
+        let text = match fs::read_to_string(Path::new(root).join("csrust.json")) {
+            Ok(x) => x,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Options::default()),
+            Err(e) => return Err(format!("csrust.json: {}", e))
+        };
+
+        let config: ConfigFile = serde_json::from_str(&text).map_err(|e| format!("csrust.json: {}", e))?;
+
+        Ok(Options { storage: config.storage, ..Options::default() })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default)]
+    storage: Storage
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Storage {
+    #[default]
+    Memory,
+    #[serde(alias = "hashmap")]
+    Indexed,
+    Json,
+    Sqlite
+}
+
+impl Storage {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "memory" => Some(Storage::Memory),
+            "indexed" | "hashmap" => Some(Storage::Indexed),
+            "json" => Some(Storage::Json),
+            "sqlite" => Some(Storage::Sqlite),
+            _ => None
+        }
+    }
+
+    fn get_store_type(&self) -> Option<&'static str> {
+        match self {
+            Storage::Memory => None,
+            Storage::Indexed => Some("IndexedStore"),
+            Storage::Json => Some("JsonStore"),
+            Storage::Sqlite => Some("SqliteStore")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Output {
+    pub code: String,
+    pub source_map: Vec<source_map::Mapping>,
+    pub types: Vec<Type>,
+    pub classes: Vec<ClassMethod>,
+    pub warnings: Vec<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct Diagnostics {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>
+}
+
+impl SourceFile {
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        Ok(SourceFile { path: path.to_string_lossy().into_owned(), text: fs::read_to_string(path)? })
+    }
+}
+
+impl Project {
+    pub fn load(root: &str) -> std::io::Result<Self> {
+        let root = Path::new(root);
+
+        Ok(Project {
+            models: read_files(&root.join("Models"))?,
+            repositories: read_files(&root.join("Repositories"))?,
+            controllers: read_files(&root.join("Controllers"))?,
+            program: read_optional_file(&root.join("Program.cs"))?
+        })
+    }
+}
+
+struct Code {
+    usings: Vec<String>,
+    types: Vec<String>,
+    structs: Vec<String>,
+    methods: Vec<String>,
+    errors: Vec<String>,
+    warnings: Vec<String>,
+    validated_types: Vec<String>,
+    repository_types: Vec<String>,
+    static_paths: HashMap<String, String>,
+    extension_paths: HashMap<String, Vec<String>>,
+    database_contexts: Vec<String>,
+    database_tables: Vec<DatabaseTable>,
+    async_methods: Vec<String>,
+    fallible_methods: Vec<String>,
+    mutating_methods: Vec<String>,
+    is_string_enums: bool,
+    runtime_collisions: Vec<String>,
+    type_declarations: Vec<(String, String, String)>,
+    store_properties: Vec<String>,
+    storage: Storage,
+    entity_configurations: Vec<EntityConfiguration>,
+    counter_seeds: HashMap<String, String>,
+    member_values: HashMap<String, String>,
+    failures: Vec<String>,
+    source_file: String,
+    is_source_map: bool,
+    is_source_comments: bool
+}
+
+struct TypeTable {
+    types: HashMap<String, Type>,
+    scope: Scope,
+    class_name: String,
+    locals: Vec<Parameter>,
+    usages: Vec<LocalUsage>,
+    position: usize,
+    keys: HashMap<String, EntityKey>,
+    constraints: HashMap<String, ColumnConstraint>,
+    configurations: HashMap<String, EntityConfiguration>,
+    diagnostics: RefCell<Vec<String>>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Scope {
+    namespace: String,
+    usings: Vec<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Parameter {
+    pub name: String,
+    pub type_name: String
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum BindingSource {
+    Body,
+    Query,
+    Route,
+    Header,
+    Form,
+    Services
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Binding {
+    parameter: Parameter,
+    source: BindingSource,
+    name: String,
+    default_value: Option<String>,
+    is_optional: bool
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BodyContext<'a> {
+    Handler(&'a str, &'a ControllerAction),
+    Repository(&'a Type, &'a Method),
+    Method(&'a [Type], &'a Method)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ControllerAction {
+    method: Method,
+    http_method: String,
+    template: String,
+    bindings: Vec<Binding>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct EntityKey {
+    property: String,
+    type_name: String,
+    is_generated: bool
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DatabaseTable {
+    name: String,
+    entity: String,
+    scope: Scope
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct ColumnConstraint {
+    is_required: bool,
+    max_length: Option<usize>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct EntityConfiguration {
+    entity: String,
+    table: Option<String>,
+    key: Option<String>,
+    properties: Vec<(String, ColumnConstraint)>,
+    indexes: Vec<EntityIndex>,
+    relationships: Vec<Relationship>,
+    seeds: Vec<Vec<(String, String)>>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct EntityIndex {
+    properties: Vec<String>,
+    name: Option<String>,
+    is_unique: bool
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Relationship {
+    reference: Option<String>,
+    collection: Option<String>,
+    foreign_key: Option<String>,
+    is_required: bool,
+    is_principal: bool
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Extractors {
+    path: Vec<String>,
+    filters: Vec<String>,
+    parameters: Vec<String>,
+    defaults: Vec<String>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct LocalUsage {
+    name: String,
+    rust_name: String,
+    reads: Vec<usize>,
+    is_mutated: bool
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Kind {
+    Class,
+    Record,
+    Enum,
+    EnumMember,
+    TypeParameter,
+    Property,
+    Required,
+    Base,
+    Const,
+    Static,
+    Readonly,
+    Local,
+    Assigned,
+    Method,
+    Abstract,
+    Virtual,
+    Override,
+    Extension,
+    Constructor,
+    RecordConstructor,
+    InterfaceMethod,
+    Getter,
+    Setter,
+    ExpressionBody
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Method {
+    pub name: String,
+    pub return_type: String,
+    pub parameters: Vec<Parameter>,
+    pub type_parameters: Vec<Parameter>,
+    pub kind: Kind
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClassMethod {
+    pub class_name: String,
+    pub methods: Vec<Method>,
+    pub constructors: Vec<Method>,
+    pub bases: Vec<String>,
+    pub type_parameters: Vec<Parameter>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ClassMethods {
+    class_methods: HashMap<String, ClassMethod>,
+    interfaces: HashMap<String, ClassMethod>,
+    records: HashMap<String, Type>,
+    extensions: HashMap<String, Vec<Method>>,
+    scope: Scope,
+    diagnostics: RefCell<Vec<String>>
+}
+
+impl ClassMethods {
+    fn new() -> Self {
+        ClassMethods {
+            class_methods: HashMap::new(),
+            interfaces: HashMap::new(),
+            records: HashMap::new(),
+            extensions: HashMap::new(),
+            scope: Scope::default(),
+            diagnostics: RefCell::new(Vec::new())
+        }
+    }
+
+    fn add_record(&mut self, record: Type) {
+        self.records.insert(self.scope.get_qualified_name(&record.name), record);
+    }
+
+    fn find_record(&self, name: &str) -> Option<&Type> {
+        let key = self.scope.resolve(&self.records, name, &self.diagnostics)?;
+
+        self.records.get(&key)
+    }
+
+    fn add_extension(&mut self, method: Method) {
+        if let Some(x) = method.parameters.first() {
+            self.extensions.entry(String::from(get_simple_type_name(get_generic_type_name(&x.type_name)))).or_default().push(method);
+        }
+    }
+
+    fn find_extension(&self, type_name: &str, method_name: &str) -> Option<&Method> {
+        self.extensions.get(get_simple_type_name(get_generic_type_name(type_name)))?.iter().find(|a| a.name == method_name)
+    }
+
+    fn add_interface(&mut self, interface: ClassMethod) {
+        self.interfaces.insert(self.scope.get_qualified_name(&interface.class_name), interface);
+    }
+
+    fn find_interface(&self, name: &str) -> Option<&ClassMethod> {
+        let key = self.scope.resolve(&self.interfaces, name, &self.diagnostics)?;
+
+        self.interfaces.get(&key)
+    }
+
+    fn add_class_method(&mut self, method: ClassMethod) {
+        self.class_methods.insert(self.scope.get_qualified_name(&method.class_name), method);
+    }
+
+    fn find_class_method(&self, class_name: &str) -> Option<&ClassMethod> {
+        let key = self.scope.resolve(&self.class_methods, class_name, &self.diagnostics)?;
+
+        self.class_methods.get(&key)
+    }
+
+    fn find_implementation(&self, interface_name: &str) -> Option<&ClassMethod> {
+        let mut implementations: Vec<&ClassMethod> = self.class_methods.values()
+            .filter(|a| a.bases.iter().any(|b| get_simple_type_name(get_generic_type_name(b)) == get_simple_type_name(interface_name))).collect();
+
+        implementations.sort_by(|a, b| a.class_name.cmp(&b.class_name));
+
+        implementations.first().copied()
+    }
+
+    fn get_class_method(&self, class_name: &str) -> Result<ClassMethod, String> {
+        match self.find_class_method(class_name).or_else(|| self.find_implementation(class_name)) {
+            Some(x) => Ok(x.clone()),
+            None => Err(format!("class {} not found", class_name))
+        }
+    }
+
+    fn get_implementation_name(&self, class_name: &str) -> String {
+        match self.find_class_method(class_name).or_else(|| self.find_implementation(class_name)) {
+            Some(x) => x.class_name.clone(),
+            None => String::from(class_name)
+        }
+    }
+
+    fn get_base_class(&self, class_name: &str) -> Option<String> {
+        let base = self.find_class_method(class_name)?.bases.first()?;
+
+        self.find_class_method(get_generic_type_name(base)).map(|a| a.class_name.clone())
+    }
+
+    fn get_base_type(&self, class_name: &str) -> Option<String> {
+        let base = self.find_class_method(class_name)?.bases.first()?;
+
+        self.find_class_method(get_generic_type_name(base)).map(|_| get_rust_type_name(base))
+    }
+
+    fn is_ambiguous_name(&self, class_name: &str) -> bool {
+        self.class_methods.values().filter(|a| a.class_name == class_name).count() > 1
+    }
+}
+
+impl Scope {
+    fn get_qualified_name(&self, name: &str) -> String {
+        if self.namespace.is_empty() {
+            return String::from(name.trim());
+        }
+
+        format!("{}.{}", self.namespace, name.trim())
+    }
+
+    fn get_nested_scope(&self, type_name: &str) -> Scope {
+        Scope { namespace: self.get_qualified_name(type_name), usings: self.usings.clone() }
+    }
+
+    fn resolve<T>(&self, symbols: &HashMap<String, T>, name: &str, diagnostics: &RefCell<Vec<String>>) -> Option<String> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut groups: Vec<Vec<String>> = Vec::new();
+
+        let mut namespace = self.namespace.as_str();
+
+        while !namespace.is_empty() {
+            groups.push(vec![format!("{}.{}", namespace, name)]);
+            namespace = namespace.rfind('.').map(|a| &namespace[..a]).unwrap_or("");
+        }
+
+        groups.push(self.usings.iter().map(|a| format!("{}.{}", a, name)).collect());
+        groups.push(vec![String::from(name)]);
+
+        for group in groups {
+            let mut found: Vec<String> = group.into_iter().filter(|a| symbols.contains_key(a)).collect();
+
+            found.dedup();
+
+            if found.len() > 1 {
+                add_ambiguity_diagnostic(diagnostics, name, &found);
+            }
+
+            if let Some(x) = found.into_iter().next() {
+                return Some(x);
+            }
+        }
+
+        let suffix = format!(".{}", name);
+
+        let mut found: Vec<&String> = symbols.keys().filter(|a| a.ends_with(&suffix)).collect();
+
+        found.sort();
+
+        if found.len() > 1 {
+            add_ambiguity_diagnostic(diagnostics, name, &found);
+        }
+
+        found.first().map(|a| String::from(a.as_str()))
+    }
+}
+
+fn add_ambiguity_diagnostic<T: AsRef<str>>(diagnostics: &RefCell<Vec<String>>, name: &str, candidates: &[T]) {
+
+    let candidates: Vec<&str> = candidates.iter().map(|a| a.as_ref()).collect();
+
+    let diagnostic = format!("'{}' is an ambiguous reference between '{}', using '{}'", name, candidates.join("' and '"), candidates[0]);
+
+    let mut diagnostics = diagnostics.borrow_mut();
+
+    if !diagnostics.contains(&diagnostic) {
+        diagnostics.push(diagnostic);
+    }
+}
+
+fn get_simple_type_name(value: &str) -> &str {
+    value.rsplit('.').next().unwrap_or("").trim()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Type {
+    pub name: String,
+    pub type_name: String,
+    pub kind: Kind,
+    pub properties: Vec<Type>
+}
+
+impl TypeTable {
+    fn new() -> Self {
+        TypeTable {
+            types: HashMap::new(),
+            scope: Scope::default(),
+            class_name: String::from(""),
+            locals: Vec::new(),
+            usages: Vec::new(),
+            position: 0,
+            keys: HashMap::new(),
+            constraints: HashMap::new(),
+            configurations: HashMap::new(),
+            diagnostics: RefCell::new(Vec::new())
+        }
+    }
+
+    fn enter_class(&mut self, class_name: &str) {
+        self.class_name = String::from(class_name.trim());
+        self.locals.clear();
+        self.usages.clear();
+    }
+
+    fn enter_method(&mut self, parameters: &[Parameter]) {
+        self.locals = parameters.to_vec();
+        self.usages.clear();
+    }
+
+    fn enter_body(&mut self, iter: &Pair<Rule>, parameters: &[Parameter], mutating_methods: &[String]) {
+        self.enter_method(parameters);
+        self.usages = get_local_usages(iter, parameters, mutating_methods);
+    }
+
+    fn find_usage(&self, name: &str) -> Option<&LocalUsage> {
+        self.usages.iter().rev().find(|a| a.name == name)
+    }
+
+    fn is_read_after(&self, name: &str) -> bool {
+        self.find_usage(name).is_some_and(|a| a.reads.iter().any(|b| *b >= self.position))
+    }
+
+    fn add_local(&mut self, name: &str, type_name: &str) {
+        self.locals.push(Parameter { name: String::from(name.trim()), type_name: String::from(type_name.trim()) });
+    }
+
+    fn find_local(&self, name: &str) -> Option<&Parameter> {
+        self.locals.iter().rev().find(|a| a.name == name)
+    }
+
+    fn add_type(&mut self, t: Type) {
+        self.types.insert(self.scope.get_qualified_name(&t.name), t);
+    }
+
+    fn get_type_table(&self, name: &str) -> Result<Type, String> {
+        match self.find_type(name) {
+            Some(x) => Ok(x.clone()),
+            None => Err(format!("type {} not found", name))
+        }
+    }
+
+    fn find_type(&self, name: &str) -> Option<&Type> {
+        let key = self.scope.resolve(&self.types, name, &self.diagnostics)?;
+
+        self.types.get(&key)
+    }
+
+    fn add_key(&mut self, name: &str, key: EntityKey) {
+        self.keys.insert(self.scope.get_qualified_name(name), key);
+    }
+
+    fn find_key(&self, name: &str) -> Option<&EntityKey> {
+        let key = self.scope.resolve(&self.keys, name, &self.diagnostics)?;
+
+        self.keys.get(&key)
+    }
+
+    fn add_constraint(&mut self, name: &str, property: &str, constraint: ColumnConstraint) {
+        self.constraints.insert(format!("{}.{}", self.scope.get_qualified_name(name), property), constraint);
+    }
+
+    fn find_constraint(&self, name: &str, property: &str) -> Option<&ColumnConstraint> {
+        let key = self.scope.resolve(&self.types, name, &self.diagnostics)?;
+
+        self.constraints.get(&format!("{}.{}", key, property))
+    }
+
+    fn add_configuration(&mut self, name: &str, configuration: EntityConfiguration) {
+        self.configurations.insert(self.scope.get_qualified_name(name), configuration);
+    }
+
+    fn find_configuration(&self, name: &str) -> Option<&EntityConfiguration> {
+        let key = self.scope.resolve(&self.configurations, name, &self.diagnostics)?;
+
+        self.configurations.get(&key)
+    }
+
+    fn is_enum_type(&self, name: &str) -> bool {
+        self.find_type(name).is_some_and(|a| a.kind == Kind::Enum)
+    }
+
+    fn get_sorted_types(&self) -> Vec<&Type> {
+        let mut keys: Vec<&String> = self.types.keys().collect();
+
+        keys.sort();
+
+        keys.into_iter().map(|a| &self.types[a]).collect()
+    }
+}
+
+impl Type {
+    fn new() -> Self {
+        Type {
+            name: String::from(""),
+            type_name: String::from(""),
+            kind: Kind::Property,
+            properties: Vec::new()
+        }
+    }
+
+    fn get_type_property(&self, name: &str) -> Result<Type, String> {
+        match self.properties.iter().find(|a| a.name == name) {
+            Some(x) => Ok(x.clone()),
+            None => Err(format!("property {} not found in {}", name, self.name))
+        }
+    }
+
+    fn get_type_properties(&self) -> Vec<Type> {
+        self.properties.clone()
+    }
+}
+
+impl BodyContext<'_> {
+    fn get_method_name(&self) -> &str {
+        match self {
+            BodyContext::Handler(_, x) => &x.method.name,
+            BodyContext::Repository(_, x) | BodyContext::Method(_, x) => &x.name
+        }
+    }
+}
+
+impl Code {
+    fn new() -> Self {
+        Code {
+            usings: Vec::new(),
+            types: Vec::new(),
+            structs: Vec::new(),
+            methods: Vec::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            validated_types: Vec::new(),
+            repository_types: Vec::new(),
+            static_paths: HashMap::new(),
+            extension_paths: HashMap::new(),
+            database_contexts: Vec::new(),
+            database_tables: Vec::new(),
+            async_methods: Vec::new(),
+            fallible_methods: Vec::new(),
+            mutating_methods: Vec::new(),
+            is_string_enums: false,
+            runtime_collisions: Vec::new(),
+            type_declarations: Vec::new(),
+            store_properties: Vec::new(),
+            storage: Storage::Memory,
+            entity_configurations: Vec::new(),
+            counter_seeds: HashMap::new(),
+            member_values: HashMap::new(),
+            failures: Vec::new(),
+            source_file: String::from(""),
+            is_source_map: false,
+            is_source_comments: false
+        }
+    }
+
+    fn new_nested(&self) -> Self {
+        Code { static_paths: self.static_paths.clone(), extension_paths: self.extension_paths.clone(), database_contexts: self.database_contexts.clone(), async_methods: self.async_methods.clone(), fallible_methods: self.fallible_methods.clone(), mutating_methods: self.mutating_methods.clone(), is_string_enums: self.is_string_enums, runtime_collisions: self.runtime_collisions.clone(), store_properties: self.store_properties.clone(), storage: self.storage, entity_configurations: self.entity_configurations.clone(), source_file: self.source_file.clone(), is_source_map: self.is_source_map, is_source_comments: self.is_source_comments, ..Code::new() }
+    }
+
+    fn is_fallible_method(&self, class_name: &str, method_name: &str) -> bool {
+        self.fallible_methods.contains(&format!("{}.{}", get_generic_type_name(class_name), method_name))
+    }
+
+    fn is_mutating_method(&self, class_name: &str, method_name: &str) -> bool {
+        self.mutating_methods.contains(&format!("{}.{}", get_generic_type_name(class_name), method_name))
+    }
+
+    fn get_source_marker(&self, iter: &Pair<Rule>) -> String {
+
+        if !self.is_source_map && !self.is_source_comments {
+            return String::from("");
+        }
+
+        let (line, column) = iter.as_span().start_pos().line_col();
+
+        source_map::get_marker(&self.source_file, line, column)
+    }
+
+    fn add_using(&mut self, value: String){
+        if !self.usings.contains(&value) {
+            self.usings.push(value);
+        }
+    }
+
+    fn add_type(&mut self, value: &str){
+        self.types.push(String::from(value));
+    }
+
+    fn add_struct(&mut self, value: &str){
+        self.structs.push(String::from(value));
+    }
+
+    fn add_method(&mut self, value: String){
+        self.methods.push(value);
+    }
+
+    fn add_error(&mut self, value: String){
+        if !self.errors.contains(&value) {
+            self.errors.push(value);
+        }
+    }
+
+    fn add_warning(&mut self, value: String){
+        if !self.warnings.contains(&value) {
+            self.warnings.push(value);
+        }
+    }
+
+    fn add_failure(&mut self, value: String){
+        let value = format!("{}: {}", self.source_file, value);
+
+        if !self.failures.contains(&value) {
+            self.failures.push(value);
+        }
+    }
+
+    fn add_validated_type(&mut self, value: &str){
+        if !self.validated_types.iter().any(|a| a == value) {
+            self.validated_types.push(String::from(value));
+        }
+    }
+
+    fn is_validated_type(&self, value: &str) -> bool {
+        self.validated_types.iter().any(|a| a == get_simple_type_name(get_generic_type_name(value)))
+    }
+
+    fn is_database(&self) -> bool {
+        !self.database_contexts.is_empty() || self.storage == Storage::Sqlite
+    }
+
+    fn get_store_type(&self, class_name: &str, property: &str) -> Option<String> {
+        let name = format!("{}.{}", get_simple_type_name(get_generic_type_name(class_name)), property);
+
+        self.storage.get_store_type().filter(|_| self.store_properties.contains(&name)).map(|a| self.get_runtime_name(a))
+    }
+
+    fn get_runtime_name(&self, name: &str) -> String {
+        if self.runtime_collisions.iter().any(|a| a == name) {
+            format!("{}::{}", RUNTIME_MODULE, name)
+        }
+        else {
+            String::from(name)
+        }
+    }
+
+    fn get_runtime_visibility(&self) -> &'static str {
+        if self.runtime_collisions.is_empty() { "" } else { "pub(crate) " }
+    }
+
+    fn find_entity_configuration(&self, class_name: &str) -> Option<&EntityConfiguration> {
+        self.entity_configurations.iter().find(|a| a.entity == get_simple_type_name(get_generic_type_name(class_name)))
+    }
+
+    fn is_navigation_property(&self, class_name: &str, type_name: &str) -> bool {
+        let element_type = if is_list_type(type_name) { get_list_element_type(type_name) } else { String::from(type_name.trim_end_matches('?')) };
+
+        self.find_entity_configuration(class_name).is_some() && self.find_entity_configuration(&element_type).is_some()
+    }
+
+    fn get_list_value(&self, class_name: &str, property: &Type, value: &str) -> String {
+        if self.is_navigation_property(class_name, &property.type_name) {
+            return String::from(value);
+        }
+
+        match self.get_store_type(class_name, &property.name) {
+            Some(x) => format!("Arc::new({}::from({}))", x, value),
+            None => format!("Arc::new(RwLock::new({}))", value)
+        }
+    }
+
+    fn add_collection_usings(&mut self){
+        for name in ["HashMap", "HashSet", "BTreeMap"] {
+            if self.structs.iter().chain(self.methods.iter()).any(|a| a.contains(name)) {
+                self.add_using(format!("use std::collections::{};\n", name));
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Step {
+    Models,
+    Repositories,
+    Controllers
+}
+
+pub fn transpile(project: &Project, options: &Options) -> Result<Output, Diagnostics> {
+
+    let mut code = Code::new();
+
+    code.is_source_map = options.source_map;
+
+    code.is_source_comments = options.source_comments;
+
+    code.storage = options.storage;
+
+    code.is_string_enums = project.program.as_ref().is_some_and(|a| a.text.contains("JsonStringEnumConverter"));
+
+    let mut type_table = TypeTable::new();
+
+    let mut class_methods = ClassMethods::new();
+
+    code.add_using(String::from("use warp::{http, Filter};\n"));
+    code.add_using(String::from("use serde::{Serialize, Deserialize};\n"));
+    code.add_using(String::from("use parking_lot::RwLock;\n"));
+    code.add_using(String::from("use std::sync::Arc;\n"));
+    code.add_using(String::from("use thiserror::Error;\n"));
+
+    let parsed = parse_models_contents(&project.models, &mut code, &Step::Models, &mut type_table, &mut class_methods)
+        .and_then(|_| parse_files_contents(&project.repositories, Rule::parse_models_contents, &mut code, &Step::Repositories, &mut type_table, &mut class_methods))
+        .and_then(|_| parse_files_contents(&project.controllers, Rule::parse_controller_contents, &mut code, &Step::Controllers, &mut type_table, &mut class_methods));
+
+    if let Err(e) = parsed {
+        return Err(Diagnostics { errors: vec![e], warnings: code.warnings });
+    }
+
+    if !code.failures.is_empty() {
+        return Err(Diagnostics { errors: code.failures, warnings: code.warnings });
+    }
+
+    add_entity_store_code(&mut code, &type_table);
+
+    add_database_code(&mut code, &type_table);
+
+    add_storage_code(&mut code, &type_table);
+
+    match_error_enum_code(&mut code);
+
+    add_validation_code(&mut code);
+
+    for elem in type_table.diagnostics.take().into_iter().chain(class_methods.diagnostics.take()) {
+        code.add_warning(elem);
+    }
+
+    code.add_collection_usings();
+
+    get_output(code, &type_table, &class_methods)
+}
+
+pub fn schema(project: &Project) -> Result<migrations::Schema, Diagnostics> {
+
+    let mut code = Code::new();
+
+    let mut type_table = TypeTable::new();
+
+    let mut class_methods = ClassMethods::new();
+
+    if let Err(e) = parse_models_contents(&project.models, &mut code, &Step::Models, &mut type_table, &mut class_methods) {
+        return Err(Diagnostics { errors: vec![e], warnings: code.warnings });
+    }
+
+    if !code.failures.is_empty() {
+        return Err(Diagnostics { errors: code.failures, warnings: code.warnings });
+    }
+
+    let tables = get_database_tables(&mut code, &type_table).into_iter().map(|a| a.0).collect();
+
+    Ok(migrations::Schema { tables, warnings: code.warnings })
+}
+
+fn parse_models_contents(files: &[SourceFile], code: &mut Code, step: &Step, types: &mut TypeTable, class_methods: &mut ClassMethods) -> Result<(), String> {
+
+    let mut contents: Vec<&SourceFile> = files.iter().collect();
+
+    contents.sort_by_key(|a| !a.text.contains(" enum "));
+
+    let texts: Vec<(String, String)> = contents.into_iter().map(|a| (a.path.clone(), a.text.replace("\u{feff}", ""))).collect();
+
+    let mut parses = Vec::new();
+
+    for (file_name, text) in texts.iter() {
+        let successful_parse = CSParser::parse(Rule::parse_models_contents, text).map_err(|e| format!("{}: {}", file_name, e))?;
+
+        let mut class_names: Vec<String> = Vec::new();
+
+        for pair in successful_parse.clone() {
+            class_names.append(&mut match_models_declarations_pairs(pair, class_methods));
+        }
+
+        for pair in successful_parse.clone().flatten().filter(|a| a.as_rule() == Rule::class_code) {
+            add_static_paths(&pair, code);
+            add_database_context(&pair, code);
+        }
+
+        parses.push((class_names, file_name, successful_parse));
+    }
+
+    let classes: Vec<Pair<Rule>> = parses.iter().flat_map(|a| a.2.clone().flatten().filter(|b| b.as_rule() == Rule::class_code)).collect();
+
+    for elem in parses.iter().flat_map(|a| a.2.clone().flatten()) {
+        add_runtime_collision(&elem, code);
+    }
+
+    for (_, file_name, successful_parse) in parses.iter() {
+        add_type_declarations(successful_parse, file_name, code);
+    }
+
+    add_store_properties(&classes, code);
+
+    add_method_effects(&classes, code, class_methods);
+
+    let mut depths: HashMap<String, usize> = HashMap::new();
+
+    for elem in parses.iter().flat_map(|a| a.0.iter()) {
+        depths.insert(elem.clone(), get_inheritance_depth(elem, class_methods)?);
+    }
+
+    parses.sort_by_key(|a| a.0.iter().map(|b| depths[b]).max().unwrap_or(0));
+
+    for (_, file_name, successful_parse) in parses {
+
+        code.source_file = file_name.clone();
+
+        for pair in successful_parse {
+            match_pairs(pair, code, step, types, class_methods);
+        }
+    }
+
+    Ok(())
+}
+
+fn get_type_declaration_name(iter: &Pair<Rule>) -> Option<String> {
+
+    match iter.as_rule() {
+        Rule::class_code | Rule::record_code if !is_exception_class(iter) => Some(get_class_declaration(iter).class_name),
+        Rule::interface_code | Rule::enum_code => iter.clone().into_inner().find(|a| a.as_rule() == Rule::identifier).map(|a| String::from(a.as_str().trim())),
+        _ => None
+    }
+}
+
+fn add_type_declarations(successful_parse: &Pairs<Rule>, file_name: &str, code: &mut Code) {
+
+    code.source_file = String::from(file_name);
+
+    for namespace in successful_parse.clone().flatten().filter(|a| a.as_rule() == Rule::namespace_code_block) {
+        let namespace_name = get_namespace_name(&namespace);
+
+        for name in namespace.into_inner().filter_map(|a| get_type_declaration_name(&a)) {
+            let name = get_generic_type_name(&name);
+
+            let rust_name = get_rust_name(name, NameKind::Type);
+
+            let qualified_name = format!("{}.{}", namespace_name, name);
+
+            match code.type_declarations.iter().find(|a| a.0 == rust_name).cloned() {
+                Some((_, x, file)) if x != qualified_name => code.add_failure(format!("{} translates to the Rust type {}, which {} in {} already declares; rename one of them",
+                    qualified_name, rust_name, x, file)),
+                Some(_) => {},
+                None => code.type_declarations.push((rust_name, qualified_name, String::from(file_name)))
+            }
+        }
+    }
+}
+
+fn add_runtime_collision(iter: &Pair<Rule>, code: &mut Code) {
+
+    let Some(name) = get_type_declaration_name(iter) else {
+        return;
+    };
+
+    let name = get_rust_name(&name, NameKind::Type);
+
+    if RUNTIME_NAMES.contains(&name.as_str()) && !code.runtime_collisions.contains(&name) {
+        code.add_warning(format!("{} shares its name with generated support code, which is emitted in the {} module", name, RUNTIME_MODULE));
+        code.runtime_collisions.push(name);
+    }
+}
+
+fn add_method_effects(classes: &[Pair<Rule>], code: &mut Code, class_methods: &ClassMethods) {
+
+    let mut calls: Vec<(String, Vec<String>, bool)> = Vec::new();
+
+    for elem in classes.iter() {
+        let declaration = get_class_declaration(elem);
+
+        let class_name = declaration.class_name;
+
+        let interfaces: Vec<ClassMethod> = declaration.bases.iter().filter_map(|a| class_methods.find_interface(get_generic_type_name(a)).cloned()).collect();
+
+        let statics: Vec<String> = elem.clone().into_inner().filter(|a| a.as_rule() == Rule::properties)
+            .filter(|a| a.clone().into_inner().any(|b| matches!(b.as_rule(), Rule::static_key_word | Rule::const_key_word)))
+            .filter_map(|a| a.into_inner().find(|b| b.as_rule() == Rule::identifier).map(|b| String::from(b.as_str().trim()))).collect();
+
+        for action in elem.clone().into_inner().filter(|a| a.as_rule() == Rule::action) {
+            let method = get_method_declaration(action.clone());
+
+            let key = format!("{}.{}", class_name, method.name);
+
+            let is_instance = method.kind != Kind::Static && method.kind != Kind::Extension;
+
+            let statements: Vec<Pair<Rule>> = action.into_inner().filter(|a| a.as_rule() == Rule::code).flat_map(|a| a.into_inner().flatten()).collect();
+
+            if statements.iter().any(|a| matches!(a.as_rule(), Rule::throw_statement | Rule::try_statement)) {
+                code.fallible_methods.push(key.clone());
+            }
+
+            let mut locals: Vec<&str> = method.parameters.iter().map(|a| a.name.as_str()).collect();
+
+            locals.extend(statements.iter().filter(|a| a.as_rule() == Rule::assignment).filter_map(get_declared_local));
+
+            let is_member = |a: &str| a.starts_with("this.") || !(locals.contains(&get_local_root_name(a)) || statics.iter().any(|b| b == get_local_root_name(a)));
+
+            let is_mutating = statements.iter().any(|a| match a.as_rule() {
+                Rule::assignment => a.clone().into_inner().find(|b| b.as_rule() == Rule::property_call).is_some_and(|b| is_member(b.as_str().trim())),
+                Rule::method_call => get_mutated_receiver(a).is_some_and(|b| is_member(&b)),
+                _ => false
+            });
+
+            if is_instance && is_mutating {
+                code.mutating_methods.push(key.clone());
+            }
+
+            let callees: Vec<String> = statements.iter().filter(|a| a.as_rule() == Rule::method_call).filter_map(get_self_call_name)
+                .filter_map(|a| find_method_owner(&class_name, a, class_methods).map(|b| format!("{}.{}", b, a))).collect();
+
+            if method.kind == Kind::Override {
+                let base_key = class_methods.get_base_class(&class_name)
+                    .and_then(|a| find_method_owner(&a, &method.name, class_methods)).map(|a| format!("{}.{}", a, method.name));
+
+                if let Some(x) = base_key {
+                    calls.push((x.clone(), vec![key.clone()], true));
+                    calls.push((key.clone(), vec![x], true));
+                }
+            }
+
+            for interface in interfaces.iter().filter(|a| a.methods.iter().any(|b| b.name == method.name)) {
+                calls.push((format!("{}.{}", interface.class_name, method.name), vec![key.clone()], true));
+            }
+
+            calls.push((key, callees, is_instance));
+        }
+    }
+
+    loop {
+        let fallible: Vec<String> = calls.iter().filter(|a| !code.fallible_methods.contains(&a.0) && a.1.iter().any(|b| code.fallible_methods.contains(b)))
+            .map(|a| a.0.clone()).collect();
+
+        let mutating: Vec<String> = calls.iter().filter(|a| a.2 && !code.mutating_methods.contains(&a.0) && a.1.iter().any(|b| code.mutating_methods.contains(b)))
+            .map(|a| a.0.clone()).collect();
+
+        if fallible.is_empty() && mutating.is_empty() {
+            break;
+        }
+
+        code.fallible_methods.extend(fallible);
+        code.mutating_methods.extend(mutating);
+    }
+}
+
+fn get_declared_local<'a>(iter: &Pair<'a, Rule>) -> Option<&'a str> {
+
+    let mut elements = iter.clone().into_inner();
+
+    elements.next().filter(|a| matches!(a.as_rule(), Rule::var_key_word | Rule::property_type))?;
+
+    elements.next().filter(|a| a.as_rule() == Rule::property_call).map(|a| a.as_str().trim())
+}
+
+fn get_mutated_receiver(iter: &Pair<Rule>) -> Option<String> {
+
+    let elements: Vec<Pair<Rule>> = iter.clone().into_inner().collect();
+
+    let index = elements.iter().position(|a| a.as_rule() == Rule::method_invocation).filter(|a| *a > 0)?;
+
+    if !is_mutating_method(&elements[index]) {
+        return None;
+    }
+
+    Some(elements[..index].iter().map(|a| a.as_str().trim()).collect::<Vec<&str>>().join("."))
+}
+
+fn get_self_call_name<'a>(iter: &Pair<'a, Rule>) -> Option<&'a str> {
+
+    let mut elements = iter.clone().into_inner().skip_while(|a| a.as_rule() == Rule::identifier && a.as_str().trim() == "this");
+
+    let invocation = elements.next().filter(|a| a.as_rule() == Rule::method_invocation)?;
+
+    invocation.into_inner().find(|a| a.as_rule() == Rule::identifier).map(|a| a.as_str().trim())
+}
+
+fn find_method_owner(class_name: &str, method_name: &str, class_methods: &ClassMethods) -> Option<String> {
+
+    let mut current = String::from(get_generic_type_name(class_name));
+
+    loop {
+        let declaration = class_methods.find_class_method(&current)?;
+
+        if declaration.methods.iter().any(|a| a.name == method_name) {
+            return Some(declaration.class_name.clone());
+        }
+
+        current = class_methods.get_base_class(&current)?;
+    }
+}
+
+fn add_static_paths(iter: &Pair<Rule>, code: &mut Code) {
+
+    let class_name = get_class_declaration(iter).class_name;
+
+    if iter.clone().into_inner().any(|a| a.as_rule() == Rule::static_key_word) {
+        let module_name = get_rust_name(&class_name, NameKind::Module);
+
+        let methods: Vec<Method> = iter.clone().into_inner().filter(|a| a.as_rule() == Rule::action).map(get_method_declaration).collect();
+
+        for (target, trait_name) in get_extension_traits(&class_name, &methods) {
+            for method in methods.iter().filter(|a| a.kind == Kind::Extension && a.parameters[0].type_name == target) {
+                code.extension_paths.entry(method.name.clone()).or_default().push(format!("use {}::{};\n", module_name, trait_name));
+            }
+        }
+
+        code.static_paths.insert(class_name, module_name);
+        return;
+    }
+
+    for elem in iter.clone().into_inner().filter(|a| a.as_rule() == Rule::action) {
+        let method = get_method_declaration(elem);
+
+        if method.kind == Kind::Static {
+            code.static_paths.insert(format!("{}.{}", class_name, method.name), class_name.clone());
+        }
+    }
+}
+
+fn add_database_context(iter: &Pair<Rule>, code: &mut Code) {
+
+    let declaration = get_class_declaration(iter);
+
+    if declaration.bases.iter().any(|a| get_simple_type_name(a) == "DbContext") && !code.database_contexts.contains(&declaration.class_name) {
+        add_entity_configurations(iter, &declaration.class_name, code);
+        code.database_contexts.push(declaration.class_name);
+    }
+}
+
+fn add_entity_configurations(iter: &Pair<Rule>, class_name: &str, code: &mut Code) {
+
+    for elem in iter.clone().into_inner().filter(|a| a.as_rule() == Rule::properties) {
+        let type_name = elem.into_inner().find(|a| a.as_rule() == Rule::property_type).map(|a| String::from(a.as_str().trim())).unwrap_or_default();
+
+        if get_generic_type_name(&type_name) == "DbSet" {
+            get_entity_configuration(&mut code.entity_configurations, &get_list_element_type(&type_name));
+        }
+    }
+
+    for elem in iter.clone().into_inner().filter(|a| a.as_rule() == Rule::action) {
+        let method = get_method_declaration(elem.clone());
+
+        let Some(builder) = method.parameters.first().filter(|_| method.name == "OnModelCreating") else {
+            continue;
+        };
+
+        for body in elem.into_inner().filter(|a| a.as_rule() == Rule::code) {
+            match_model_builder_pairs(body, &builder.name, None, class_name, code);
+        }
+    }
+}
+
+fn get_entity_configuration<'a>(configurations: &'a mut Vec<EntityConfiguration>, entity: &str) -> &'a mut EntityConfiguration {
+
+    let entity = get_simple_type_name(entity);
+
+    match configurations.iter().position(|a| a.entity == entity) {
+        Some(x) => &mut configurations[x],
+        None => {
+            configurations.push(EntityConfiguration { entity: String::from(entity), ..Default::default() });
+            configurations.last_mut().expect("")
+        }
+    }
+}
+
+fn match_model_builder_pairs(iter: Pair<Rule>, builder: &str, entity: Option<&str>, class_name: &str, code: &mut Code) {
+
+    for elem in iter.into_inner() {
+        if elem.as_rule() != Rule::method_call {
+            code.add_warning(format!("{}.OnModelCreating: {} is not translated", class_name, elem.as_str().trim()));
+            continue;
+        }
+
+        let mut path: Vec<&str> = Vec::new();
+
+        let mut invocations: Vec<Pair<Rule>> = Vec::new();
+
+        for part in elem.clone().into_inner() {
+            match part.as_rule(){
+                Rule::identifier if invocations.is_empty() => path.push(part.as_str().trim()),
+                Rule::method_invocation => invocations.push(part),
+                _ => {}
+            }
+        }
+
+        if path.first() == Some(&"base") {
+            continue;
+        }
+
+        let first = invocations.first().map(get_fluent_call_name).unwrap_or_default();
+
+        let (entity_name, calls) = match (path.as_slice(), entity) {
+            ([x], _) if *x == builder && first == "Entity" => {
+                let Some(name) = invocations[0].clone().into_inner().find(|a| a.as_rule() == Rule::type_arguments).map(|a| String::from(a.into_inner().as_str().trim())) else {
+                    code.add_warning(format!("{}.OnModelCreating: {} is not translated", class_name, elem.as_str().trim()));
+                    continue;
+                };
+
+                if let Some(x) = invocations[0].clone().into_inner().find(|a| a.as_rule() == Rule::lambda_block) {
+                    let mut block = x.into_inner();
+
+                    let parameter = block.next().map(|a| String::from(a.as_str().trim())).unwrap_or_default();
+
+                    if let Some(x) = block.find(|a| a.as_rule() == Rule::code) {
+                        match_model_builder_pairs(x, &parameter, Some(&name), class_name, code);
+                    }
+                }
+
+                (name, &invocations[1..])
+            },
+            ([x], Some(y)) if *x == builder => (String::from(y), &invocations[..]),
+            _ => {
+                code.add_warning(format!("{}.OnModelCreating: {} is not translated", class_name, elem.as_str().trim()));
+                continue;
+            }
+        };
+
+        if calls.is_empty() {
+            continue;
+        }
+
+        let untranslated = add_fluent_configuration(get_entity_configuration(&mut code.entity_configurations, &entity_name), calls);
+
+        for elem in untranslated {
+            code.add_warning(format!("{}.OnModelCreating: {}.{} is not translated", class_name, get_simple_type_name(&entity_name), elem));
+        }
+    }
+}
+
+fn add_fluent_configuration(configuration: &mut EntityConfiguration, calls: &[Pair<Rule>]) -> Vec<String> {
+
+    let mut untranslated: Vec<String> = Vec::new();
+
+    let member = get_fluent_member(&calls[0]);
+
+    let rest = &calls[1..];
+
+    match (get_fluent_call_name(&calls[0]).as_str(), member) {
+        ("HasKey", Some(x)) => { configuration.key = Some(x); },
+        ("ToTable", Some(x)) => { configuration.table = Some(x); },
+        ("HasIndex", Some(x)) => {
+            let mut index = EntityIndex { properties: vec![x], name: None, is_unique: false };
+
+            for elem in rest {
+                match get_fluent_call_name(elem).as_str() {
+                    "IsUnique" => { index.is_unique = get_fluent_arguments(elem).first().is_none_or(|a| a != "false"); },
+                    "HasDatabaseName" | "HasName" => { index.name = get_fluent_member(elem); },
+                    x => untranslated.push(String::from(x))
+                }
+            }
+
+            configuration.indexes.push(index);
+            return untranslated;
+        },
+        ("Property", Some(x)) => {
+            let position = match configuration.properties.iter().position(|a| a.0 == x) {
+                Some(y) => y,
+                None => {
+                    configuration.properties.push((x, ColumnConstraint::default()));
+                    configuration.properties.len() - 1
+                }
+            };
+
+            let constraint = &mut configuration.properties[position].1;
+
+            for elem in rest {
+                match get_fluent_call_name(elem).as_str() {
+                    "IsRequired" => { constraint.is_required = get_fluent_arguments(elem).first().is_none_or(|a| a != "false"); },
+                    "HasMaxLength" => { constraint.max_length = get_fluent_arguments(elem).first().and_then(|a| a.parse().ok()); },
+                    x => untranslated.push(String::from(x))
+                }
+            }
+
+            return untranslated;
+        },
+        (x @ ("HasOne" | "HasMany"), Some(y)) => {
+            let is_principal = x == "HasMany";
+
+            let mut relationship = if is_principal {
+                Relationship { collection: Some(y), is_principal, ..Default::default() }
+            }
+            else {
+                Relationship { reference: Some(y), is_principal, ..Default::default() }
+            };
+
+            for elem in rest {
+                match (get_fluent_call_name(elem).as_str(), is_principal) {
+                    ("WithMany", false) => { relationship.collection = get_fluent_member(elem); },
+                    ("WithOne", true) => { relationship.reference = get_fluent_member(elem); },
+                    ("HasForeignKey", _) => { relationship.foreign_key = get_fluent_member(elem); },
+                    ("IsRequired", _) => { relationship.is_required = get_fluent_arguments(elem).first().is_none_or(|a| a != "false"); },
+                    (x, _) => untranslated.push(String::from(x))
+                }
+            }
+
+            configuration.relationships.push(relationship);
+            return untranslated;
+        },
+        ("HasData", _) => {
+            for elem in calls[0].clone().into_inner().filter(|a| a.as_rule() == Rule::instance_parameters).flat_map(|a| a.into_inner()) {
+                let values: Vec<(String, String)> = elem.into_inner().filter(|a| a.as_rule() == Rule::object_initializer).flat_map(|a| a.into_inner())
+                    .filter(|a| a.as_rule() == Rule::member_initializer)
+                    .map(|a| {
+                        let mut parts = a.into_inner();
+                        (parts.next().map(|b| String::from(b.as_str().trim())).unwrap_or_default(), parts.next().map(|b| String::from(b.as_str().trim())).unwrap_or_default())
+                    }).collect();
+
+                if values.is_empty() {
+                    untranslated.push(String::from("HasData"));
+                }
+                else {
+                    configuration.seeds.push(values);
+                }
+            }
+        },
+        (x, _) => {
+            untranslated.push(String::from(x));
+            return untranslated;
+        }
+    }
+
+    untranslated.extend(rest.iter().map(get_fluent_call_name));
+
+    untranslated
+}
+
+fn get_fluent_call_name(iter: &Pair<Rule>) -> String {
+    iter.clone().into_inner().find(|a| a.as_rule() == Rule::identifier).map(|a| String::from(a.as_str().trim())).unwrap_or_default()
+}
+
+fn get_fluent_arguments(iter: &Pair<Rule>) -> Vec<String> {
+    iter.clone().into_inner().filter(|a| a.as_rule() == Rule::parameters).flat_map(|a| a.into_inner()).map(|a| String::from(a.as_str().trim())).collect()
+}
+
+fn get_fluent_member(iter: &Pair<Rule>) -> Option<String> {
+
+    if let Some(x) = iter.clone().into_inner().find(|a| a.as_rule() == Rule::lambda_expression) {
+        let parts: Vec<String> = x.into_inner().map(|a| String::from(a.as_str().trim())).collect();
+
+        return match parts.as_slice() {
+            [parameter, member] => member.strip_prefix(&format!("{}.", parameter)).map(String::from),
+            _ => None
+        };
+    }
+
+    get_fluent_arguments(iter).first().filter(|a| a.starts_with('"')).map(|a| String::from(a.trim_matches('"')))
+}
+
+fn add_store_properties(classes: &[Pair<Rule>], code: &mut Code) {
+
+    if code.storage == Storage::Memory {
+        return;
+    }
+
+    let mut entities: Vec<String> = Vec::new();
+
+    let mut properties: Vec<(String, String, String)> = Vec::new();
+
+    for elem in classes.iter() {
+        let class_name = get_class_declaration(elem).class_name;
+
+        for property in elem.clone().into_inner().filter(|a| a.as_rule() == Rule::properties) {
+            if property.clone().into_inner().any(|a| matches!(a.as_rule(), Rule::static_key_word | Rule::const_key_word)) {
+                continue;
+            }
+
+            let type_name = property.clone().into_inner().find(|a| a.as_rule() == Rule::property_type).map(|a| String::from(a.as_str().trim())).unwrap_or_default();
+
+            let Some(name) = property.clone().into_inner().find(|a| a.as_rule() == Rule::identifier).map(|a| String::from(a.as_str().trim())) else {
+                continue;
+            };
+
+            if get_key_attributes(&property).0 || name.eq_ignore_ascii_case("Id") || name.eq_ignore_ascii_case(&format!("{}Id", class_name)) {
+                entities.push(class_name.clone());
+            }
+
+            properties.push((class_name.clone(), name, type_name));
+        }
+    }
+
+    for (class_name, name, type_name) in properties {
+        if entities.contains(&class_name) || code.database_contexts.contains(&class_name) || !is_list_type(&type_name) {
+            continue;
+        }
+
+        if entities.iter().any(|a| a == get_simple_type_name(&get_list_element_type(&type_name))) {
+            code.store_properties.push(format!("{}.{}", class_name, name));
+        }
+    }
+}
+
+fn match_models_declarations_pairs(iter: Pair<Rule>, class_methods: &mut ClassMethods) -> Vec<String> {
+
+    let mut class_names: Vec<String> = Vec::new();
+
+    let mut usings: Vec<String> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule() {
+            Rule::namespace_code_block => {
+                class_methods.scope = Scope { namespace: get_namespace_name(&elem), usings: usings.clone() };
+                match_type_declarations_pairs(elem, class_methods, &mut class_names);
+            },
+            Rule::using_code_block => usings.push(match_using_code_block(elem)),
+            _ => unreachable!()
+        }
+    }
+
+    class_names
+}
+
+fn match_type_declarations_pairs(iter: Pair<Rule>, class_methods: &mut ClassMethods, class_names: &mut Vec<String>) {
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::interface_code => class_methods.add_interface(get_interface_declaration(&elem)),
+            Rule::class_code | Rule::record_code => {
+                if is_exception_class(&elem) {
+                    continue;
+                }
+
+                let declaration = get_class_declaration(&elem);
+
+                class_names.push(declaration.class_name.clone());
+
+                if elem.as_rule() == Rule::record_code {
+                    class_methods.add_record(get_record_declaration(&elem, &declaration));
+                }
+
+                let scope = class_methods.scope.clone();
+
+                let nested_scope = scope.get_nested_scope(&declaration.class_name);
+
+                for method in declaration.methods.iter().filter(|a| a.kind == Kind::Extension) {
+                    class_methods.add_extension(method.clone());
+                }
+
+                class_methods.add_class_method(declaration);
+
+                class_methods.scope = nested_scope;
+
+                for nested in elem.into_inner().filter(|a| a.as_rule() == Rule::nested_type) {
+                    match_type_declarations_pairs(nested, class_methods, class_names);
+                }
+
+                class_methods.scope = scope;
+            },
+            _ => {}
+        }
+    }
+}
+
+fn get_inheritance_depth(class_name: &str, class_methods: &ClassMethods) -> Result<usize, String> {
+
+    let mut depth = 0;
+
+    let mut current = String::from(class_name);
+
+    while let Some(base) = class_methods.get_base_class(&current) {
+        if depth > class_methods.class_methods.len() {
+            return Err(format!("class {} has a circular base class chain", class_name));
+        }
+
+        depth += 1;
+        current = base;
+    }
+
+    Ok(depth)
+}
+
+fn parse_files_contents(files: &[SourceFile], rule: Rule, code: &mut Code, step: &Step, types: &mut TypeTable, class_methods: &mut ClassMethods) -> Result<(), String> {
+
+    for file in files.iter() {
+        let text = file.text.replace("\u{feff}", "");
+
+        let successful_parse = CSParser::parse(rule, &text).map_err(|e| format!("{}: {}", file.path, e))?;
+
+        code.source_file = file.path.clone();
+
+        if rule == Rule::parse_models_contents {
+            add_type_declarations(&successful_parse, &file.path, code);
+        }
+
+        for pair in successful_parse {
+            match_pairs(pair, code, step, types, class_methods);
+        }
+    }
+
+    Ok(())
+}
+
+fn read_optional_file(path: &Path) -> std::io::Result<Option<SourceFile>> {
+    match SourceFile::load(path) {
+        Ok(x) => Ok(Some(x)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e)
+    }
+}
+
+fn read_files(path: &Path) -> std::io::Result<Vec<SourceFile>> {
+    let mut files_contents: Vec<SourceFile> = Vec::new();
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.extension().is_some_and(|a| a == "cs") {
+            files_contents.push(SourceFile::load(&path)?);
+        }
+    }
+
+    files_contents.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(files_contents)
+}
+
+fn match_pairs(iter: Pair<Rule>, code: &mut Code, step: &Step, types: &mut TypeTable, class_methods: &mut ClassMethods) {
+
+    let mut usings: Vec<String> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule() {
+            Rule::namespace_code_block => {
+                set_scope(types, class_methods, Scope { namespace: get_namespace_name(&elem), usings: usings.clone() });
+                match_namespace_code_block(elem, code, step, types, class_methods)
+            },
+            Rule::using_code_block => usings.push(match_using_code_block(elem)),
+            _ => unreachable!()
+        };
+    }
+}
+
+fn match_namespace_code_block(iter: Pair<Rule>, code: &mut Code, step: &Step, types: &mut TypeTable, class_methods: &mut ClassMethods) {
+
+    for elem in iter.into_inner() {
+        match elem.as_rule() {
+            Rule::namespace_key_word => {},
+            Rule::identifier => {},
+            Rule::left_bracers => {},
+            Rule::right_bracers => {},
+            Rule::semicolon => {},
+            _ => match_type_declaration_pairs(elem, code, step, types, class_methods)
+        };
+    }
+}
+
+fn match_type_declaration_pairs(iter: Pair<Rule>, code: &mut Code, step: &Step, types: &mut TypeTable, class_methods: &mut ClassMethods) {
+
+    match iter.as_rule() {
+        Rule::enum_code => match_enum_code_pairs(iter, code, types),
+        Rule::interface_code => {
+            if *step == Step::Models {
+                match_interface_code_pairs(iter, code, class_methods)
+            }
+        },
+        Rule::record_code => {
+            if *step == Step::Models {
+                match_class_models_code_pairs(iter, code, step, types, class_methods)
+            }
+        },
+        Rule::class_code => 
+        {
+            if is_exception_class(&iter) {
+                match_exception_class_code_pairs(iter, code)
+            }
+            else if *step == Step::Models {
+                match_class_models_code_pairs(iter, code, step, types, class_methods)
+            }
+            else if *step == Step::Repositories {
+                match_repositories_class_code_pairs(iter, code, step, types, class_methods)
+            }
+            else if *step == Step::Controllers {
+                match_controller_class_code_pairs(iter, code, step, types, class_methods)
+            }
+        },
+        _ => unreachable!()
+    };
+}
+
+fn add_nested_types_code(code: &mut Code, nested_code: Code, outer_name: &str, nested_names: &[String], class_methods: &ClassMethods) {
+
+    code.failures.extend(nested_code.failures.iter().cloned());
+
+    if nested_code.structs.is_empty() {
+        return;
+    }
+
+    let module_name = get_rust_name(outer_name, NameKind::Module);
+
+    for elem in nested_code.usings {
+        code.add_using(elem);
+    }
+
+    for elem in nested_code.errors {
+        code.add_error(elem);
+    }
+
+    for elem in nested_code.warnings {
+        code.add_warning(elem);
+    }
+
+    for elem in nested_code.validated_types {
+        code.add_validated_type(&elem);
+    }
+
+    for elem in nested_names.iter() {
+        if class_methods.is_ambiguous_name(elem) {
+            code.add_warning(format!("nested type {}.{} has the same name as another type, refer to it as {}::{}", outer_name, elem, module_name, elem));
+        }
+        else {
+            code.add_using(format!("use {}::{};\n", module_name, elem));
+        }
+    }
+
+    let body: String = nested_code.structs.concat().lines().map(|a| if a.is_empty() { String::from("\n") } else { format!("\t{}\n", a) }).collect();
+
+    code.add_struct(&format!("\npub mod {} {{\n\tuse super::*;\n{}}}\n", module_name, body));
+}
+
+fn get_nested_type_name(iter: &Pair<Rule>) -> String {
+
+    match iter.as_rule() {
+        Rule::class_code | Rule::record_code => get_class_declaration(iter).class_name,
+        _ => iter.clone().into_inner().find(|a| a.as_rule() == Rule::identifier).map(|a| String::from(a.as_str())).unwrap_or_default()
+    }
+}
+
+fn match_using_code_block(iter: Pair<Rule>) -> String {
+
+    let mut namespace: Vec<&str> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule() {
+            Rule::using_key_word => {},
+            Rule::identifier => namespace.push(elem.as_str()),
+            Rule::semicolon => {},
+            _ => unreachable!()
+        };
+    }
+
+    namespace.join(".")
+}
+
+fn get_namespace_name(iter: &Pair<Rule>) -> String {
+
+    let names: Vec<&str> = iter.clone().into_inner().filter(|a| a.as_rule() == Rule::identifier).map(|a| a.as_str()).collect();
+
+    names.join(".")
+}
+
+fn set_scope(types: &mut TypeTable, class_methods: &mut ClassMethods, scope: Scope) {
+    types.scope = scope.clone();
+    class_methods.scope = scope;
+}
+
+fn match_class_models_code_pairs(iter: Pair<Rule>, code: &mut Code, step: &Step, types: &mut TypeTable, class_methods: &mut ClassMethods){
+
+    let mut class_name: &str = "";
+
+    let mut properties: Vec<Type> = Vec::new();
+
+    let mut static_properties: Vec<Type> = Vec::new();
+
+    let mut methods: Vec<Method> = Vec::new();
+
+    let declaration = get_class_declaration(&iter);
+
+    let struct_name = declaration.class_name.clone();
+
+    if code.database_contexts.contains(&struct_name) {
+        match_database_context_pairs(iter, code, types);
+        return;
+    }
+
+    let constructors = declaration.constructors.clone();
+
+    let base_class = class_methods.get_base_class(&struct_name);
+
+    let base_type = class_methods.get_base_type(&struct_name);
+
+    let type_arguments = get_rust_generic_arguments(&declaration.type_parameters);
+
+    let self_type = format!("{}{}", struct_name, type_arguments);
+
+    let mut is_abstract = false;
+
+    let mut constructors_code = String::from("");
+
+    let mut default_constructor_code = String::from("");
+
+    let mut methods_code = String::from("");
+
+    let mut trait_code = String::from("");
+
+    let mut accessor = "";
+
+    let mut overrides: Vec<(Method, String)> = Vec::new();
+
+    let mut constructor_index = 0;
+
+    let is_record = iter.as_rule() == Rule::record_code;
+
+    let mut rust_struct = RustStruct::new(&self_type);
+
+    let source = code.get_source_marker(&iter);
+
+    let mut nested_code = code.new_nested();
+
+    let mut nested_names: Vec<String> = Vec::new();
+
+    let mut validations: Vec<String> = Vec::new();
+
+    let mut key_attributes: Vec<(String, bool, Option<bool>)> = Vec::new();
+
+    let mut is_static = false;
+
+    let mut statics_code = String::from("");
+
+    let mut constants_code = String::from("");
+
+    let mut extensions: Vec<(Method, String)> = Vec::new();
+
+    if let Some(x) = &base_class {
+        properties.push(Type { name: String::from("base"), type_name: x.clone(), kind: Kind::Base, properties: Vec::new() });
+    }
+
+    if let Some(x) = &base_type {
+        rust_struct.add_field(format!("   #[serde(flatten)]\n   pub base: {},\n", x));
+    }
+
+    for elem in declaration.type_parameters.iter() {
+        types.add_type(Type { name: elem.name.clone(), type_name: elem.name.clone(), kind: Kind::TypeParameter, properties: Vec::new() });
+    }
+
+    if *step == Step::Models {
+        for elem in iter.into_inner() {
+            match elem.as_rule(){
+                Rule::constructor => {
+                    let constructor = &constructors[constructor_index];
+                    constructor_index += 1;
+
+                    types.enter_class(class_name);
+
+                    let constructor_source = code.get_source_marker(&elem);
+
+                    let constructor_code = format!("{}{}", constructor_source,
+                        match_models_constructor_pairs(elem, code, class_name, types, &mut properties, constructor, class_methods));
+
+                    if constructor.parameters.is_empty() {
+                        default_constructor_code = constructor_code;
+                    }
+                    else {
+                        constructors_code.push_str(&constructor_code);
+                    }
+                },
+                Rule::action => {
+                    types.add_type(Type { name: String::from(class_name), type_name: String::from(class_name), kind: Kind::Class, properties: properties.clone() });
+
+                    types.enter_class(class_name);
+
+                    let method_source = code.get_source_marker(&elem);
+
+                    let is_static_method = elem.clone().into_inner().any(|a| a.as_rule() == Rule::static_key_word);
+
+                    let method_properties = if is_static_method { static_properties.clone() } else { [properties.as_slice(), &static_properties].concat() };
+
+                    let (method, body) = match_models_action_pairs(elem, code, types, class_methods, &method_properties);
+
+                    let mutated: Vec<String> = method.parameters.iter().filter(|a| types.find_usage(&a.name).is_some_and(|b| b.is_mutated)).map(|a| a.name.clone()).collect();
+
+                    let signature = get_rust_method_signature(&method, class_name, &mutated, code);
+
+                    let declaration = get_rust_method_signature(&method, class_name, &[], code);
+
+                    match method.kind {
+                        Kind::Abstract => trait_code.push_str(&format!("\n\t{}{};\n", method_source, declaration)),
+                        Kind::Virtual => {
+                            let arguments: Vec<String> = method.parameters.iter().map(|a| get_rust_name(&a.name, NameKind::Local)).collect();
+
+                            let suffix = if code.is_mutating_method(class_name, &method.name) { "_mut" } else { "" };
+
+                            if !suffix.is_empty() {
+                                accessor = suffix;
+                            }
+
+                            methods_code.push_str(&format!("\n\t{}pub {} {{\n{}\t}}\n", method_source, signature, body));
+                            trait_code.push_str(&format!("\n\t{}{} {{\n\t\tself.as_{}{}().{}({})\n\t}}\n", method_source, declaration, naming::to_snake_case(&struct_name),
+                                suffix, get_rust_method_name(&method.name), arguments.join(", ")));
+                        },
+                        Kind::Override => overrides.push((method.clone(), body)),
+                        Kind::Extension => {
+                            let receiver = method.parameters.first().map(|a| get_rust_name(&a.name, NameKind::Local)).unwrap_or_default();
+
+                            let binding = if body.split(|a: char| !a.is_alphanumeric() && a != '_').any(|a| a == receiver) { format!("\t\tlet {} = self;\n", receiver) } else { String::from("") };
+
+                            extensions.push((method.clone(), format!("\n\t{}{} {{\n{}{}\t}}\n", method_source, signature, binding, body)));
+                        },
+                        _ => methods_code.push_str(&format!("\n\t{}pub {} {{\n{}\t}}\n", method_source, signature, body))
+                    }
+
+                    methods.push(method);
+                },
+                Rule::properties if elem.clone().into_inner().any(|a| a.as_rule() == Rule::expression_body) => {
+                    types.enter_class(class_name);
+
+                    let method_source = code.get_source_marker(&elem);
+
+                    let (method, body) = match_models_expression_property_pairs(elem, code, types, class_methods, &[properties.as_slice(), &static_properties].concat());
+
+                    methods_code.push_str(&format!("\n\t{}pub {} {{\n{}\t}}\n", method_source, get_rust_method_signature(&method, class_name, &[], code), body));
+                },
+                Rule::properties => {
+                    if let Some((property, declaration)) = get_static_declaration(&elem, code, types, class_methods) {
+                        match property.kind {
+                            _ if is_static => statics_code.push_str(&format!("\n\t{}", declaration)),
+                            Kind::Const => constants_code.push_str(&format!("\n\t{}", declaration)),
+                            _ => code.add_struct(&format!("\n{}", declaration))
+                        }
+
+                        static_properties.push(property);
+
+                        continue;
+                    }
+
+                    let (is_key, is_generated) = get_key_attributes(&elem);
+
+                    let mut constraint = get_column_constraint(&elem);
+
+                    let property = match_models_properties_pairs(elem, code, &mut rust_struct, &mut validations);
+
+                    if let Some((_, x)) = code.find_entity_configuration(&struct_name).and_then(|a| a.properties.iter().find(|b| b.0 == property.name)) {
+                        constraint.is_required |= x.is_required;
+                        constraint.max_length = x.max_length.or(constraint.max_length);
+                    }
+
+                    if constraint != ColumnConstraint::default() {
+                        types.add_constraint(&struct_name, &property.name, constraint);
+                    }
+
+                    key_attributes.push((property.name.clone(), is_key, is_generated));
+                    properties.push(property);
+                },
+                Rule::constructor_parameters => {
+                    for parameter in elem.into_inner() {
+                        properties.push(match_models_record_parameter_pairs(parameter, code, &mut rust_struct));
+                    }
+                },
+                Rule::nested_type => {
+                    let scope = types.scope.clone();
+
+                    set_scope(types, class_methods, scope.get_nested_scope(&struct_name));
+
+                    for nested in elem.into_inner() {
+                        nested_names.push(get_nested_type_name(&nested));
+                        match_type_declaration_pairs(nested, &mut nested_code, step, types, class_methods);
+                    }
+
+                    set_scope(types, class_methods, scope);
+                },
+                Rule::attribute => {},
+                Rule::public_key_word =>
+                {
+                    let derives = if is_record { get_record_derives(&struct_name, code, types, class_methods) } else { String::from("") };
+
+                    if constructors.iter().any(|a| a.parameters.is_empty()) {
+                        rust_struct.add_attribute(format!("#[derive(Debug, Deserialize, Serialize, Clone{})]", derives));
+                    }
+                    else {
+                        rust_struct.add_attribute(format!("#[derive(Debug, Default, Deserialize, Serialize, Clone{})]", derives));
+                    }
+
+                    rust_struct.is_public = true;
+                },
+                Rule::abstract_key_word => { is_abstract = true; },
+                Rule::static_key_word => { is_static = true; },
+                Rule::sealed_key_word => {},
+                Rule::record_key_word => {},
+                Rule::class_key_word => {},
+                Rule::class_name =>
+                {
+                    class_name = elem.as_str().trim();
+                },
+                Rule::base_list => {},
+                Rule::type_parameters => {},
+                Rule::type_constraint_clause => {},
+                Rule::left_parenthesis => {},
+                Rule::right_parenthesis => {},
+                Rule::left_bracers => {},
+                Rule::right_bracers if is_static => {
+                    let module_name = get_rust_name(&struct_name, NameKind::Module);
+
+                    let extensions_code = get_extension_traits_code(&struct_name, &extensions, code);
+
+                    code.add_struct(&format!("\n{}pub mod {} {{\n\tuse super::*;\n{}{}{}}}\n", source, module_name, statics_code, methods_code, extensions_code));
+                },
+                Rule::right_bracers | Rule::semicolon =>
+                {
+                    code.add_struct(&format!("{}{}", source, rust_struct.to_code()));
+
+                    if let Some(x) = constructors.iter().find(|a| a.kind == Kind::RecordConstructor) {
+                        constructors_code.insert_str(0, &get_record_constructor_code(x, &struct_name, &properties));
+                    }
+
+                    let default_parameters: Vec<String> = declaration.type_parameters.iter()
+                        .filter(|a| properties.iter().any(|b| b.type_name == a.name)).map(|a| a.name.clone()).collect();
+
+                    let generics = get_rust_generics(&declaration.type_parameters, &default_parameters);
+
+                    let mut inherent_impl = RustImpl::new(&generics, None, &self_type);
+                    inherent_impl.add_item(constants_code.clone());
+                    inherent_impl.add_item(constructors_code.clone());
+                    inherent_impl.add_item(methods_code.clone());
+
+                    if !inherent_impl.is_empty() {
+                        code.add_struct(&inherent_impl.to_code());
+                    }
+
+                    let mut default_impl = RustImpl::new(&generics, Some("Default"), &self_type);
+                    default_impl.add_item(default_constructor_code.clone());
+
+                    if !default_impl.is_empty() {
+                        code.add_struct(&default_impl.to_code());
+                    }
+
+                    if base_class.as_ref().is_some_and(|a| code.is_validated_type(get_generic_type_name(a))) {
+                        validations.insert(0, String::from("\n\t\tfor (key, messages) in self.base.validate() {\n\t\t\terrors.entry(key).or_default().extend(messages);\n\t\t}\n"));
+                    }
+
+                    if validations.iter().any(|a| !a.is_empty()) {
+                        let mut validate_impl = RustImpl::new(&generics, Some(&code.get_runtime_name("Validate")), &self_type);
+                        validate_impl.add_item(format!("\n\tfn validate(&self) -> BTreeMap<String, Vec<String>> {{\n\t\tlet mut errors: BTreeMap<String, Vec<String>> = BTreeMap::new();\n{}\n\t\terrors\n\t}}\n", validations.concat()));
+
+                        code.add_struct(&validate_impl.to_code());
+                        code.add_validated_type(&struct_name);
+                    }
+
+                    if let Some(x) = get_entity_key(&struct_name, &properties, &key_attributes, &base_class, code, types) {
+                        let field = get_rust_name(&x.property, NameKind::Field);
+
+                        let value = if is_copy_type(&x.type_name, types) { format!("self.{}", field) } else { format!("self.{}.clone()", field) };
+
+                        let rust_type = get_rust_type_name(&x.type_name);
+
+                        let mut entity_impl = RustImpl::new(&generics, Some(&code.get_runtime_name("Entity")), &self_type);
+                        entity_impl.add_item(format!("\n\ttype Key = {};\n\n\tconst NAME: &'static str = \"{}\";\n\n\tconst KEY_NAME: &'static str = \"{}\";\n",
+                            rust_type, struct_name, x.property));
+                        entity_impl.add_item(format!("\n\tfn key(&self) -> {} {{\n\t\t{}\n\t}}\n", rust_type, value));
+
+                        code.add_struct(&entity_impl.to_code());
+                        types.add_key(&struct_name, x);
+                    }
+
+                    if let Some(x) = code.find_entity_configuration(&struct_name) {
+                        types.add_configuration(&struct_name, x.clone());
+                    }
+
+                    if let Some(x) = &base_type {
+                        add_base_class_code(code, &generics, &self_type, x);
+                    }
+
+                    if !trait_code.is_empty() {
+                        let accessors = get_base_accessors(&struct_name, &self_type, !accessor.is_empty());
+
+                        let declarations: String = accessors.iter().map(|a| format!("\n\t{};", a)).collect();
+
+                        let definitions: String = accessors.iter().map(|a| format!("\n\t{} {{\n\t\tself\n\t}}", a)).collect();
+
+                        code.add_struct(&format!("\npub trait {}Trait{} {{{}\n{}}}\n", struct_name, generics, declarations, trait_code));
+
+                        if !is_abstract {
+                            code.add_struct(&format!("\nimpl{} {}Trait{} for {} {{{}\n}}\n", generics, struct_name, type_arguments, self_type, definitions));
+                        }
+                    }
+
+                    let implemented = add_base_trait_impls_code(code, &generics, &self_type, &overrides, class_methods);
+
+                    add_interface_impls_code(code, &generics, &self_type, &declaration, &methods, types, class_methods);
+
+                    for (method, body) in overrides.iter().filter(|a| !implemented.contains(&a.0.name)) {
+                        if method.name == "ToString" && method.parameters.is_empty() {
+                            let body: String = body.lines().map(|a| format!("\t{}\n", a)).collect();
+
+                            let mut fmt = RustFunction::new("fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result");
+                            fmt.add_statement(format!("\n\t\tlet value = (|| -> String {{\n{}\t\t}})();\n", body));
+                            fmt.add_statement(String::from("\n\t\twrite!(f, \"{}\", value)"));
+
+                            match fmt.to_code() {
+                                Ok(x) => {
+                                    let mut display_impl = RustImpl::new(&generics, Some("std::fmt::Display"), &self_type);
+                                    display_impl.add_item(x);
+
+                                    code.add_struct(&display_impl.to_code());
+                                },
+                                Err(e) => code.add_failure(format!("{}.ToString: {}", struct_name, e))
+                            }
+                        }
+                        else {
+                            code.add_warning(format!("{}.{} overrides a member that is not virtual in a translated base class", struct_name, method.name));
+                        }
+                    }
+
+                    code.add_struct("\n");
+                },
+                _ => unreachable!()
+            }
+        }
+
+        check_type_references(&properties, types, class_methods);
+
+        add_name_collision_failures(code, &struct_name, &properties, &methods);
+
+        types.add_type(Type { name: String::from(class_name), type_name: String::from(class_name), kind: Kind::Class, properties });
+
+        add_nested_types_code(code, nested_code, &struct_name, &nested_names, class_methods);
+
+        class_methods.add_class_method(ClassMethod { class_name: struct_name, methods, constructors, bases: declaration.bases, type_parameters: declaration.type_parameters })
+    }
+}
+
+fn get_extension_traits_code(class_name: &str, extensions: &[(Method, String)], code: &mut Code) -> String {
+
+    for (method, _) in extensions.iter().filter(|a| is_generic_extension(&a.0)) {
+        code.add_warning(format!("{}.{} extends a generic type and is not translated", class_name, method.name));
+    }
+
+    let methods: Vec<Method> = extensions.iter().map(|a| a.0.clone()).collect();
+
+    let mut traits_code = String::from("");
+
+    for (target, trait_name) in get_extension_traits(class_name, &methods) {
+        let target_methods: Vec<&(Method, String)> = extensions.iter().filter(|a| !is_generic_extension(&a.0) && a.0.parameters[0].type_name == target).collect();
+
+        let signatures: String = target_methods.iter().map(|a| format!("\n\t{};\n", get_rust_method_signature(&a.0, class_name, &[], code))).collect();
+
+        let bodies: String = target_methods.iter().map(|a| a.1.clone()).collect();
+
+        traits_code.push_str(&format!("\n\tpub trait {} {{{}\t}}\n\n\timpl {} for {} {{{}\t}}\n", trait_name, signatures, trait_name, get_rust_type_name(&target), bodies));
+    }
+
+    traits_code
+}
+
+fn is_generic_extension(method: &Method) -> bool {
+
+    let target = method.parameters.first().map(|a| a.type_name.as_str()).unwrap_or_default();
+
+    method.type_parameters.iter().any(|a| target.split(['<', '>', ',', ' ']).any(|b| b == a.name))
+}
+
+fn get_extension_traits(class_name: &str, methods: &[Method]) -> Vec<(String, String)> {
+
+    let mut targets: Vec<String> = Vec::new();
+
+    for elem in methods.iter().filter(|a| a.kind == Kind::Extension && !a.parameters.is_empty() && !is_generic_extension(a)) {
+        if !targets.contains(&elem.parameters[0].type_name) {
+            targets.push(elem.parameters[0].type_name.clone());
+        }
+    }
+
+    targets.iter().map(|a| {
+        let trait_name = if targets.len() == 1 { String::from(class_name) } else { format!("{}{}", class_name, get_rust_type_name(a).replace(['<', '>', ',', ' '], "")) };
+
+        (a.clone(), trait_name)
+    }).collect()
+}
+
+fn get_key_attributes(iter: &Pair<Rule>) -> (bool, Option<bool>) {
+
+    let mut is_key = false;
+
+    let mut is_generated: Option<bool> = None;
+
+    for elem in iter.clone().into_inner().filter(|a| a.as_rule() == Rule::attribute) {
+        match get_attribute_name(&elem).as_str() {
+            "Key" => { is_key = true; },
+            "DatabaseGenerated" => { is_generated = get_attribute_arguments(&elem).first().map(|a| !a.1.ends_with("None")); },
+            _ => {}
+        }
+    }
+
+    (is_key, is_generated)
+}
+
+fn get_column_constraint(iter: &Pair<Rule>) -> ColumnConstraint {
+
+    let mut constraint = ColumnConstraint::default();
+
+    for elem in iter.clone().into_inner().filter(|a| a.as_rule() == Rule::attribute) {
+        match get_attribute_name(&elem).as_str() {
+            "Required" => { constraint.is_required = true; },
+            "MaxLength" | "StringLength" => { constraint.max_length = get_attribute_arguments(&elem).first().and_then(|a| a.1.parse().ok()); },
+            _ => {}
+        }
+    }
+
+    constraint
+}
+
+fn get_entity_key(class_name: &str, properties: &[Type], key_attributes: &[(String, bool, Option<bool>)], base_class: &Option<String>,
+    code: &mut Code, types: &TypeTable) -> Option<EntityKey> {
+
+    let keys: Vec<&String> = key_attributes.iter().filter(|a| a.1).map(|a| &a.0).collect();
+
+    if keys.len() > 1 {
+        code.add_warning(format!("{}: composite keys are not supported, {} is used as the key", class_name, keys[0]));
+    }
+
+    let configured = code.find_entity_configuration(class_name).and_then(|a| a.key.clone());
+
+    let property = match configured.as_ref().or(keys.first().copied()) {
+        Some(x) => properties.iter().find(|a| &a.name == x),
+        None => properties.iter().filter(|a| a.kind != Kind::Base)
+            .find(|a| a.name.eq_ignore_ascii_case("Id") || a.name.eq_ignore_ascii_case(&format!("{}Id", class_name)))
+    };
+
+    match property {
+        Some(x) => {
+            let is_generated = key_attributes.iter().find(|a| a.0 == x.name).and_then(|a| a.2).unwrap_or(x.type_name == "int" || x.type_name == "long");
+
+            Some(EntityKey { property: x.name.clone(), type_name: x.type_name.clone(), is_generated })
+        },
+        None => base_class.as_ref().and_then(|a| types.find_key(get_generic_type_name(a))).cloned()
+    }
+}
+
+fn add_name_collision_failures(code: &mut Code, struct_name: &str, properties: &[Type], methods: &[Method]) {
+
+    let field_names: Vec<String> = properties.iter().filter(|a| a.kind != Kind::Base).map(|a| a.name.clone()).collect();
+
+    for elem in naming::find_collisions(&field_names, NameKind::Field) {
+        code.add_failure(format!("{}: field names {}; rename one of them", struct_name, elem));
+    }
+
+    let method_names: Vec<String> = methods.iter().map(|a| a.name.clone()).collect();
+
+    for elem in naming::find_collisions(&method_names, NameKind::Function) {
+        code.add_failure(format!("{}: method names {}; rename one of them", struct_name, elem));
+    }
+}
+
+fn check_type_references(properties: &[Type], types: &TypeTable, class_methods: &ClassMethods) {
+
+    for elem in properties.iter().filter(|a| a.kind != Kind::Base) {
+        let type_name = if elem.type_name.contains('<') { get_list_element_type(&elem.type_name) } else { elem.type_name.clone() };
+
+        if class_methods.find_class_method(&type_name).is_none() {
+            types.find_type(&type_name);
+        }
+    }
+}
+
+fn match_models_record_parameter_pairs(iter: Pair<Rule>, code: &mut Code, rust_struct: &mut RustStruct) -> Type {
+
+    let source = code.get_source_marker(&iter);
+
+    let mut property_type: Vec<Type> = Vec::new();
+
+    let mut rust_prop_type = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::property_type => { rust_prop_type = match_property_type_code_pairs(elem, code, &mut property_type) },
+            Rule::identifier => {
+                let prop_type = property_type.pop().expect("");
+
+                rust_struct.add_field(format!("{}{}", source, get_rust_field_declaration(elem.as_str(), &rust_prop_type)));
+
+                return Type { name: String::from(elem.as_str()), type_name: prop_type.type_name, kind: Kind::Property, properties: Vec::new() };
+            },
+            _ => unreachable!()
+        }
+    }
+
+    Type::new()
+}
+
+fn get_record_constructor_code(constructor: &Method, struct_name: &str, properties: &[Type]) -> String {
+
+    let mut parameters: Vec<String> = Vec::new();
+
+    let mut fields: Vec<String> = Vec::new();
+
+    for elem in constructor.parameters.iter() {
+        let value = if elem.type_name.starts_with("Vec<") { format!("Arc::new(RwLock::new({}))", elem.name) } else { elem.name.clone() };
+
+        parameters.push(format!("{}: {}", elem.name, get_rust_type_name(&elem.type_name)));
+        fields.push(format!("\n\t\t\t{}: {}, ", elem.name, value));
+    }
+
+    if properties.len() > constructor.parameters.len() {
+        fields.push(String::from("\n\t\t\t..Default::default()"));
+    }
+
+    format!("\n\tpub fn {}({}) -> Self {{\n\t\t{} {{{}\n\t\t}}\n\t}}\n", constructor.name, parameters.join(", "), struct_name, fields.join(""))
+}
+
+fn get_record_declaration(iter: &Pair<Rule>, declaration: &ClassMethod) -> Type {
+
+    let mut fields: Vec<Type> = Vec::new();
+
+    for elem in declaration.bases.iter() {
+        fields.push(Type { name: String::from("base"), type_name: elem.clone(), kind: Kind::Base, properties: Vec::new() });
+    }
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::constructor_parameters => {
+                for parameter in elem.into_inner() {
+                    let mut field = Type::new();
+
+                    for x in parameter.into_inner() {
+                        match x.as_rule(){
+                            Rule::property_type => { field.type_name = String::from(x.as_str().trim()); },
+                            Rule::identifier => { field.name = String::from(x.as_str()); },
+                            _ => unreachable!()
+                        }
+                    }
+
+                    fields.push(field);
+                }
+            },
+            Rule::properties => {
+                let children: Vec<Pair<Rule>> = elem.into_inner().collect();
+
+                if children.iter().any(|a| a.as_rule() == Rule::static_key_word || a.as_rule() == Rule::expression_body) {
+                    continue;
+                }
+
+                let mut field = Type::new();
+
+                for x in children {
+                    match x.as_rule(){
+                        Rule::property_type => { field.type_name = String::from(x.as_str().trim()); },
+                        Rule::identifier => { field.name = String::from(x.as_str()); },
+                        _ => {}
+                    }
+                }
+
+                fields.push(field);
+            },
+            _ => {}
+        }
+    }
+
+    Type { name: declaration.class_name.clone(), type_name: declaration.class_name.clone(), kind: Kind::Record, properties: fields }
+}
+
+fn get_record_derives(struct_name: &str, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    match get_equality_level(struct_name, types, class_methods, 0) {
+        2 => String::from(", PartialEq, Eq, Hash"),
+        1 => String::from(", PartialEq"),
+        _ => {
+            code.add_warning(format!("record {} has members without value equality in Rust, PartialEq is not derived", struct_name));
+            String::from("")
+        }
+    }
+}
+
+fn get_equality_level(type_name: &str, types: &TypeTable, class_methods: &ClassMethods, depth: usize) -> u8 {
+
+    match type_name.trim() {
+        "int" | "string" | "bool" => return 2,
+        "decimal" => return 1,
+        _ => {}
+    }
+
+    if types.is_enum_type(type_name) || types.find_type(type_name).is_some_and(|a| a.kind == Kind::TypeParameter) {
+        return 2;
+    }
+
+    match class_methods.find_record(get_generic_type_name(type_name)) {
+        Some(x) if depth <= class_methods.records.len() => {
+            x.properties.iter().map(|a| get_equality_level(&a.type_name, types, class_methods, depth + 1)).min().unwrap_or(2)
+        },
+        _ => 0
+    }
+}
+
+fn match_models_expression_property_pairs(iter: Pair<Rule>, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods,
+    properties: &[Type]) -> (Method, String) {
+
+    let mut method = Method { name: String::from(""), return_type: String::from(""), parameters: Vec::new(), type_parameters: Vec::new(), kind: Kind::ExpressionBody };
+
+    let mut body = String::from("");
+
+    let properties = get_class_properties(properties, types);
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::property_type => { method.return_type = String::from(elem.as_str().trim()); },
+            Rule::identifier => { method.name = String::from(elem.as_str()); },
+            Rule::expression_body => {
+                let member_values = properties.iter().map(|a| (a.name.clone(), get_rust_member_value(&a.name, &properties, &method, code, types, true))).collect();
+
+                code.member_values = member_values;
+
+                for value in elem.into_inner().filter(|a| a.as_rule() != Rule::semicolon) {
+                    body = format!("\t\t{}\n", get_rust_return_value(value, &properties, &method, code, types, class_methods));
+                }
+
+                code.member_values.clear();
+            },
+            _ => {}
+        }
+    }
+
+    (method, body)
+}
+
+fn add_base_class_code(code: &mut Code, generics: &str, self_type: &str, base_type: &str) {
+
+    let mut deref_impl = RustImpl::new(generics, Some("std::ops::Deref"), self_type);
+    deref_impl.add_item(format!("\n\ttype Target = {};\n", base_type));
+    deref_impl.add_item(String::from("\n\tfn deref(&self) -> &Self::Target {\n\t\t&self.base\n\t}\n"));
+
+    code.add_struct(&deref_impl.to_code());
+
+    let mut deref_mut_impl = RustImpl::new(generics, Some("std::ops::DerefMut"), self_type);
+    deref_mut_impl.add_item(String::from("\n\tfn deref_mut(&mut self) -> &mut Self::Target {\n\t\t&mut self.base\n\t}\n"));
+
+    code.add_struct(&deref_mut_impl.to_code());
+}
+
+fn add_base_trait_impls_code(code: &mut Code, generics: &str, self_type: &str, overrides: &[(Method, String)], class_methods: &mut ClassMethods) -> Vec<String> {
+
+    let mut implemented: Vec<String> = Vec::new();
+
+    let mut current = String::from(get_generic_type_name(self_type));
+
+    while let Some(base_class) = class_methods.get_base_class(&current) {
+        let base_type = class_methods.get_base_type(&current).unwrap_or(base_class.clone());
+
+        let base_methods = match class_methods.get_class_method(&base_class) {
+            Ok(x) => x.methods,
+            Err(e) => {
+                code.add_failure(e);
+                break;
+            }
+        };
+
+        let trait_methods: Vec<&Method> = base_methods.iter()
+            .filter(|a| a.kind == Kind::Abstract || a.kind == Kind::Virtual).collect();
+
+        if !trait_methods.is_empty() {
+            let trait_name = format!("{}Trait{}", base_class, base_type.trim_start_matches(&base_class));
+
+            let is_mutating = trait_methods.iter().any(|a| a.kind == Kind::Virtual && code.is_mutating_method(&base_class, &a.name));
+
+            let mut trait_impl = RustImpl::new(generics, Some(&trait_name), self_type);
+
+            for elem in get_base_accessors(&base_class, &base_type, is_mutating) {
+                trait_impl.add_item(format!("\n\t{} {{\n\t\tself\n\t}}\n", elem));
+            }
+
+            for (method, body) in overrides.iter() {
+                if trait_methods.iter().any(|a| a.name == method.name) {
+                    trait_impl.add_item(format!("\n\t{} {{\n{}\t}}\n", get_rust_method_signature(method, self_type, &[], code), body));
+                    implemented.push(method.name.clone());
+                }
+            }
+
+            code.add_struct(&trait_impl.to_code());
+        }
+
+        current = base_class;
+    }
+
+    implemented
+}
+
+fn get_base_accessors(class_name: &str, type_name: &str, is_mutating: bool) -> Vec<String> {
+
+    let mut accessors = vec![format!("fn as_{}(&self) -> &{}", naming::to_snake_case(class_name), type_name)];
+
+    if is_mutating {
+        accessors.push(format!("fn as_{}_mut(&mut self) -> &mut {}", naming::to_snake_case(class_name), type_name));
+    }
+
+    accessors
+}
+
+fn get_class_declaration(iter: &Pair<Rule>) -> ClassMethod {
+
+    let mut class_name = String::from("");
+
+    let mut methods: Vec<Method> = Vec::new();
+
+    let mut constructors: Vec<Method> = Vec::new();
+
+    let mut bases: Vec<String> = Vec::new();
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::class_name => { class_name = String::from(elem.as_str().trim()); },
+            Rule::base_list => {
+                bases = elem.into_inner().map(|a| String::from(a.as_str().trim())).collect();
+            },
+            Rule::constructor => {
+                let mut parameters: Vec<Parameter> = Vec::new();
+
+                for constructor in elem.into_inner() {
+                    if constructor.as_rule() == Rule::constructor_parameters {
+                        parameters = match_parameters_pairs(constructor);
+                    }
+                }
+
+                let name = get_constructor_name(&parameters, &constructors);
+
+                constructors.push(Method { name, return_type: class_name.clone(), parameters, type_parameters: Vec::new(), kind: Kind::Constructor });
+            },
+            Rule::action => methods.push(get_method_declaration(elem)),
+            _ => {}
+        }
+    }
+
+    if let Some(x) = iter.clone().into_inner().find(|a| a.as_rule() == Rule::constructor_parameters) {
+        let parameters: Vec<Parameter> = match_parameters_pairs(x).into_iter()
+            .map(|a| Parameter { name: get_rust_name(&a.name, NameKind::Local), type_name: a.type_name }).collect();
+
+        let name = get_constructor_name(&parameters, &constructors);
+
+        constructors.push(Method { name, return_type: class_name.clone(), parameters, type_parameters: Vec::new(), kind: Kind::RecordConstructor });
+    }
+
+    ClassMethod { class_name, methods, constructors, bases, type_parameters: get_type_parameters(iter) }
+}
+
+fn get_method_declaration(iter: Pair<Rule>) -> Method {
+
+    let mut method = Method { name: String::from(""), return_type: String::from(""), parameters: Vec::new(), type_parameters: get_type_parameters(&iter), kind: Kind::Method };
+
+    let is_extension = is_extension_method(&iter);
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::static_key_word => { method.kind = Kind::Static; },
+            Rule::abstract_key_word => { method.kind = Kind::Abstract; },
+            Rule::virtual_key_word => { method.kind = Kind::Virtual; },
+            Rule::override_key_word => { method.kind = Kind::Override; },
+            Rule::action_return_type | Rule::action_async_return_type | Rule::method_return_type => { method.return_type = String::from(elem.as_str()); },
+            Rule::identifier => { method.name = String::from(elem.as_str()); },
+            Rule::action_parameters => { method.parameters = match_parameters_pairs(elem); },
+            _ => {}
+        }
+    }
+
+    if is_extension {
+        method.kind = Kind::Extension;
+    }
+
+    method
+}
+
+fn is_extension_method(iter: &Pair<Rule>) -> bool {
+
+    iter.clone().into_inner().find(|a| a.as_rule() == Rule::action_parameters)
+        .and_then(|a| a.into_inner().next())
+        .is_some_and(|a| a.into_inner().any(|b| b.as_rule() == Rule::this_key_word))
+}
+
+fn get_interface_declaration(iter: &Pair<Rule>) -> ClassMethod {
+
+    let mut class_name = String::from("");
+
+    let mut methods: Vec<Method> = Vec::new();
+
+    let mut bases: Vec<String> = Vec::new();
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { class_name = String::from(elem.as_str().trim()); },
+            Rule::base_list => {
+                bases = elem.into_inner().map(|a| String::from(a.as_str().trim())).collect();
+            },
+            Rule::interface_member => {
+                for member in elem.into_inner() {
+                    match member.as_rule(){
+                        Rule::interface_property => methods.append(&mut get_interface_property_declaration(member)),
+                        Rule::interface_method => methods.push(get_interface_method_declaration(member)),
+                        _ => unreachable!()
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+
+    ClassMethod { class_name, methods, constructors: Vec::new(), bases, type_parameters: get_type_parameters(iter) }
+}
+
+fn get_interface_property_declaration(iter: Pair<Rule>) -> Vec<Method> {
+
+    let mut name = String::from("");
+
+    let mut type_name = String::from("");
+
+    let mut has_setter = false;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::property_type => { type_name = String::from(elem.as_str().trim()); },
+            Rule::identifier => { name = String::from(elem.as_str()); },
+            Rule::set_key_word => { has_setter = true; },
+            _ => {}
+        }
+    }
+
+    let mut accessors = vec![Method { name: name.clone(), return_type: type_name.clone(), parameters: Vec::new(), type_parameters: Vec::new(), kind: Kind::Getter }];
+
+    if has_setter {
+        accessors.push(Method { name, return_type: String::from("void"), parameters: vec![Parameter { name: String::from("value"), type_name }],
+            type_parameters: Vec::new(), kind: Kind::Setter });
+    }
+
+    accessors
+}
+
+fn get_interface_method_declaration(iter: Pair<Rule>) -> Method {
+
+    let mut method = Method { name: String::from(""), return_type: String::from("void"), parameters: Vec::new(), type_parameters: get_type_parameters(&iter), kind: Kind::InterfaceMethod };
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::property_type => { method.return_type = String::from(elem.as_str().trim()); },
+            Rule::identifier => { method.name = String::from(elem.as_str()); },
+            Rule::action_parameters => { method.parameters = match_parameters_pairs(elem); },
+            _ => {}
+        }
+    }
+
+    method
+}
+
+fn match_interface_code_pairs(iter: Pair<Rule>, code: &mut Code, class_methods: &mut ClassMethods) {
+
+    let declaration = get_interface_declaration(&iter);
+
+    let generics = get_rust_generics(&declaration.type_parameters, &[]);
+
+    let supertraits: Vec<String> = declaration.bases.iter().map(|a| get_rust_type_name(a)).collect();
+
+    code.add_struct(&code.get_source_marker(&iter));
+
+    if supertraits.is_empty() {
+        code.add_struct(&format!("\npub trait {}{} {{", declaration.class_name, generics));
+    }
+    else {
+        code.add_struct(&format!("\npub trait {}{}: {} {{", declaration.class_name, generics, supertraits.join(" + ")));
+    }
+
+    for elem in declaration.methods.iter() {
+        code.add_struct(&format!("\n\t{};", get_rust_interface_method_signature(elem, &declaration.class_name, code)));
+    }
+
+    code.add_struct("\n}\n");
+
+    class_methods.add_interface(declaration);
+}
+
+fn get_rust_interface_method_signature(method: &Method, interface_name: &str, code: &Code) -> String {
+
+    match method.kind {
+        Kind::Getter => format!("fn {}(&self) -> {}", get_rust_method_name(&method.name), get_rust_type_name(&method.return_type)),
+        Kind::Setter => format!("fn set_{}(&mut self, value: {})", naming::to_snake_case(&method.name), get_rust_type_name(&method.parameters[0].type_name)),
+        _ => get_rust_method_signature(method, interface_name, &[], code)
+    }
+}
+
+fn add_interface_impls_code(code: &mut Code, generics: &str, self_type: &str, declaration: &ClassMethod, methods: &[Method],
+    types: &mut TypeTable, class_methods: &mut ClassMethods) {
+
+    for base in declaration.bases.iter() {
+        let interface = match class_methods.find_interface(get_generic_type_name(base)) {
+            Some(x) => x.clone(),
+            None => continue
+        };
+
+        let arguments: Vec<(String, String)> = interface.type_parameters.iter().map(|a| a.name.clone())
+            .zip(get_type_argument_names(base)).collect();
+
+        let mut interface_impl = RustImpl::new(generics, Some(&get_rust_type_name(base)), self_type);
+
+        for elem in interface.methods.iter() {
+            let mut method = elem.clone();
+
+            method.return_type = substitute_type_parameters(&method.return_type, &arguments);
+
+            for parameter in method.parameters.iter_mut() {
+                parameter.type_name = substitute_type_parameters(&parameter.type_name, &arguments);
+            }
+
+            let field = get_rust_method_name(&method.name);
+
+            let body = match method.kind {
+                Kind::Getter => {
+                    if is_list_type(&method.return_type) {
+                        format!("self.{}.read().clone()", field)
+                    }
+                    else if is_copy_type(&method.return_type, types) {
+                        format!("self.{}", field)
+                    }
+                    else {
+                        format!("self.{}.clone()", field)
+                    }
+                },
+                Kind::Setter => {
+                    if is_list_type(&method.parameters[0].type_name) {
+                        format!("*self.{}.write() = value;", field)
+                    }
+                    else {
+                        format!("self.{} = value;", field)
+                    }
+                },
+                _ if code.is_fallible_method(&interface.class_name, &method.name) && !is_fallible_implementation(&method, self_type, code, class_methods) => {
+                    format!("Ok({})", get_rust_interface_method_call(&method, self_type, methods, code, class_methods))
+                },
+                _ => get_rust_interface_method_call(&method, self_type, methods, code, class_methods)
+            };
+
+            interface_impl.add_item(format!("\n\t{} {{\n\t\t{}\n\t}}\n", get_rust_interface_method_signature(&method, &interface.class_name, code), body));
+        }
+
+        code.add_struct(&interface_impl.to_code());
+    }
+}
+
+fn is_fallible_implementation(method: &Method, self_type: &str, code: &Code, class_methods: &ClassMethods) -> bool {
+    find_method_owner(self_type, &method.name, class_methods).is_some_and(|a| code.is_fallible_method(&a, &method.name))
+}
+
+fn get_rust_interface_method_call(method: &Method, self_type: &str, methods: &[Method], code: &mut Code, class_methods: &mut ClassMethods) -> String {
+
+    let arguments: Vec<String> = method.parameters.iter().map(|a| get_rust_name(&a.name, NameKind::Local)).collect();
+
+    let call = format!("{}({})", get_rust_method_name(&method.name), arguments.join(", "));
+
+    let is_inherent = |a: &Method| a.name == method.name && (a.kind == Kind::Method || a.kind == Kind::Virtual);
+
+    if methods.iter().any(is_inherent) {
+        return format!("self.{}", call);
+    }
+
+    let mut receiver = String::from("self");
+
+    let mut current = String::from(get_generic_type_name(self_type));
+
+    while let Some(base_class) = class_methods.get_base_class(&current) {
+        receiver.push_str(".base");
+
+        match class_methods.get_class_method(&base_class) {
+            Ok(x) if x.methods.iter().any(is_inherent) => return format!("{}.{}", receiver, call),
+            Ok(_) => {},
+            Err(e) => {
+                code.add_failure(e);
+                break;
+            }
+        }
+
+        current = base_class;
+    }
+
+    if methods.iter().any(|a| a.name == method.name && a.kind == Kind::Abstract) {
+        code.add_failure(format!("{} implements interface method {} as abstract, which is not translated", self_type, method.name));
+    }
+    else {
+        code.add_failure(format!("{} does not implement interface method {}", self_type, method.name));
+    }
+
+    String::from("")
+}
+
+fn get_type_argument_names(value: &str) -> Vec<String> {
+
+    let mut names: Vec<String> = Vec::new();
+
+    if let Ok(mut x) = CSParser::parse(Rule::property_type, value.trim()) {
+        match x.next().and_then(|a| a.into_inner().next()) {
+            Some(generic) if generic.as_rule() == Rule::generic_type => {
+                for elem in generic.into_inner().filter(|a| a.as_rule() == Rule::type_arguments) {
+                    names = elem.into_inner().map(|a| String::from(a.as_str().trim())).collect();
+                }
+            },
+            Some(list) if list.as_rule() == Rule::list_type => {
+                names = list.into_inner().map(|a| String::from(a.as_str().trim())).collect();
+            },
+            _ => {}
+        }
+    }
+
+    names
+}
+
+fn substitute_type_parameters(value: &str, arguments: &[(String, String)]) -> String {
+
+    let mut result = String::from("");
+
+    let mut token = String::from("");
+
+    for c in value.chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            token.push(c);
+            continue;
+        }
+
+        match arguments.iter().find(|a| a.0 == token) {
+            Some(x) => result.push_str(&x.1),
+            None => result.push_str(&token)
+        }
+
+        token.clear();
+        result.push(c);
+    }
+
+    result.pop();
+
+    result
+}
+
+fn get_constructor_name(parameters: &[Parameter], constructors: &[Method]) -> String {
+
+    if parameters.is_empty() {
+        return String::from("default");
+    }
+
+    let names: Vec<String> = parameters.iter().map(|a| naming::to_snake_case(&a.name)).collect();
+
+    let name = format!("with_{}", names.join("_and_"));
+
+    if !constructors.iter().any(|a| a.name == name) {
+        return name;
+    }
+
+    let type_names: Vec<String> = parameters.iter().map(|a| naming::to_snake_case(&a.type_name)).collect();
+
+    format!("{}_{}", name, type_names.join("_"))
+}
+
+fn get_rust_constructor_call(type_name: &str, arguments: Vec<Pair<Rule>>, line: usize, code: &mut Code, types: &TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    let constructor_name = get_rust_constructor_name(type_name, &arguments, line, code, class_methods);
+
+    let parameters = match class_methods.find_class_method(type_name).and_then(|a| a.constructors.iter().find(|b| b.name == constructor_name)) {
+        Some(x) => x.parameters.clone(),
+        None => Vec::new()
+    };
+
+    let mut values: Vec<String> = Vec::new();
+
+    for (index, elem) in arguments.into_iter().enumerate() {
+        let mut value = get_rust_parameter(elem, code, types, class_methods);
+
+        if parameters.get(index).is_some_and(|a| a.type_name == "f64") && value.parse::<i64>().is_ok() {
+            value.push_str(".0");
+        }
+
+        values.push(value);
+    }
+
+    format!("{}({})", constructor_name, values.join(", "))
+}
+
+fn get_rust_constructor_name(type_name: &str, arguments: &[Pair<Rule>], line: usize, code: &mut Code, class_methods: &mut ClassMethods) -> String {
+
+    if arguments.is_empty() {
+        return String::from("default");
+    }
+
+    let candidates: Vec<Method> = match class_methods.find_class_method(type_name) {
+        Some(x) => x.constructors.iter()
+            .filter(|a| a.parameters.len() == arguments.len())
+            .filter(|a| a.parameters.iter().zip(arguments.iter()).all(|(p, arg)| is_argument_compatible(arg, &p.type_name)))
+            .cloned().collect(),
+        None => Vec::new()
+    };
+
+    if candidates.is_empty() {
+        code.add_warning(format!("line {}: no constructor of {} takes {} argument(s)", line, type_name, arguments.len()));
+        return String::from("new");
+    }
+
+    if candidates.len() > 1 {
+        code.add_warning(format!("line {}: call to {} constructor is ambiguous, using {}", line, type_name, candidates[0].name));
+    }
+
+    candidates[0].name.clone()
+}
+
+fn is_argument_compatible(iter: &Pair<Rule>, type_name: &str) -> bool {
+
+    match iter.clone().into_inner().next().map(|a| a.as_rule()) {
+        Some(Rule::string_literal) => type_name == "String",
+        Some(Rule::number) => type_name == "i32" || type_name == "f64",
+        _ => true
+    }
+}
+
+fn match_repositories_class_code_pairs(iter: Pair<Rule>, code: &mut Code, step: &Step, types: &mut TypeTable, class_methods: &mut ClassMethods){
+
+    let bases = get_class_declaration(&iter).bases;
+
+    let source = code.get_source_marker(&iter);
+
+    let mut class_name: &str = "";
+
+    let mut properties: Vec<Type> = Vec::new();
+
+    let mut methods: Vec<Method> = Vec::new();
+
+    let mut methods_code = String::from("");
+
+    let mut statics: Vec<Pair<Rule>> = Vec::new();
+
+    if *step == Step::Repositories {
+        for elem in iter.into_inner() {
+            match elem.as_rule(){
+                Rule::public_key_word => {},
+                Rule::static_key_word => {},
+                Rule::class_key_word => {},
+                Rule::constructor => { 
+                    types.add_type(Type { name: String::from(class_name), type_name: String::from(class_name), 
+                    kind: Kind::Class, properties: properties.to_vec() }); 
+                },
+                Rule::properties => match get_static_declaration(&elem, code, types, class_methods) {
+                    Some((property, _)) => {
+                        properties.push(property);
+                        statics.push(elem);
+                    },
+                    None => properties.push(match_repositories_properties_pairs(elem, code))
+                },
+                Rule::class_name =>
+                {
+                    if class_name.is_empty() {
+                        class_name = elem.as_str();
+                    }
+                },
+                Rule::base_list => {},
+                Rule::type_parameters => {},
+                Rule::type_constraint_clause => {},
+                Rule::nested_type => {},
+                Rule::left_bracers => {},
+                Rule::right_bracers => {},
+                Rule::action => {
+                    types.add_type(Type { name: String::from(class_name), type_name: String::from(class_name), kind: Kind::Class, properties: properties.to_vec() });
+
+                    let (method, method_code) = match_repositories_action_pairs(elem, code, class_name, types, class_methods);
+
+                    methods.push(method);
+                    methods_code.push_str(&method_code);
+                },
+                _ => unreachable!()
+            }
+        }
+
+        for elem in statics {
+            if let Some((_, declaration)) = get_static_declaration(&elem, code, types, class_methods) {
+                code.add_struct(&format!("\n{}", declaration));
+            }
+        }
+
+        code.counter_seeds.clear();
+
+        if !methods_code.is_empty() {
+            code.add_struct(&format!("\n{}pub struct {};\n", source, class_name.trim()));
+
+            let mut repository_impl = RustImpl::new("", None, class_name.trim());
+            repository_impl.add_item(methods_code);
+
+            code.add_struct(&repository_impl.to_code());
+            code.repository_types.push(String::from(class_name.trim()));
+        }
+    }
+
+    class_methods.add_class_method(ClassMethod { class_name: String::from(class_name), methods, constructors: Vec::new(), bases, type_parameters: Vec::new() })
+
+}
+
+fn match_controller_class_code_pairs(iter: Pair<Rule>, code: &mut Code, step: &Step, types: &mut TypeTable, class_methods: &mut ClassMethods){
+
+    let bases = get_class_declaration(&iter).bases;
+
+    let source = code.get_source_marker(&iter);
+
+    let mut class_name: &str = "";    
+
+    let mut properties: Vec<Type> = Vec::new();
+
+    let mut actions: Vec<ControllerAction> = Vec::new();
+
+    let mut routes: Vec<String> = Vec::new();
+
+    let mut route_template = String::from("");
+
+    if *step == Step::Controllers {
+        for elem in iter.into_inner() {
+            match elem.as_rule(){
+                Rule::public_key_word => {},
+                Rule::class_key_word => {},
+                Rule::attribute => {
+                    if let Some(x) = get_attribute_argument(elem, "Route") {
+                        route_template = x;
+                    }
+                },
+                Rule::constructor => match_controller_constructor_pairs(elem, class_name, types, &mut properties),
+                Rule::properties => { 
+                    let property = match_controller_properties_pairs(elem, code);
+                    properties.push(property);
+                },
+                Rule::class_name =>
+                {
+                    if class_name.is_empty() {
+                        class_name = elem.as_str();
+                    }
+                },
+                Rule::base_list => {},
+                Rule::type_parameters => {},
+                Rule::type_constraint_clause => {},
+                Rule::nested_type => {},
+                Rule::left_bracers => {},
+                Rule::right_bracers => {},
+                Rule::action => {
+                    let route_segments = get_controller_route_segments(&route_template, class_name);
+
+                    actions.push(match_controller_action_pairs(elem, code, class_name, &route_segments, types, class_methods));
+                },
+                _ => unreachable!()
+            }
+        }
+
+        let methods: Vec<Method> = actions.iter().map(|a| a.method.clone()).collect();
+
+        class_methods.add_class_method(ClassMethod { class_name: String::from(class_name), methods, constructors: Vec::new(), bases: bases.clone(), type_parameters: Vec::new() });
+
+        if !bases.iter().any(|a| is_controller_base_class(a)) && !class_name.ends_with("Controller") {
+            code.add_warning(format!("{} does not derive from ControllerBase and is not exposed as a controller by ASP.NET", class_name));
+        }
+
+        let route_segments = get_controller_route_segments(&route_template, class_name);
+
+        code.add_method(source.clone());
+
+        add_rejection_handler_code(code);
+
+        code.add_method(source);
+
+        let mut main = RustFunction::new("async fn main()");
+        main.add_attribute(String::from("#[tokio::main]"));
+
+        let get_actions: Vec<&ControllerAction> = actions.iter().filter(|a| is_routed_action(a)).collect();
+
+        let action_states: Vec<Vec<Type>> = get_actions.iter().map(|a| get_action_states(class_name, &a.bindings, types, class_methods)).collect();
+
+        let mut states = get_action_states(class_name, &[], types, class_methods);
+
+        for elem in action_states.iter().flatten() {
+            if !states.iter().any(|a| get_rust_filter_name(a) == get_rust_filter_name(elem)) {
+                states.push(elem.clone());
+            }
+        }
+
+        for elem in states.iter() {
+            let name = get_rust_name(&elem.name, NameKind::Local);
+
+            if code.database_contexts.contains(&elem.type_name) {
+                main.add_statement(format!("\n\tlet {} = {}::connect(&std::env::var(\"DATABASE_URL\").unwrap_or_else(|_| String::from(\"sqlite::memory:\"))).await.expect(\"database connection\");",
+                    name, elem.type_name));
+            }
+            else {
+                main.add_statement(format!("\n\tlet {} = {}::default();", name, elem.type_name));
+            }
+            main.add_statement(format!("\n\tlet {} = warp::any().map(move || {}.clone());", get_rust_filter_name(elem), name));
+        }
+
+        let handler_names: Vec<String> = get_actions.iter().map(|a| a.method.name.clone()).collect();
+
+        for elem in naming::find_collisions(&handler_names, NameKind::Function) {
+            code.add_failure(format!("{}: handler names {}; rename one of them", class_name, elem));
+        }
+
+        for (index, elem) in get_actions.iter().enumerate() {
+            let handler_name = get_rust_name(&elem.method.name, NameKind::Function);
+
+            let extractors = get_binding_extractors(elem, &route_segments, code, types);
+
+            routes.push(handler_name.clone());
+
+            let http_method = if elem.http_method.is_empty() { "get" } else { elem.http_method.as_str() };
+
+            let mut path = extractors.path.clone();
+            path.push(String::from("warp::path::end()"));
+
+            let mut filters: Vec<String> = path[1..].to_vec();
+            filters.push(format!("warp::{}()", http_method));
+            filters.extend(extractors.filters.iter().cloned());
+
+            for state in action_states[index].iter() {
+                let filter_name = get_rust_filter_name(state);
+
+                if action_states[index + 1..].iter().flatten().any(|a| get_rust_filter_name(a) == filter_name) {
+                    filters.push(format!("{}.clone()", filter_name));
+                }
+                else {
+                    filters.push(filter_name);
+                }
+            }
+
+            let chain: String = filters.iter().map(|a| format!("\n\t.and({})", a)).collect();
+
+            main.add_statement(format!("\n\n\tlet {} = {}{}\n\t.and_then({});", handler_name, path[0], chain, handler_name));
+        }
+
+        let mut joined_routes: Vec<String> = Vec::new();
+
+        for elem in routes.clone().into_iter() {
+            if joined_routes.is_empty() {
+                joined_routes.push(elem);
+            }
+            else {
+                joined_routes.push(format!("or({})", elem));
+            }
+        }
+
+        main.add_statement(format!("\n\n\tlet routes = {}\n\t.recover(handle_rejection);", joined_routes.join(".")));
+
+        if code.storage == Storage::Json {
+            main.add_statement(String::from("\n\n\tlet (_, server) = warp::serve(routes)\n\t\t.bind_with_graceful_shutdown(([127, 0, 0, 1], 3030), async {\n\t\t\ttokio::signal::ctrl_c().await.ok();\n\t\t});"));
+            main.add_statement(String::from("\n\n\tserver.await;"));
+        }
+        else {
+            main.add_statement(String::from("\n\n\twarp::serve(routes)\n\t\t.run(([127, 0, 0, 1], 3030))\n\t\t.await;"));
+        }
+
+        match main.to_code() {
+            Ok(x) => code.add_method(x),
+            Err(e) => code.add_failure(e)
+        }
+    }
+}
+
+fn get_rust_filter_name(state: &Type) -> String {
+    format!("{}_{}", naming::to_snake_case(&state.name), naming::to_snake_case(&state.type_name))
+}
+
+fn is_controller_base_class(value: &str) -> bool {
+    value == "ControllerBase" || value == "Controller"
+}
+
+fn get_attribute_argument(iter: Pair<Rule>, name: &str) -> Option<String> {
+    get_attribute_named_argument(iter, name, "")
+}
+
+fn get_attribute_named_argument(iter: Pair<Rule>, name: &str, argument: &str) -> Option<String> {
+
+    let mut is_attribute = false;
+
+    let mut argument_name = "";
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { is_attribute = elem.as_str() == name; },
+            Rule::attribute_name => { argument_name = elem.as_str(); },
+            Rule::string if is_attribute && argument_name == argument => return Some(String::from(elem.as_str().trim_matches('"'))),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn get_attribute_name(iter: &Pair<Rule>) -> String {
+    iter.clone().into_inner().find(|a| a.as_rule() == Rule::identifier).map(|a| String::from(a.as_str())).unwrap_or_default()
+}
+
+fn get_action_route_template(iter: &Pair<Rule>) -> Option<String> {
+
+    let name = get_attribute_name(iter);
+
+    match name.as_str() {
+        "Route" | "HttpGet" | "HttpPost" | "HttpPut" | "HttpDelete" | "HttpPatch" => get_attribute_argument(iter.clone(), &name),
+        _ => None
+    }
+}
+
+fn get_route_parameter_name(segment: &str) -> Option<String> {
+
+    let name = segment.strip_prefix('{')?.strip_suffix('}')?;
+
+    let name = name.trim_start_matches('*').split([':', '=', '?']).next()?;
+
+    Some(String::from(name))
+}
+
+fn get_binding_source(name: &str) -> Option<BindingSource> {
+
+    match name {
+        "FromBody" => Some(BindingSource::Body),
+        "FromQuery" => Some(BindingSource::Query),
+        "FromRoute" => Some(BindingSource::Route),
+        "FromHeader" => Some(BindingSource::Header),
+        "FromForm" => Some(BindingSource::Form),
+        "FromServices" => Some(BindingSource::Services),
+        _ => None
+    }
+}
+
+fn is_simple_binding_type(value: &str, types: &TypeTable) -> bool {
+
+    let simple_types = ["string", "int", "long", "short", "byte", "bool", "decimal", "double", "float", "char",
+        "Guid", "DateTime", "DateTimeOffset", "DateOnly", "TimeOnly", "TimeSpan", "Uri"];
+
+    simple_types.contains(&value) || types.is_enum_type(value)
+}
+
+fn get_implicit_binding_source(parameter: &Parameter, route_names: &[String], types: &TypeTable, class_methods: &ClassMethods) -> BindingSource {
+
+    if is_interface_name(&parameter.type_name) && class_methods.find_implementation(&parameter.type_name).is_some() {
+        BindingSource::Services
+    }
+    else if !is_simple_binding_type(&parameter.type_name, types) {
+        BindingSource::Body
+    }
+    else if route_names.iter().any(|a| a.eq_ignore_ascii_case(&parameter.name)) {
+        BindingSource::Route
+    }
+    else {
+        BindingSource::Query
+    }
+}
+
+fn get_parameter_bindings(iter: Pair<Rule>, template: &str, types: &TypeTable, class_methods: &ClassMethods) -> Vec<Binding> {
+
+    let route_names: Vec<String> = template.split('/').filter_map(get_route_parameter_name).collect();
+
+    let mut bindings: Vec<Binding> = Vec::new();
+
+    for elem in iter.into_inner().filter(|a| a.as_rule() == Rule::action_parameter) {
+        let mut parameter = Parameter { name: String::from(""), type_name: String::from("") };
+
+        let mut source: Option<BindingSource> = None;
+
+        let mut name: Option<String> = None;
+
+        let mut default_value: Option<String> = None;
+
+        let mut is_optional = false;
+
+        for inner in elem.into_inner() {
+            match inner.as_rule() {
+                Rule::attribute => {
+                    let attribute_name = get_attribute_name(&inner);
+
+                    if let Some(x) = get_binding_source(&attribute_name) {
+                        source = Some(x);
+                        name = get_attribute_named_argument(inner, &attribute_name, "Name");
+                    }
+                },
+                Rule::property_type => { parameter.type_name = String::from(inner.as_str().trim()); },
+                Rule::nullable_marker => { is_optional = true; },
+                Rule::identifier => { parameter.name = String::from(inner.as_str()); },
+                Rule::parameter_default => {
+                    default_value = get_rust_parameter_default(inner, &parameter.type_name, types);
+                    is_optional = true;
+                },
+                _ => unreachable!()
+            }
+        }
+
+        let source = source.unwrap_or_else(|| get_implicit_binding_source(&parameter, &route_names, types, class_methods));
+
+        let name = name.unwrap_or(parameter.name.clone());
+
+        bindings.push(Binding { parameter, source, name, default_value, is_optional });
+    }
+
+    bindings
+}
+
+fn get_rust_parameter_default(iter: Pair<Rule>, type_name: &str, types: &TypeTable) -> Option<String> {
+
+    let value = iter.into_inner().next()?;
+
+    match value.as_rule() {
+        Rule::string_literal => Some(format!("String::from({})", value.as_str())),
+        Rule::number if type_name == "decimal" && !value.as_str().contains('.') => Some(format!("{}.0", value.as_str())),
+        Rule::number => Some(String::from(value.as_str())),
+        Rule::property_call if value.as_str() == "null" => None,
+        Rule::property_call if types.is_enum_type(type_name) => {
+            let member = value.as_str().rsplit('.').next().unwrap_or_default();
+
+            Some(format!("{}::{}", get_rust_name(type_name, NameKind::Type), get_rust_name(member, NameKind::Type)))
+        },
+        _ => Some(String::from(value.as_str()))
+    }
+}
+
+fn get_binding_struct_name(method_name: &str, source: &BindingSource) -> String {
+
+    let suffix = if *source == BindingSource::Form { "Form" } else { "Query" };
+
+    format!("{}{}", get_rust_name(method_name, NameKind::Type), suffix)
+}
+
+fn get_grouped_bindings<'a>(bindings: &'a [Binding], source: &BindingSource, types: &TypeTable) -> Vec<&'a Binding> {
+    bindings.iter().filter(|a| a.source == *source && is_simple_binding_type(&a.parameter.type_name, types)).collect()
+}
+
+fn add_binding_structs_code(method_name: &str, bindings: &[Binding], code: &mut Code, types: &TypeTable) {
+
+    for source in [BindingSource::Query, BindingSource::Form] {
+        let grouped = get_grouped_bindings(bindings, &source, types);
+
+        if grouped.is_empty() {
+            continue;
+        }
+
+        let mut rust_struct = RustStruct::new(&get_binding_struct_name(method_name, &source));
+
+        rust_struct.add_attribute(String::from("#[derive(Debug, Deserialize)]"));
+        rust_struct.is_public = true;
+
+        for elem in grouped {
+            let field_name = get_rust_name(&elem.parameter.name, NameKind::Field);
+
+            let field_type = get_rust_binding_type(elem);
+
+            if naming::get_wire_name(&field_name) == elem.name {
+                rust_struct.add_field(format!("   pub {}: {},\n", field_name, field_type));
+            }
+            else {
+                rust_struct.add_field(format!("   #[serde(rename = \"{}\")]\n   pub {}: {},\n", elem.name, field_name, field_type));
+            }
+        }
+
+        code.add_struct(&rust_struct.to_code());
+    }
+}
+
+fn get_rust_binding_type(binding: &Binding) -> String {
+
+    let rust_type = get_rust_type_name(&binding.parameter.type_name);
+
+    if binding.is_optional { format!("Option<{}>", rust_type) } else { rust_type }
+}
+
+fn get_binding_extractors(action: &ControllerAction, controller_segments: &[String], code: &mut Code, types: &TypeTable) -> Extractors {
+
+    let mut extractors = Extractors::default();
+
+    let method_name = &action.method.name;
+
+    let template = action.template.replace("[action]", &method_name.to_lowercase());
+
+    let is_absolute = template.starts_with('/') || template.starts_with("~/");
+
+    let mut segments: Vec<String> = if is_absolute { Vec::new() } else { controller_segments.to_vec() };
+
+    if template.is_empty() {
+        segments.push(method_name.to_lowercase());
+    }
+
+    segments.extend(template.trim_start_matches('~').split('/').filter(|a| !a.is_empty()).map(String::from));
+
+    let route_bindings: Vec<&Binding> = action.bindings.iter().filter(|a| a.source == BindingSource::Route).collect();
+
+    let unplaced: Vec<String> = route_bindings.iter()
+        .filter(|a| !segments.iter().any(|b| get_route_parameter_name(b).is_some_and(|c| c.eq_ignore_ascii_case(&a.parameter.name))))
+        .map(|a| format!("{{{}}}", a.parameter.name)).collect();
+
+    segments.extend(unplaced);
+
+    for segment in segments.iter() {
+        match get_route_parameter_name(segment) {
+            Some(x) => match route_bindings.iter().find(|a| a.parameter.name.eq_ignore_ascii_case(&x)) {
+                Some(binding) => {
+                    if binding.is_optional || segment.contains('?') {
+                        code.add_warning(format!("{}: optional route parameter {} is translated as required", method_name, binding.parameter.name));
+                    }
+
+                    extractors.path.push(format!("warp::path::param::<{}>()", get_rust_type_name(&binding.parameter.type_name)));
+                    extractors.parameters.push(format!("{}{}: {}", get_parameter_binding(&binding.parameter.name, types), get_rust_name(&binding.parameter.name, NameKind::Local),
+                        get_rust_type_name(&binding.parameter.type_name)));
+                },
+                None => {
+                    code.add_warning(format!("{}: route parameter {} is not bound to an action parameter", method_name, x));
+                    extractors.path.push(String::from("warp::path::param::<String>()"));
+                    extractors.parameters.push(format!("_{}: String", get_rust_name(&x, NameKind::Local)));
+                }
+            },
+            None => extractors.path.push(format!("warp::path(\"{}\")", segment.to_lowercase()))
+        }
+    }
+
+    let mut body_count = 0;
+
+    for source in [BindingSource::Query, BindingSource::Form] {
+        let grouped = get_grouped_bindings(&action.bindings, &source, types);
+
+        if grouped.is_empty() {
+            continue;
+        }
+
+        let struct_name = get_binding_struct_name(method_name, &source);
+
+        let fields: Vec<String> = grouped.iter().map(|a| get_rust_name(&a.parameter.name, NameKind::Field)).collect();
+
+        if source == BindingSource::Form {
+            extractors.filters.push(format!("warp::body::form::<{}>()", struct_name));
+            body_count += 1;
+        }
+        else {
+            extractors.filters.push(format!("warp::query::<{}>()", struct_name));
+        }
+
+        extractors.parameters.push(format!("{} {{ {} }}: {}", struct_name, fields.join(", "), struct_name));
+    }
+
+    for elem in action.bindings.iter() {
+        let name = get_rust_name(&elem.parameter.name, NameKind::Local);
+
+        let rust_type = get_rust_type_name(&elem.parameter.type_name);
+
+        let is_simple = is_simple_binding_type(&elem.parameter.type_name, types);
+
+        let filter = match elem.source {
+            BindingSource::Query if !is_simple => get_validated_filter(format!("warp::query::<{}>()", rust_type), &rust_type, code),
+            BindingSource::Form if !is_simple => get_validated_filter(format!("warp::body::form::<{}>()", rust_type), &rust_type, code),
+            BindingSource::Header if elem.is_optional => format!("warp::header::optional::<{}>(\"{}\")", rust_type, elem.name),
+            BindingSource::Header => format!("warp::header::<{}>(\"{}\")", rust_type, elem.name),
+            BindingSource::Body => get_validated_filter(format!("warp::body::json::<{}>()", rust_type), &rust_type, code),
+            _ => String::from("")
+        };
+
+        if filter.is_empty() {
+            continue;
+        }
+
+        if (elem.source == BindingSource::Body || elem.source == BindingSource::Form) && elem.is_optional {
+            code.add_warning(format!("{}: optional body parameter {} is translated as required", method_name, elem.parameter.name));
+        }
+
+        if elem.source == BindingSource::Body || elem.source == BindingSource::Form {
+            body_count += 1;
+        }
+
+        let parameter_type = if elem.source == BindingSource::Header { get_rust_binding_type(elem) } else { rust_type };
+
+        extractors.filters.push(filter);
+        extractors.parameters.push(format!("{}{}: {}", get_parameter_binding(&elem.parameter.name, types), name, parameter_type));
+    }
+
+    if body_count > 1 {
+        code.add_warning(format!("{}: only one parameter can be read from the request body", method_name));
+    }
+
+    for elem in action.bindings.iter().filter(|a| a.source == BindingSource::Query || a.source == BindingSource::Header || a.source == BindingSource::Form) {
+        let name = get_rust_name(&elem.parameter.name, NameKind::Local);
+
+        let is_grouped = elem.source != BindingSource::Header && is_simple_binding_type(&elem.parameter.type_name, types);
+
+        if !is_grouped && elem.source != BindingSource::Header {
+            continue;
+        }
+
+        match &elem.default_value {
+            Some(x) if x.starts_with("String::from") => extractors.defaults.push(format!("let {} = {}.unwrap_or_else(|| {});", name, name, x)),
+            Some(x) => extractors.defaults.push(format!("let {} = {}.unwrap_or({});", name, name, x)),
+            None => {}
+        }
+    }
+
+    extractors
+}
+
+fn get_validated_filter(filter: String, rust_type: &str, code: &Code) -> String {
+
+    if !code.is_validated_type(rust_type) {
+        return filter;
+    }
+
+    format!("{}.and_then(validate_request::<{}>)", filter, rust_type)
+}
+
+fn get_controller_route_segments(route_template: &str, class_name: &str) -> Vec<String> {
+
+    let controller_name = class_name.trim().trim_end_matches("Controller").to_lowercase();
+
+    let route_template = if route_template.is_empty() { "[controller]" } else { route_template };
+
+    route_template.replace("[controller]", &controller_name)
+        .split('/')
+        .filter(|a| !a.is_empty())
+        .map(|a| a.to_lowercase())
+        .collect()
+}
+
+fn match_controller_properties_pairs(iter: Pair<Rule>, code: &mut Code) -> Type {
+
+    let mut property_type: Vec<Type> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::attribute => {},
+            Rule::public_key_word => {},
+            Rule::private_key_word => {},
+            Rule::static_key_word => {},
+            Rule::required_key_word => {},
+            Rule::readonly_key_word => {},
+            Rule::assignment => return match_assignment_properties_pairs(elem, &property_type.pop().expect("")),
+            Rule::property_type => { match_property_type_code_pairs(elem, code, &mut property_type); },
+            Rule::nullable_marker => {},
+            Rule::identifier => 
+            {
+                let prop_type = property_type.pop().expect("");
+                return Type { name: String::from(elem.as_str()), type_name: prop_type.type_name, kind: Kind::Property, properties: Vec::new() };
+            },
+            Rule::left_bracers => {}
+            Rule::get_key_word => {}
+            Rule::semicolon => {}
+            Rule::set_key_word => {}
+            Rule::init_key_word => {}
+            Rule::right_bracers => {}
+            _ => unreachable!()
+        }
+    }
+
+    Type::new()
+}
+
+fn match_database_context_pairs(iter: Pair<Rule>, code: &mut Code, types: &mut TypeTable) {
+
+    let source = code.get_source_marker(&iter);
+
+    let class_name = get_class_declaration(&iter).class_name;
+
+    let mut properties: Vec<Type> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::properties => {
+                let property = match_models_properties_pairs(elem, code, &mut RustStruct::new(&class_name), &mut Vec::new());
+
+                if get_generic_type_name(&property.type_name) == "DbSet" {
+                    code.database_tables.push(DatabaseTable { name: property.name.clone(), entity: get_list_element_type(&property.type_name), scope: types.scope.clone() });
+                    properties.push(property);
+                }
+                else {
+                    code.add_warning(format!("{}.{} is not a DbSet and is not translated", class_name, property.name));
+                }
+            },
+            Rule::action => {
+                let method = get_method_declaration(elem);
+
+                if method.name != "OnModelCreating" {
+                    code.add_warning(format!("{}.{} is not translated", class_name, method.name));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    code.add_using(String::from("use sqlx::sqlite::SqlitePool;\n"));
+    code.add_using(String::from("use parking_lot::Mutex;\n"));
+
+    let mut fields = String::from("");
+
+    let mut sets = String::from("");
+
+    let mut schemas: Vec<String> = Vec::new();
+
+    for elem in properties.iter() {
+        let field = get_rust_name(&elem.name, NameKind::Field);
+
+        let entity = get_list_element_type(&elem.type_name);
+
+        fields.push_str(&format!("   pub {}: {}<{}>,\n", field, code.get_runtime_name("DbSet"), entity));
+        sets.push_str(&format!("\t\t\t{}: {}::new(pool.clone(), changes.clone()),\n", field, code.get_runtime_name("DbSet")));
+        schemas.push(format!("{}::SCHEMA", entity));
+    }
+
+    let error = code.get_runtime_name("Error");
+
+    code.add_struct(&format!("\n{}pub struct {} {{\n   pub pool: SqlitePool,\n   pub changes: Arc<Mutex<Vec<{}>>>,\n{}}}\n", source, class_name, code.get_runtime_name("Change"), fields));
+
+    let mut inherent_impl = RustImpl::new("", None, &class_name);
+    inherent_impl.add_item(format!("\n\tpub fn new(pool: SqlitePool) -> Self {{\n\t\tlet changes = Arc::new(Mutex::new(Vec::new()));\n\n\t\t{} {{\n{}\t\t\tpool,\n\t\t\tchanges\n\t\t}}\n\t}}\n",
+        class_name, sets));
+    inherent_impl.add_item(format!("\n\tpub async fn connect(url: &str) -> Result<Self, {}> {{\n\t\tlet context = {}::new(connect_database(url).await?);\n\n\t\tcontext.ensure_created().await?;\n\n\t\tOk(context)\n\t}}\n",
+        error, class_name));
+    inherent_impl.add_item(format!("\n\tpub async fn ensure_created(&self) -> Result<(), {}> {{\n\t\tfor elem in [{}] {{\n\t\t\tsqlx::query(elem).execute(&self.pool).await?;\n\t\t}}\n\n\t\tOk(())\n\t}}\n",
+        error, schemas.join(", ")));
+    inherent_impl.add_item(format!("\n\tpub async fn save_changes(&self) -> Result<i32, {}> {{\n\t\tsave_changes(&self.pool, &self.changes).await\n\t}}\n", error));
+
+    code.add_struct(&inherent_impl.to_code());
+
+    let mut clone_impl = RustImpl::new("", Some("Clone"), &class_name);
+    clone_impl.add_item(format!("\n\tfn clone(&self) -> Self {{\n\t\t{}::new(self.pool.clone())\n\t}}\n", class_name));
+
+    code.add_struct(&clone_impl.to_code());
+    code.add_struct("\n");
+
+    types.add_type(Type { name: class_name.clone(), type_name: class_name, kind: Kind::Class, properties });
+}
+
+fn match_models_properties_pairs(iter: Pair<Rule>, code: &mut Code, rust_struct: &mut RustStruct, validations: &mut Vec<String>) -> Type {
+
+    let source = code.get_source_marker(&iter);
+
+    let mut property_type: Vec<Type> = Vec::new();
+
+    let mut rust_prop_type = String::from("");
+
+    let mut kind = Kind::Property;
+
+    let mut attributes: Vec<Pair<Rule>> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::attribute => attributes.push(elem),
+            Rule::public_key_word => {},
+            Rule::private_key_word => {},
+            Rule::static_key_word => {},
+            Rule::required_key_word => { kind = Kind::Required; },
+            Rule::readonly_key_word => {},
+            Rule::assignment => return match_assignment_properties_pairs(elem, &property_type.pop().expect("")),
+            Rule::property_type => { rust_prop_type = match_property_type_code_pairs(elem, code, &mut property_type) },
+            Rule::nullable_marker => {
+                rust_prop_type = format!("Option<{}>", rust_prop_type);
+
+                if let Some(x) = property_type.last_mut() {
+                    x.type_name.push('?');
+                }
+            },
+            Rule::identifier => 
+            {
+                let prop_type = property_type.pop().expect("");
+
+                let element_type = rust_prop_type.strip_prefix("Arc<RwLock<Vec<").and_then(|a| a.strip_suffix(">>>")).map(String::from);
+
+                let is_navigation = code.is_navigation_property(&rust_struct.name, &prop_type.type_name);
+
+                let default = match (is_navigation, element_type) {
+                    (true, Some(x)) => {
+                        rust_prop_type = format!("Vec<{}>", x);
+                        "   #[serde(default)]\n"
+                    },
+                    (true, None) => {
+                        rust_prop_type = format!("Option<Box<{}>>", rust_prop_type.strip_prefix("Option<").and_then(|a| a.strip_suffix('>')).unwrap_or(&rust_prop_type));
+                        "   #[serde(default, skip_serializing_if = \"Option::is_none\")]\n"
+                    },
+                    (false, Some(x)) => {
+                        if let Some(store_type) = code.get_store_type(&rust_struct.name, elem.as_str()) {
+                            rust_prop_type = format!("Arc<{}<{}>>", store_type, x);
+                        }
+
+                        ""
+                    },
+                    (false, None) => ""
+                };
+
+                rust_struct.add_field(format!("{}{}{}", source, default, get_rust_field_declaration(elem.as_str(), &rust_prop_type)));
+
+                for attribute in attributes.iter() {
+                    validations.push(get_rust_validation_code(attribute, &attributes, elem.as_str(), &rust_prop_type, code));
+                }
+
+                return Type { name: String::from(elem.as_str()), type_name: prop_type.type_name, kind, properties: Vec::new() };
+            },
+            Rule::left_bracers => {}
+            Rule::get_key_word => {}
+            Rule::semicolon => {}
+            Rule::set_key_word => {}
+            Rule::init_key_word => {}
+            Rule::right_bracers => {}
+            _ => unreachable!()
+        }
+    }
+
+    Type::new()
+}
+
+fn get_rust_field_declaration(name: &str, rust_type: &str) -> String {
+
+    let field_name = get_rust_name(name, NameKind::Field);
+
+    let wire_name = naming::to_camel_case(name);
+
+    if naming::get_wire_name(&field_name) == wire_name {
+        return format!("   pub {}: {},\n", field_name, rust_type);
+    }
+
+    format!("   #[serde(rename = \"{}\")]\n   pub {}: {},\n", wire_name, field_name, rust_type)
+}
+
+fn get_attribute_arguments(iter: &Pair<Rule>) -> Vec<(String, String)> {
+
+    let mut arguments: Vec<(String, String)> = Vec::new();
+
+    let mut argument_name = "";
+
+    for elem in iter.clone().into_inner() {
+        let text = elem.as_str().trim();
+
+        let value = match elem.as_rule() {
+            Rule::attribute_name => {
+                argument_name = text;
+                continue;
+            },
+            Rule::string => get_rust_escaped_string(&text[1..text.len() - 1]),
+            Rule::verbatim_string => text[2..text.len() - 1].replace("\"\"", "\"").replace('\\', "\\\\").replace('"', "\\\""),
+            Rule::typeof_expression => elem.into_inner().find(|a| a.as_rule() == Rule::identifier).map(|a| String::from(a.as_str())).unwrap_or_default(),
+            Rule::number | Rule::property_call => String::from(text),
+            _ => continue
+        };
+
+        arguments.push((String::from(argument_name), value));
+
+        argument_name = "";
+    }
+
+    arguments
+}
+
+fn get_rust_range_bound(value: &str, rust_type: &str) -> String {
+
+    match value {
+        "int.MinValue" => String::from("i32::MIN"),
+        "int.MaxValue" => String::from("i32::MAX"),
+        "double.MinValue" | "decimal.MinValue" => String::from("f64::MIN"),
+        "double.MaxValue" | "decimal.MaxValue" => String::from("f64::MAX"),
+        x if rust_type == "f64" && !x.contains('.') => format!("{}.0", x),
+        x => String::from(x)
+    }
+}
+
+fn get_rust_validation_code(attribute: &Pair<Rule>, attributes: &[Pair<Rule>], property_name: &str, rust_type: &str, code: &mut Code) -> String {
+
+    let name = get_attribute_name(attribute);
+
+    let arguments = get_attribute_arguments(attribute);
+
+    let positional: Vec<&str> = arguments.iter().filter(|a| a.0.is_empty()).map(|a| a.1.as_str()).collect();
+
+    let get_named = |key: &str| arguments.iter().find(|a| a.0 == key).map(|a| a.1.clone());
+
+    let display_name = attributes.iter().filter(|a| get_attribute_name(a) == "Display")
+        .find_map(|a| get_attribute_arguments(a).into_iter().find(|b| b.0 == "Name").map(|b| b.1))
+        .unwrap_or_else(|| String::from(property_name));
+
+    let member = format!("self.{}", get_rust_name(property_name, NameKind::Field));
+
+    let (rust_type, is_optional) = match rust_type.strip_prefix("Option<").and_then(|a| a.strip_suffix('>')) {
+        Some(x) => (x, true),
+        None => (rust_type, false)
+    };
+
+    let field = if is_optional { get_rust_name(property_name, NameKind::Local) } else { member.clone() };
+
+    let is_string = rust_type == "String";
+
+    let is_number = matches!(rust_type, "i32" | "i64" | "f64");
+
+    let length = if is_string {
+        Some(format!("{}.chars().count()", field))
+    }
+    else if rust_type.starts_with("Vec<") {
+        Some(format!("{}.len()", field))
+    }
+    else if rust_type.starts_with("Arc<RwLock<Vec<") {
+        Some(format!("{}.read().len()", field))
+    }
+    else {
+        None
+    };
+
+    let (condition, message, values) = match (name.as_str(), positional.as_slice()) {
+        ("Required", _) if is_optional && is_string && get_named("AllowEmptyStrings").as_deref() == Some("true") => (format!("{}.is_none()", member), "The {0} field is required.", Vec::new()),
+        ("Required", _) if is_optional && is_string => (format!("{}.as_ref().is_none_or(|a| a.trim().is_empty())", member), "The {0} field is required.", Vec::new()),
+        ("Required", _) if is_optional => (format!("{}.is_none()", member), "The {0} field is required.", Vec::new()),
+        ("Required", _) if is_string && get_named("AllowEmptyStrings").as_deref() == Some("true") => return String::from(""),
+        ("Required", _) if is_string => (format!("{}.trim().is_empty()", field), "The {0} field is required.", Vec::new()),
+        ("Required", _) => return String::from(""),
+        ("StringLength", [max, ..]) if is_string => match get_named("MinimumLength") {
+            Some(min) => (format!("{}.chars().count() < {} || {}.chars().count() > {}", field, min, field, max),
+                "The field {0} must be a string with a minimum length of {2} and a maximum length of {1}.", vec![String::from(*max), min]),
+            None => (format!("{}.chars().count() > {}", field, max), "The field {0} must be a string with a maximum length of {1}.", vec![String::from(*max)])
+        },
+        ("MaxLength", [max, ..]) if length.is_some() => (format!("{} > {}", length.unwrap_or_default(), max),
+            "The field {0} must be a string or array type with a maximum length of '{1}'.", vec![String::from(*max)]),
+        ("MinLength", [min, ..]) if length.is_some() => (format!("{} < {}", length.unwrap_or_default(), min),
+            "The field {0} must be a string or array type with a minimum length of '{1}'.", vec![String::from(*min)]),
+        ("Range", [.., min, max]) if is_number => (format!("!({}..={}).contains(&{})", get_rust_range_bound(min, rust_type), get_rust_range_bound(max, rust_type), field),
+            "The field {0} must be between {1} and {2}.", vec![String::from(*min), String::from(*max)]),
+        ("RegularExpression", [pattern, ..]) if is_string => (format!("!{}.is_empty() && !regex::Regex::new(\"^(?:{})$\").is_ok_and(|a| a.is_match(&{}))", field, pattern, field),
+            "The field {0} must match the regular expression '{1}'.", vec![String::from(*pattern)]),
+        ("EmailAddress", _) if is_string => (format!("!({}.matches('@').count() == 1 && !{}.starts_with('@') && !{}.ends_with('@'))", field, field, field),
+            "The {0} field is not a valid e-mail address.", Vec::new()),
+        ("Url", _) if is_string => (format!("![\"http://\", \"https://\", \"ftp://\"].iter().any(|a| {}.to_lowercase().starts_with(a))", field),
+            "The {0} field is not a valid fully-qualified http, https, or ftp URL.", Vec::new()),
+        ("StringLength" | "MaxLength" | "MinLength" | "Range" | "RegularExpression" | "EmailAddress" | "Url", _) => {
+            code.add_warning(format!("{}: [{}] is not translated for a property of type {}", property_name, name, rust_type));
+            return String::from("");
+        },
+        _ => return String::from("")
+    };
+
+    let mut message = get_named("ErrorMessage").unwrap_or_else(|| String::from(message)).replace("{0}", &display_name);
+
+    for (index, elem) in values.iter().enumerate() {
+        message = message.replace(&format!("{{{}}}", index + 1), elem);
+    }
+
+    if is_optional && name != "Required" {
+        let borrow = if is_number { "" } else { "&" };
+
+        return format!("\n\t\tif let Some({}) = {}{} {{\n\t\t\tif {} {{\n\t\t\t\terrors.entry(String::from(\"{}\")).or_default().push(String::from(\"{}\"));\n\t\t\t}}\n\t\t}}\n",
+            field, borrow, member, condition, property_name, message);
+    }
+
+    format!("\n\t\tif {} {{\n\t\t\terrors.entry(String::from(\"{}\")).or_default().push(String::from(\"{}\"));\n\t\t}}\n", condition, property_name, message)
+}
+
+fn match_repositories_properties_pairs(iter: Pair<Rule>, code: &mut Code) -> Type {
+
+    let mut property_type: Vec<Type> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::attribute => {},
+            Rule::public_key_word => {},
+            Rule::private_key_word => {},
+            Rule::required_key_word => {},
+            Rule::readonly_key_word => {},
+            Rule::assignment => return match_assignment_properties_pairs(elem, &property_type.pop().expect("")),
+            Rule::property_type => { match_property_type_code_pairs(elem, code, &mut property_type); },
+            Rule::nullable_marker => {},
+            Rule::identifier => 
+            {
+                let prop_type = property_type.pop().expect("");
+                return Type { name: String::from(elem.as_str()), type_name: prop_type.type_name, kind: Kind::Property, properties: Vec::new() };
+            },
+            Rule::left_bracers => {}
+            Rule::get_key_word => {}
+            Rule::semicolon => {}
+            Rule::set_key_word => {}
+            Rule::init_key_word => {}
+            Rule::right_bracers => {}
+            _ => unreachable!()
+        }
+    }
+
+    Type::new()
+}
+
+fn get_static_declaration(iter: &Pair<Rule>, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> Option<(Type, String)> {
+
+    let source = code.get_source_marker(iter);
+
+    let mut kind = Kind::Property;
+
+    let mut visibility = "";
+
+    let mut type_name = String::from("");
+
+    let mut name = String::from("");
+
+    let mut value: Option<Pair<Rule>> = None;
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::public_key_word => { visibility = "pub "; },
+            Rule::static_key_word => { kind = Kind::Static; },
+            Rule::const_key_word => { kind = Kind::Const; },
+            Rule::readonly_key_word if kind == Kind::Static => { kind = Kind::Readonly; },
+            Rule::property_type => { type_name = String::from(elem.as_str().trim()); },
+            Rule::identifier => { name = String::from(elem.as_str().trim()); },
+            Rule::assignment => {
+                let (_, target, x) = get_assignment_parts(&elem)?;
+
+                name = target;
+                value = Some(x);
+            },
+            Rule::expression_body => return None,
+            _ => {}
+        }
+    }
+
+    if kind == Kind::Property {
+        return None;
+    }
+
+    let constant_name = get_rust_name(&name, NameKind::Constant);
+
+    let rust_type = get_rust_type_name(&type_name);
+
+    let declaration = match (kind, get_rust_atomic_type(&type_name)) {
+        (Kind::Const, _) => match value {
+            Some(x) if x.as_rule() == Rule::string_literal && rust_type == "String" => {
+                format!("{}const {}: &str = {};", visibility, constant_name, x.as_str().trim())
+            },
+            Some(x) => format!("{}const {}: {} = {};", visibility, constant_name, rust_type, get_rust_expression_value(x, &type_name, code, types, class_methods)),
+            None => return None
+        },
+        (Kind::Static, Some(x)) => {
+            code.add_using(format!("use std::sync::atomic::{};\n", x));
+            code.add_using(String::from("use std::sync::atomic::Ordering;\n"));
+
+            let initial = match value {
+                Some(a) => String::from(a.as_str().trim()),
+                None if x == "AtomicBool" => String::from("false"),
+                None => String::from("0")
+            };
+
+            match code.counter_seeds.get(&constant_name).cloned() {
+                Some(seed) => {
+                    code.add_using(String::from("use std::sync::LazyLock;\n"));
+
+                    format!("{}static {}: LazyLock<{}> = LazyLock::new(|| {}::new({}.max({})));", visibility, constant_name, x, x, seed, initial)
+                },
+                None => format!("{}static {}: {} = {}::new({});", visibility, constant_name, x, x, initial)
+            }
+        },
+        _ => {
+            code.add_using(String::from("use std::sync::LazyLock;\n"));
+
+            let initial = match value {
+                Some(a) => get_rust_expression_value(a, &type_name, code, types, class_methods),
+                None => String::from("Default::default()")
+            };
+
+            if kind == Kind::Readonly {
+                format!("{}static {}: LazyLock<{}> = LazyLock::new(|| {});", visibility, constant_name, rust_type, initial)
+            }
+            else {
+                code.add_using(String::from("use parking_lot::Mutex;\n"));
+
+                format!("{}static {}: LazyLock<Mutex<{}>> = LazyLock::new(|| Mutex::new({}));", visibility, constant_name, rust_type, initial)
+            }
+        }
+    };
+
+    Some((Type { name, type_name, kind, properties: Vec::new() }, format!("{}{}\n", source, declaration)))
+}
+
+fn get_rust_atomic_type(type_name: &str) -> Option<&'static str> {
+
+    match type_name.trim() {
+        "int" => Some("AtomicI32"),
+        "long" => Some("AtomicI64"),
+        "bool" => Some("AtomicBool"),
+        _ => None
+    }
+}
+
+fn match_controller_constructor_pairs(iter: Pair<Rule>, class_name: &str, types: &mut TypeTable, properties: &mut [Type]) {
+
+    types.add_type(Type { name: String::from(class_name), type_name: String::from(class_name), kind: Kind::Class, properties: properties.to_vec() });
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::constructor_parameters => {},
+            Rule::code => {},
+            Rule::public_key_word => {},
+            Rule::identifier => { },
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::left_bracers => {},
+            Rule::right_bracers => {},
+            _ => unreachable!()
+        }
+    }
+}
+
+fn match_models_constructor_pairs(iter: Pair<Rule>, code: &mut Code, class_name: &str, types: &mut TypeTable,
+    properties: &mut [Type], constructor: &Method, class_methods: &mut ClassMethods) -> String {
+
+    let mut chained_constructor = (Rule::this_key_word, String::from(""));
+
+    let mut constructor_code = String::from("");
+
+    types.add_type(Type { name: String::from(class_name).replace(" ", ""), type_name: String::from(class_name).replace(" ", ""),
+        kind: Kind::Class, properties: properties.to_vec() });
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::constructor_initializer => { chained_constructor = match_constructor_initializer_pairs(elem, code, constructor, types, class_methods); },
+            Rule::code => { constructor_code = match_models_constructor_code_pairs(elem, code, class_name, types, class_methods, constructor, &chained_constructor); },
+            Rule::constructor_parameters => { types.enter_method(&get_parameter_declarations(elem)); },
+            Rule::public_key_word => {},
+            Rule::identifier => {},
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::left_bracers => {},
+            Rule::right_bracers => {},
+            _ => unreachable!()
+        }
+    }
+
+    constructor_code
+}
+
+fn match_constructor_initializer_pairs(iter: Pair<Rule>, code: &mut Code, constructor: &Method, types: &TypeTable, class_methods: &mut ClassMethods) -> (Rule, String) {
+
+    let line = iter.as_span().start_pos().line_col().0;
+
+    let mut is_base = false;
+
+    let mut arguments: Vec<Pair<Rule>> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::base_key_word => { is_base = true; },
+            Rule::this_key_word => {},
+            Rule::parameters => { arguments = elem.into_inner().collect(); },
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            _ => unreachable!()
+        }
+    }
+
+    if is_base {
+        return match class_methods.get_base_class(&constructor.return_type) {
+            Some(x) => (Rule::base_key_word, format!("{}::{}", x, get_rust_constructor_call(&x, arguments, line, code, types, class_methods))),
+            None => {
+                code.add_warning(format!("line {}: base constructor call in {} is not translated", line, constructor.return_type));
+                (Rule::base_key_word, String::from(""))
+            }
+        };
+    }
+
+    (Rule::this_key_word, format!("Self::{}", get_rust_constructor_call(&constructor.return_type, arguments, line, code, types, class_methods)))
+}
+
+fn match_controller_action_pairs(iter: Pair<Rule>, code: &mut Code, class_name: &str, route_segments: &[String], types: &mut TypeTable,
+    class_methods: &mut ClassMethods) -> ControllerAction {
+
+    let source = code.get_source_marker(&iter);
+
+    let mut action = ControllerAction {
+        method: Method { name: String::from(""), return_type: String::from(""), parameters: Vec::new(), type_parameters: Vec::new(), kind: Kind::Method },
+        http_method: String::from(""),
+        template: String::from(""),
+        bindings: Vec::new()
+    };
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::action_parameters => {
+                action.method.parameters = match_parameters_pairs(elem.clone());
+                action.bindings = get_parameter_bindings(elem, &action.template, types, class_methods);
+            },
+            Rule::code => { 
+                types.enter_class(class_name);
+                types.enter_body(&elem, &action.method.parameters, &code.mutating_methods);
+
+                if is_routed_action(&action) {
+                    let extractors = get_binding_extractors(&action, route_segments, code, types);
+
+                    add_binding_structs_code(&action.method.name, &action.bindings, code, types);
+
+                    match_controller_code_pairs(elem, code, class_name, &action, &extractors, types, class_methods)
+                }
+            },
+            Rule::attribute => {
+                if let Some(x) = get_action_http_method(&elem) {
+                    action.http_method = x;
+                }
+
+                if let Some(x) = get_action_route_template(&elem) {
+                    action.template = x;
+                }
+            },
+            Rule::public_key_word => {},
+            Rule::static_key_word => {},
+            Rule::action_return_type => action.method.return_type = String::from(elem.as_str()),
+            Rule::action_async_return_type => action.method.return_type = String::from(elem.as_str()),
+            Rule::method_return_type => action.method.return_type = String::from(elem.as_str()),
+            Rule::async_key_word => {},
+            Rule::identifier => {
+                action.method.name = String::from(elem.as_str());
+                
+                if is_routed_action(&action) {
+                    code.add_method(source.clone());
+                }
+            },
+            Rule::type_parameters => {},
+            Rule::type_constraint_clause => {},
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::left_bracers => {},
+            Rule::right_bracers => {},
+            _ => unreachable!()
+        }
+    }
+
+    action
+}
+
+fn is_get_action(method: &Method) -> bool {
+    method.name.to_lowercase().contains("get")
+}
+
+fn is_routed_action(action: &ControllerAction) -> bool {
+    !action.http_method.is_empty() || is_get_action(&action.method)
+}
+
+fn get_action_http_method(iter: &Pair<Rule>) -> Option<String> {
+
+    match get_attribute_name(iter).as_str() {
+        "HttpGet" => Some(String::from("get")),
+        "HttpPost" => Some(String::from("post")),
+        "HttpPut" => Some(String::from("put")),
+        "HttpDelete" => Some(String::from("delete")),
+        "HttpPatch" => Some(String::from("patch")),
+        _ => None
+    }
+}
+
+fn get_service_states(type_name: &str, types: &TypeTable, class_methods: &ClassMethods) -> Vec<Type> {
+
+    match types.find_type(&class_methods.get_implementation_name(type_name.trim())) {
+        Some(x) => x.properties.iter().filter(|a| a.kind == Kind::Property).cloned().collect(),
+        None => Vec::new()
+    }
+}
+
+fn get_action_states(class_name: &str, bindings: &[Binding], types: &TypeTable, class_methods: &ClassMethods) -> Vec<Type> {
+
+    let mut service_types: Vec<String> = types.find_type(class_name).map(|a| a.properties.iter().map(|b| b.type_name.clone()).collect()).unwrap_or_default();
+
+    service_types.extend(bindings.iter().filter(|a| a.source == BindingSource::Services).map(|a| a.parameter.type_name.clone()));
+
+    let mut states: Vec<Type> = Vec::new();
+
+    for elem in service_types.iter() {
+        for state in get_service_states(elem, types, class_methods) {
+            if !states.iter().any(|a| get_rust_filter_name(a) == get_rust_filter_name(&state)) {
+                states.push(state);
+            }
+        }
+    }
+
+    states
+}
+
+fn match_models_action_pairs(iter: Pair<Rule>, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods, properties: &[Type]) -> (Method, String) {
+
+    let mut method = Method { name: String::from(""), return_type: String::from(""), parameters: Vec::new(), type_parameters: get_type_parameters(&iter), kind: Kind::Method };
+
+    let is_extension = is_extension_method(&iter);
+
+    let mut body = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::action_parameters => { method.parameters = match_parameters_pairs(elem); },
+            Rule::code => {
+                types.enter_body(&elem, &method.parameters, &code.mutating_methods);
+
+                body = match_models_method_code_pairs(elem, code, types, class_methods, properties, &method);
+            },
+            Rule::attribute => {},
+            Rule::public_key_word => {},
+            Rule::protected_key_word => {},
+            Rule::static_key_word => method.kind = Kind::Static,
+            Rule::abstract_key_word => method.kind = Kind::Abstract,
+            Rule::virtual_key_word => method.kind = Kind::Virtual,
+            Rule::override_key_word => method.kind = Kind::Override,
+            Rule::action_return_type => method.return_type = String::from(elem.as_str()),
+            Rule::action_async_return_type => method.return_type = String::from(elem.as_str()),
+            Rule::method_return_type => method.return_type = String::from(elem.as_str()),
+            Rule::async_key_word => {},
+            Rule::identifier => {
+                method.name = String::from(elem.as_str());
+            },
+            Rule::type_parameters => {},
+            Rule::type_constraint_clause => {},
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::left_bracers => {},
+            Rule::right_bracers => {},
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    if is_extension {
+        method.kind = Kind::Extension;
+    }
+
+    (method, body)
+}
+
+fn match_models_method_code_pairs(iter: Pair<Rule>, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods,
+    properties: &[Type], method: &Method) -> String {
+
+    let properties = get_class_properties(properties, types);
+
+    let statements: Vec<Pair<Rule>> = iter.into_inner().filter(|a| a.as_rule() != Rule::semicolon && a.as_rule() != Rule::await_key_word).collect();
+
+    let last_index = statements.len();
+
+    let is_fallible = code.is_fallible_method(&types.class_name, &method.name);
+
+    let is_diverging = statements.last().is_some_and(|a| a.as_rule() == Rule::throw_statement);
+
+    let mut body = String::from("");
+
+    let mut is_return = false;
+
+    let mut is_returned = false;
+
+    let mut return_source = String::from("");
+
+    let members: Vec<&Type> = properties.iter().filter(|a| !method.parameters.iter().any(|b| b.name == a.name) && types.find_usage(&a.name).is_none()).collect();
+
+    let member_values = members.into_iter().map(|a| (a.name.clone(), get_rust_member_value(&a.name, &properties, method, code, types, true))).collect();
+
+    code.member_values = member_values;
+
+    for (index, elem) in statements.into_iter().enumerate() {
+        types.position = elem.as_span().end();
+
+        if is_return {
+            let value = get_rust_return_value(elem, &properties, method, code, types, class_methods);
+
+            let value = if is_fallible { format!("Ok({})", value) } else { value };
+
+            if index + 1 == last_index {
+                is_returned = true;
+                body.push_str(&format!("\t\t{}{}\n", return_source, value));
+            }
+            else {
+                body.push_str(&format!("\t\t{}return {};\n", return_source, value));
+            }
+
+            is_return = false;
+            continue;
+        }
+
+        match elem.as_rule(){
+            Rule::return_key_word => {
+                is_return = true;
+                return_source = code.get_source_marker(&elem);
+            },
+            _ => {
+                let line = elem.as_span().start_pos().line_col().0;
+
+                let (statement, _) = match_statement_code_pairs(elem, None, BodyContext::Method(&properties, method), code, types, class_methods, 2);
+
+                if statement.is_empty() {
+                    code.add_warning(format!("line {}: statement in {} is not translated", line, method.name));
+                }
+
+                body.push_str(&statement);
+            }
+        }
+    }
+
+    if is_fallible && !is_returned && !is_diverging {
+        body.push_str("\t\tOk(())\n");
+    }
+
+    code.member_values.clear();
+
+    body
+}
+
+fn get_rust_return_value(iter: Pair<Rule>, properties: &[Type], method: &Method, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    match iter.as_rule(){
+        Rule::property_call => get_rust_member_value(iter.as_str(), properties, method, code, types, false),
+        Rule::math_exp => get_rust_math_expression(iter, properties, method, code, types),
+        _ => get_rust_expression_value(iter, &method.return_type, code, types, class_methods)
+    }
+}
+
+fn get_class_properties(properties: &[Type], types: &TypeTable) -> Vec<Type> {
+
+    let mut class_properties: Vec<Type> = Vec::new();
+
+    for elem in properties.iter() {
+        if elem.kind == Kind::Base {
+            if let Some(x) = types.find_type(&elem.type_name) {
+                let base_properties = x.properties.clone();
+
+                class_properties.append(&mut get_class_properties(&base_properties, types));
+            }
+        }
+        else {
+            class_properties.push(elem.clone());
+        }
+    }
+
+    class_properties
+}
+
+fn get_rust_member_value(value: &str, properties: &[Type], method: &Method, code: &Code, types: &mut TypeTable, is_borrow: bool) -> String {
+
+    let name = value.trim().trim_start_matches("this.");
+
+    if method.parameters.iter().any(|a| a.name == name) || types.find_usage(name).is_some() {
+        return get_rust_local_value(name, types);
+    }
+
+    if let Some(x) = get_rust_static_value(name, properties) {
+        return x;
+    }
+
+    match properties.iter().find(|a| a.name == name) {
+        Some(x) => {
+            if is_copy_type(&x.type_name, types) || is_borrow {
+                format!("self.{}", get_rust_name(name, NameKind::Field))
+            }
+            else {
+                format!("self.{}.clone()", get_rust_name(name, NameKind::Field))
+            }
+        },
+        None => {
+            let split_property: Vec<&str> = name.split('.').map(|a| a.trim()).collect();
+
+            if split_property.len() > 1 && types.is_enum_type(split_property[0]) {
+                format!("{}::{}", split_property[0], get_rust_name(split_property[1], NameKind::Type))
+            }
+            else if let [member, "Length" | "Count"] = split_property.as_slice() {
+                let class_name = types.class_name.clone();
+
+                get_rust_length(&get_rust_member_value(member, properties, method, code, types, true), &class_name, member, code, types)
+            }
+            else if split_property.len() > 1 && properties.iter().any(|a| a.name == split_property[0]) {
+                format!("self.{}", get_rust_member_path(name))
+            }
+            else {
+                get_rust_member_path(name)
+            }
+        }
+    }
+}
+
+fn get_rust_math_expression(iter: Pair<Rule>, properties: &[Type], method: &Method, code: &Code, types: &mut TypeTable) -> String {
+
+    let mut expression: Vec<String> = Vec::new();
+
+    let is_comparison = iter.clone().into_inner().any(|a| a.as_rule() == Rule::bool_exp ||
+        (a.as_rule() == Rule::math_op && matches!(a.as_str().trim(), ">" | ">=" | "<" | "<=" | "!=" | "==")));
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier | Rule::property_call => expression.push(get_rust_member_value(elem.as_str(), properties, method, code, types, is_comparison)),
+            Rule::number => expression.push(String::from(elem.as_str().trim())),
+            Rule::math_op => expression.push(String::from(elem.as_str().trim())),
+            Rule::bool_exp => expression.push(String::from(elem.as_str().trim())),
+            Rule::math_exp => expression.push(get_rust_math_expression(elem, properties, method, code, types)),
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    expression.join(" ")
+}
+
+fn get_rust_length(value: &str, owner_type: &str, member: &str, code: &Code, types: &TypeTable) -> String {
+
+    if is_locked_list_property(owner_type, member, code, types) {
+        format!("{}.read().len() as i32", value)
+    }
+    else {
+        format!("{}.len() as i32", value)
+    }
+}
+
+fn is_locked_list_property(owner_type: &str, member: &str, code: &Code, types: &TypeTable) -> bool {
+
+    let property_type = types.find_type(owner_type).and_then(|a| a.properties.iter().find(|b| b.name == member)).map(|a| a.type_name.clone()).unwrap_or_default();
+
+    is_list_type(&property_type) && !code.is_navigation_property(owner_type, &property_type)
+}
+
+fn is_copy_type(value: &str, types: &TypeTable) -> bool {
+    value == "int" || value == "decimal" || value == "bool" || value == "char" || types.is_enum_type(value)
+}
+
+fn get_rust_local_value(name: &str, types: &TypeTable) -> String {
+
+    let rust_name = match types.find_usage(name) {
+        Some(x) => x.rust_name.clone(),
+        None => get_rust_name(name, NameKind::Local)
+    };
+
+    let type_name = types.find_local(name).map(|a| a.type_name.as_str()).unwrap_or("");
+
+    if is_copy_type(type_name, types) || !types.is_read_after(name) {
+        rust_name
+    }
+    else {
+        format!("{}.clone()", rust_name)
+    }
+}
+
+fn get_local_usages(iter: &Pair<Rule>, parameters: &[Parameter], mutating_methods: &[String]) -> Vec<LocalUsage> {
+
+    let mut usages: Vec<LocalUsage> = parameters.iter()
+        .map(|a| LocalUsage { name: a.name.clone(), rust_name: get_rust_name(&a.name, NameKind::Local), reads: Vec::new(), is_mutated: false }).collect();
+
+    add_local_usages(iter.clone(), &mut usages, mutating_methods);
+
+    usages
+}
+
+fn add_local_usages(iter: Pair<Rule>, usages: &mut Vec<LocalUsage>, mutating_methods: &[String]) {
+
+    match iter.as_rule(){
+        Rule::assignment => {
+            let mut is_declaration = false;
+
+            let mut is_target = true;
+
+            for elem in iter.into_inner() {
+                match elem.as_rule(){
+                    Rule::var_key_word | Rule::property_type => { is_declaration = true; },
+                    Rule::property_call if is_target => {
+                        is_target = false;
+
+                        let name = get_local_root_name(elem.as_str());
+
+                        if is_declaration {
+                            usages.push(LocalUsage { name: String::from(name), rust_name: get_rust_name(name, NameKind::Local), reads: Vec::new(), is_mutated: false });
+                        }
+                        else if let Some(x) = usages.iter_mut().rev().find(|a| a.name == name) {
+                            x.is_mutated = true;
+                        }
+                    },
+                    _ => add_local_usages(elem, usages, mutating_methods)
+                }
+            }
+        },
+        Rule::method_call => {
+            let mut path: Vec<Pair<Rule>> = Vec::new();
+
+            let mut is_receiver = true;
+
+            for elem in iter.into_inner() {
+                match elem.as_rule(){
+                    Rule::identifier if is_receiver => path.push(elem),
+                    Rule::method_invocation => {
+                        let method_name = elem.clone().into_inner().next().map(|a| a.as_str().trim()).unwrap_or("");
+
+                        let is_mutating = is_mutating_method(&elem) || mutating_methods.iter().any(|a| a.rsplit('.').next() == Some(method_name));
+
+                        if is_receiver && path.len() == 1 && is_mutating {
+                            if let Some(x) = usages.iter_mut().rev().find(|a| a.name == path[0].as_str().trim()) {
+                                x.is_mutated = true;
+                            }
+                        }
+
+                        if let Some(x) = path.first().filter(|_| is_receiver) {
+                            add_local_read(x.as_str(), x.as_span().start(), usages);
+                        }
+
+                        is_receiver = false;
+
+                        for argument in elem.into_inner().skip(1) {
+                            add_local_usages(argument, usages, mutating_methods);
+                        }
+                    },
+                    _ => {}
+                }
+            }
+        },
+        Rule::member_initializer => {
+            for elem in iter.into_inner().skip(1) {
+                add_local_usages(elem, usages, mutating_methods);
+            }
+        },
+        Rule::property_call | Rule::identifier => add_local_read(iter.as_str(), iter.as_span().start(), usages),
+        _ => {
+            for elem in iter.into_inner() {
+                add_local_usages(elem, usages, mutating_methods);
+            }
+        }
+    }
+}
+
+fn add_local_read(value: &str, position: usize, usages: &mut [LocalUsage]) {
+
+    let name = get_local_root_name(value);
+
+    if let Some(x) = usages.iter_mut().rev().find(|a| a.name == name) {
+        x.reads.push(position);
+    }
+}
+
+fn get_local_root_name(value: &str) -> &str {
+    value.split('.').next().unwrap_or("").trim()
+}
+
+fn is_mutating_method(iter: &Pair<Rule>) -> bool {
+
+    let name = iter.clone().into_inner().next().map(|a| a.as_str().trim()).unwrap_or("");
+
+    matches!(name, "Add" | "AddRange" | "Insert" | "Remove" | "RemoveAll" | "RemoveAt" | "Clear" | "Sort" | "Reverse" |
+        "Push" | "Pop" | "Enqueue" | "Dequeue" | "TryAdd" | "Append" | "AppendLine")
+}
+
+fn get_rust_method_signature(method: &Method, class_name: &str, mutated: &[String], code: &Code) -> String {
+
+    let receiver = if code.is_mutating_method(class_name, &method.name) { "&mut self" } else { "&self" };
+
+    let mut parameters: Vec<String> = if method.kind == Kind::Static { Vec::new() } else { vec![String::from(receiver)] };
+
+    let skipped = if method.kind == Kind::Extension { 1 } else { 0 };
+
+    for elem in method.parameters.iter().skip(skipped) {
+        let binding = if mutated.contains(&elem.name) { "mut " } else { "" };
+
+        parameters.push(format!("{}{}: {}", binding, get_rust_name(&elem.name, NameKind::Local), get_rust_type_name(&elem.type_name)));
+    }
+
+    let is_fallible = code.is_fallible_method(class_name, &method.name);
+
+    let return_type = get_rust_type_name(&method.return_type);
+
+    let generics = get_rust_generics(&method.type_parameters, &[]);
+
+    if is_fallible {
+        let value_type = if return_type.is_empty() { String::from("()") } else { return_type };
+
+        return format!("fn {}{}({}) -> Result<{}, {}>", get_rust_method_name(&method.name), generics, parameters.join(", "), value_type, code.get_runtime_name("Error"));
+    }
+
+    if return_type.is_empty() {
+        return format!("fn {}{}({})", get_rust_method_name(&method.name), generics, parameters.join(", "));
+    }
+
+    format!("fn {}{}({}) -> {}", get_rust_method_name(&method.name), generics, parameters.join(", "), return_type)
+}
+
+fn match_repositories_action_pairs(iter: Pair<Rule>, code: &mut Code, class_name: &str, types: &mut TypeTable, class_methods: &mut ClassMethods) -> (Method, String) {
+
+    let source = code.get_source_marker(&iter);
+
+    let mut method = Method { name: String::from(""), return_type: String::from(""), parameters: Vec::new(), type_parameters: Vec::new(), kind: Kind::Method };
+
+    let mut body = String::from("");
+
+    let mut mutated: Vec<String> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::action_parameters => { method.parameters = match_parameters_pairs(elem); },
+            Rule::code => {
+                types.enter_class(class_name);
+                types.enter_body(&elem, &method.parameters, &code.mutating_methods);
+
+                mutated = method.parameters.iter().filter(|a| types.find_usage(&a.name).is_some_and(|b| b.is_mutated)).map(|a| a.name.clone()).collect();
+
+                body = match_repositories_method_code_pairs(elem, code, class_name, types, class_methods, &method);
+            },
+            Rule::attribute => {},
+            Rule::public_key_word => {},
+            Rule::static_key_word => {},
+            Rule::action_return_type => method.return_type = String::from(elem.as_str()),
+            Rule::action_async_return_type => method.return_type = String::from(elem.as_str()),
+            Rule::method_return_type => method.return_type = String::from(elem.as_str()),
+            Rule::async_key_word => {},
+            Rule::identifier => {
+                method.name = String::from(elem.as_str());
+            },
+            Rule::type_parameters => {},
+            Rule::type_constraint_clause => {},
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::left_bracers => {},
+            Rule::right_bracers => {},
+            _ => unreachable!()
+        }
+    }
+
+    let mut parameters: Vec<String> = get_repository_states(class_name, types).iter()
+        .map(|a| format!("{}: &{}", get_rust_name(&a.name, NameKind::Local), a.type_name)).collect();
+
+    for elem in method.parameters.iter() {
+        let binding = if mutated.contains(&elem.name) { "mut " } else { "" };
+
+        parameters.push(format!("{}{}: {}", binding, get_rust_name(&elem.name, NameKind::Local), get_rust_type_name(&elem.type_name)));
+    }
+
+    let is_async = get_repository_states(class_name, types).iter().any(|a| code.database_contexts.contains(&a.type_name));
+
+    let return_type = if is_async { get_awaited_type(&method.return_type) } else { method.return_type.clone() };
+
+    let return_type = match get_rust_type_name(&return_type) {
+        x if x.is_empty() || x == "()" => String::from("()"),
+        x => x
+    };
+
+    if is_async {
+        code.async_methods.push(format!("{}.{}", class_name, method.name));
+    }
+
+    let method_code = format!("\n\t{}pub {}fn {}({}) -> Result<{}, {}> {{\n{}\t}}\n", source, if is_async { "async " } else { "" },
+        get_rust_name(&method.name, NameKind::Function), parameters.join(", "), return_type, code.get_runtime_name("Error"), body);
+
+    (method, method_code)
+}
+
+fn get_repository_states(class_name: &str, types: &TypeTable) -> Vec<Type> {
+    types.find_type(class_name).map(|a| a.properties.iter().filter(|b| b.kind == Kind::Property).cloned().collect()).unwrap_or_default()
+}
+
+fn match_repositories_method_code_pairs(iter: Pair<Rule>, code: &mut Code, class_name: &str, types: &mut TypeTable, class_methods: &mut ClassMethods,
+    method: &Method) -> String {
+
+    let class_type = types.find_type(class_name).cloned().unwrap_or_else(Type::new);
+
+    let statements: Vec<Pair<Rule>> = iter.into_inner().filter(|a| a.as_rule() != Rule::semicolon && a.as_rule() != Rule::await_key_word).collect();
+
+    let mut body = String::from("");
+
+    let mut is_return = false;
+
+    let mut is_returned = false;
+
+    let mut is_skipped = false;
+
+    for (index, elem) in statements.iter().cloned().enumerate() {
+        if is_skipped {
+            is_skipped = false;
+            continue;
+        }
+
+        types.position = elem.as_span().end();
+
+        let line = elem.as_span().start_pos().line_col().0;
+
+        let source = code.get_source_marker(&elem);
+
+        if is_return {
+            let value = get_rust_repository_value(elem, &class_type, method, code, types, class_methods);
+
+            if index + 1 == statements.len() {
+                body.push_str(&format!("\t\t{}Ok({})\n", source, value));
+                is_returned = true;
+            }
+            else {
+                body.push_str(&format!("\t\t{}return Ok({});\n", source, value));
+            }
+
+            is_return = false;
+            continue;
+        }
+
+        if elem.as_rule() == Rule::return_key_word {
+            is_return = true;
+            continue;
+        }
+
+        let (statement, is_fused) = match_statement_code_pairs(elem, statements.get(index + 1), BodyContext::Repository(&class_type, method), code, types, class_methods, 2);
+
+        is_skipped = is_fused;
+
+        if statement.is_empty() {
+            code.add_warning(format!("line {}: statement in {} is not translated", line, method.name));
+        }
+
+        body.push_str(&statement);
+    }
+
+    let is_diverging = statements.last().is_some_and(|a| a.as_rule() == Rule::throw_statement);
+
+    if !is_returned && !is_diverging {
+        body.push_str("\t\tOk(())\n");
+    }
+
+    body
+}
+
+fn get_rust_repository_value(iter: Pair<Rule>, class_type: &Type, method: &Method, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    match iter.as_rule(){
+        Rule::property_call => match (get_rust_store_path(iter.as_str(), class_type, code, types), get_rust_static_value(iter.as_str(), &class_type.properties)) {
+            (Some((x, _, true)), _) => format!("{}.to_list()?", x),
+            (Some((x, _, false)), _) => format!("{}.read().clone()", x),
+            (None, Some(x)) => x,
+            _ => match get_rust_database_set(iter.as_str(), class_type, code, types) {
+                Some(x) => format!("{}.to_list().await?", x),
+                None => get_rust_expression_value(iter, &method.return_type, code, types, class_methods)
+            }
+        },
+        Rule::method_call => match get_rust_store_call(&iter, class_type, code, types, class_methods).or_else(|| get_rust_database_call(&iter, class_type, code, types, class_methods)) {
+            Some(x) => x,
+            None => get_rust_expression_value(iter, &method.return_type, code, types, class_methods)
+        },
+        _ => get_rust_expression_value(iter, &method.return_type, code, types, class_methods)
+    }
+}
+
+fn get_rust_static_value(value: &str, properties: &[Type]) -> Option<String> {
+
+    let property = properties.iter().find(|a| a.name == value.trim())?;
+
+    let name = get_rust_name(&property.name, NameKind::Constant);
+
+    match property.kind {
+        Kind::Const if property.type_name == "string" => Some(format!("String::from({})", name)),
+        Kind::Const => Some(name),
+        Kind::Readonly => Some(format!("{}.clone()", name)),
+        Kind::Static if get_rust_atomic_type(&property.type_name).is_some() => Some(format!("{}.load(Ordering::SeqCst)", name)),
+        Kind::Static => Some(format!("{}.lock().clone()", name)),
+        _ => None
+    }
+}
+
+fn get_rust_static_assignment(iter: &Pair<Rule>, class_type: &Type, method: &Method, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> Option<String> {
+
+    let (is_declaration, target, value) = get_assignment_parts(iter)?;
+
+    if is_declaration {
+        let root = get_local_root_name(value.as_str());
+
+        if !matches!(value.as_rule(), Rule::property_call | Rule::method_call) || types.find_usage(root).is_some() || !class_type.properties.iter().any(|a| a.name == root) {
+            return None;
+        }
+
+        let target_type = match iter.clone().into_inner().find(|a| a.as_rule() == Rule::property_type) {
+            Some(x) => String::from(x.as_str().trim()),
+            None => get_expression_type(&value, types, class_methods)
+        };
+
+        let annotation = if value.as_rule() == Rule::method_call { String::from("") } else { get_rust_local_type(&target_type, types, class_methods) };
+
+        let binding = get_local_binding(true, &target, types);
+
+        let value = get_rust_repository_value(value, class_type, method, code, types, class_methods);
+
+        types.add_local(&target, &target_type);
+
+        if annotation.is_empty() {
+            return Some(format!("{}{} = {};", binding, get_rust_name(&target, NameKind::Local), value));
+        }
+
+        return Some(format!("{}{}: {} = {};", binding, get_rust_name(&target, NameKind::Local), annotation, value));
+    }
+
+    let property = class_type.properties.iter().find(|a| a.name == target && a.kind == Kind::Static && get_rust_atomic_type(&a.type_name).is_none())?;
+
+    let name = get_rust_name(&property.name, NameKind::Constant);
+
+    let is_moved = value.as_rule() == Rule::property_call && !is_copy_type(&get_expression_type(&value, types, class_methods), types);
+
+    let value = get_rust_repository_value(value, class_type, method, code, types, class_methods);
+
+    if is_moved && !value.ends_with(".clone()") {
+        return Some(format!("*{}.lock() = {}.clone();", name, value));
+    }
+
+    Some(format!("*{}.lock() = {};", name, value))
+}
+
+fn get_rust_static_member_assignment(iter: &Pair<Rule>, properties: &[Type], method: &Method, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> Option<String> {
+
+    let (is_declaration, target, value) = get_assignment_parts(iter)?;
+
+    if is_declaration {
+        let value = get_rust_static_value(value.as_str(), properties).filter(|_| value.as_rule() == Rule::property_call)?;
+
+        return Some(format!("let {} = {};", get_rust_name(&target, NameKind::Local), value));
+    }
+
+    let property = properties.iter().find(|a| a.name == target && a.kind == Kind::Static)?;
+
+    let name = get_rust_name(&property.name, NameKind::Constant);
+
+    let value = get_rust_return_value(value, properties, method, code, types, class_methods);
+
+    match get_rust_atomic_type(&property.type_name) {
+        Some(_) => Some(format!("{}.store({}, Ordering::SeqCst);", name, value)),
+        None => Some(format!("*{}.lock() = {};", name, value))
+    }
+}
+
+fn get_rust_store_path(value: &str, class_type: &Type, code: &Code, types: &TypeTable) -> Option<(String, String, bool)> {
+
+    let path: Vec<&str> = value.split('.').map(|a| a.trim()).collect();
+
+    if path.len() != 2 {
+        return None;
+    }
+
+    let state = class_type.properties.iter().find(|a| matches!(a.kind, Kind::Property | Kind::Readonly) && a.name == path[0])?;
+
+    let property = types.find_type(&state.type_name)?.properties.iter().find(|a| a.name == path[1])?;
+
+    if !is_list_type(&property.type_name) {
+        return None;
+    }
+
+    let state_name = if state.kind == Kind::Readonly { get_rust_name(&state.name, NameKind::Constant) } else { get_rust_name(&state.name, NameKind::Local) };
+
+    let is_backend = code.get_store_type(&state.type_name, &property.name).is_some();
+
+    Some((format!("{}.{}", state_name, get_rust_name(&property.name, NameKind::Field)), get_list_element_type(&property.type_name), is_backend))
+}
+
+fn get_rust_store_call(iter: &Pair<Rule>, class_type: &Type, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> Option<String> {
+
+    let mut path: Vec<&str> = Vec::new();
+
+    let mut invocation: Option<Pair<Rule>> = None;
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::identifier if invocation.is_none() => path.push(elem.as_str().trim()),
+            Rule::method_invocation if invocation.is_none() => { invocation = Some(elem); },
+            Rule::semicolon => {},
+            _ => return None
+        }
+    }
+
+    let (store, element_type, is_backend) = get_rust_store_path(&path.join("."), class_type, code, types)?;
+
+    let lock = if is_backend { "" } else { ".write()" };
+
+    let key = types.find_key(&element_type).cloned();
+
+    let mut method_name = "";
+
+    let mut arguments: Vec<String> = Vec::new();
+
+    let mut lambda: Vec<String> = Vec::new();
+
+    for elem in invocation?.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { method_name = elem.as_str(); },
+            Rule::parameters => { arguments = elem.into_inner().map(|a| get_rust_parameter(a, code, types, class_methods)).collect(); },
+            Rule::lambda_expression => { lambda = elem.into_inner().map(|a| String::from(a.as_str().trim())).collect(); },
+            _ => {}
+        }
+    }
+
+    match (method_name, arguments.as_slice(), &key) {
+        ("Add", [x], Some(_)) => Some(format!("{}.insert({})?", store, x)),
+        ("Add", [x], None) => Some(format!("{}.write().push({})", store, x)),
+        ("Remove", [x], Some(_)) => Some(format!("{}.remove(&{}.key())?", store, x)),
+        ("Find", [x], Some(_)) => Some(format!("{}.find(&{})?", store, x)),
+        ("Clear", [], _) => Some(format!("{}{}.clear(){}", store, lock, if is_backend { "?" } else { "" })),
+        ("RemoveAll", [], _) if lambda.len() == 4 => {
+            let key_path = key.map(|a| format!("{}.{}", lambda[0], a.property)).filter(|_| lambda[2] == "==");
+
+            if key_path.as_ref() == Some(&lambda[1]) {
+                Some(format!("{}.remove(&{})?", store, get_rust_member_path(&lambda[3])))
+            }
+            else if key_path.as_ref() == Some(&lambda[3]) {
+                Some(format!("{}.remove(&{})?", store, get_rust_member_path(&lambda[1])))
+            }
+            else {
+                let operator = match lambda[2].as_str() {
+                    "==" => "!=",
+                    "!=" => "==",
+                    ">" => "<=",
+                    ">=" => "<",
+                    "<" => ">=",
+                    _ => ">"
+                };
+
+                Some(format!("{}{}.retain(|{}| {} {} {}){}", store, lock, get_rust_name(&lambda[0], NameKind::Local),
+                    get_rust_member_path(&lambda[1]), operator, get_rust_member_path(&lambda[3]), if is_backend { "?" } else { "" }))
+            }
+        },
+        _ => None
+    }
+}
+
+fn get_rust_database_call(iter: &Pair<Rule>, class_type: &Type, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> Option<String> {
+
+    let mut path: Vec<&str> = Vec::new();
+
+    let mut invocations: Vec<(String, Vec<String>, Vec<String>)> = Vec::new();
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::identifier if invocations.is_empty() => path.push(elem.as_str().trim()),
+            Rule::method_invocation => {
+                let mut method_name = String::from("");
+
+                let mut arguments: Vec<String> = Vec::new();
+
+                let mut lambda: Vec<String> = Vec::new();
+
+                for invocation in elem.into_inner() {
+                    match invocation.as_rule(){
+                        Rule::identifier => { method_name = String::from(invocation.as_str().trim()); },
+                        Rule::parameters => { arguments = invocation.into_inner().map(|a| get_rust_parameter(a, code, types, class_methods)).collect(); },
+                        Rule::lambda_expression => { lambda = invocation.into_inner().map(|a| String::from(a.as_str().trim())).collect(); },
+                        _ => {}
+                    }
+                }
+
+                invocations.push((method_name, arguments, lambda));
+            },
+            Rule::semicolon => {},
+            _ => return None
+        }
+    }
+
+    let state = class_type.properties.iter().find(|a| a.kind == Kind::Property && Some(&a.name.as_str()) == path.first() && code.database_contexts.contains(&a.type_name))?;
+
+    let context = get_rust_name(&state.name, NameKind::Local);
+
+    if path.len() == 1 {
+        return match invocations.as_slice() {
+            [(x, _, _)] if x == "SaveChanges" || x == "SaveChangesAsync" => Some(format!("{}.save_changes().await?", context)),
+            _ => None
+        };
+    }
+
+    let set_path = get_rust_database_set(&path.join("."), class_type, code, types)?;
+
+    let (filter, calls) = match invocations.as_slice() {
+        [(x, _, lambda), rest @ ..] if x == "Where" => (Some(get_database_filter(lambda)?), rest),
+        x => (None, x)
+    };
+
+    let (method_name, arguments, lambda) = match calls {
+        [] => (String::from("ToList"), Vec::new(), Vec::new()),
+        [x] => x.clone(),
+        _ => return None
+    };
+
+    let filter = match (filter, lambda.is_empty()) {
+        (Some(x), true) => Some(x),
+        (None, false) => Some(get_database_filter(&lambda)?),
+        (None, true) => None,
+        _ => return None
+    };
+
+    match (method_name.trim_end_matches("Async"), arguments.as_slice(), filter) {
+        ("ToList" | "ToArray" | "AsEnumerable", [], None) => Some(format!("{}.to_list().await?", set_path)),
+        ("ToList" | "ToArray" | "AsEnumerable", [], Some(x)) => Some(format!("{}.filter({}).await?", set_path, x)),
+        ("Find", [x], None) => Some(format!("{}.find({}).await?", set_path, x)),
+        ("Add", [x], None) => Some(format!("{}.add({})?", set_path, x)),
+        ("Update", [x], None) => Some(format!("{}.update({})?", set_path, x)),
+        ("Remove", [x], None) => Some(format!("{}.remove({})?", set_path, x)),
+        ("FirstOrDefault" | "SingleOrDefault", [], Some(x)) => Some(format!("{}.filter({}).await?.into_iter().next()", set_path, x)),
+        ("Any", [], Some(x)) => Some(format!("!{}.filter({}).await?.is_empty()", set_path, x)),
+        ("Any", [], None) => Some(format!("!{}.to_list().await?.is_empty()", set_path)),
+        ("Count", [], Some(x)) => Some(format!("{}.filter({}).await?.len() as i32", set_path, x)),
+        ("Count", [], None) => Some(format!("{}.to_list().await?.len() as i32", set_path)),
+        ("ExecuteDelete", [], Some(x)) => Some(format!("{}.execute_delete({}).await?", set_path, x)),
+        _ => None
+    }
+}
+
+fn get_rust_database_set(value: &str, class_type: &Type, code: &Code, types: &TypeTable) -> Option<String> {
+
+    let path: Vec<&str> = value.split('.').map(|a| a.trim()).collect();
+
+    let [state, set] = path.as_slice() else {
+        return None;
+    };
+
+    let state = class_type.properties.iter().find(|a| a.kind == Kind::Property && a.name == *state && code.database_contexts.contains(&a.type_name))?;
+
+    let set = types.find_type(&state.type_name)?.properties.iter().find(|a| a.name == *set)?;
+
+    Some(format!("{}.{}", get_rust_name(&state.name, NameKind::Local), get_rust_name(&set.name, NameKind::Field)))
+}
+
+fn get_rust_database_assignment(iter: &Pair<Rule>, class_type: &Type, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> Option<String> {
+
+    let (is_declaration, target, value) = get_assignment_parts(iter)?;
+
+    let value = match value.as_rule() {
+        Rule::method_call => get_rust_database_call(&value, class_type, code, types, class_methods)?,
+        Rule::property_call => format!("{}.to_list().await?", get_rust_database_set(value.as_str(), class_type, code, types)?),
+        _ => return None
+    };
+
+    let binding = get_local_binding(is_declaration, &target, types);
+
+    Some(format!("{}{} = {};", binding, get_rust_name(&target, NameKind::Local), value))
+}
+
+fn get_parameter_binding(name: &str, types: &TypeTable) -> &'static str {
+    if types.find_usage(name).is_some_and(|a| a.is_mutated) { "mut " } else { "" }
+}
+
+fn get_local_binding(is_declaration: bool, target: &str, types: &TypeTable) -> &'static str {
+
+    match (is_declaration, types.find_usage(target).is_some_and(|a| a.is_mutated)) {
+        (true, true) => "let mut ",
+        (true, false) => "let ",
+        _ => ""
+    }
+}
+
+fn get_database_filter(lambda: &[String]) -> Option<String> {
+
+    let [parameter, left, operator, right] = lambda else {
+        return None;
+    };
+
+    let prefix = format!("{}.", parameter);
+
+    let (column, value, operator) = match (left.strip_prefix(&prefix), right.strip_prefix(&prefix)) {
+        (Some(x), None) => (x, right, operator.as_str()),
+        (None, Some(x)) => (x, left, match operator.as_str() {
+            ">" => "<",
+            ">=" => "<=",
+            "<" => ">",
+            "<=" => ">=",
+            x => x
+        }),
+        _ => return None
+    };
+
+    if column.contains('.') {
+        return None;
+    }
+
+    let operator = match operator {
+        "==" => "=",
+        "!=" => "<>",
+        x => x
+    };
+
+    let value = match value {
+        x if x.starts_with('"') => format!("String::from({})", x),
+        x if x.starts_with(|a: char| a.is_ascii_digit()) => x.clone(),
+        x if x.contains('.') => format!("{}.clone()", get_rust_member_path(x)),
+        x => get_rust_member_path(x)
+    };
+
+    Some(format!("\"{}\", \"{}\", {}", column, operator, value))
+}
+
+fn get_assignment_parts<'a>(iter: &Pair<'a, Rule>) -> Option<(bool, String, Pair<'a, Rule>)> {
+
+    let mut is_declaration = false;
+
+    let mut target: Option<String> = None;
+
+    let mut value: Option<Pair<Rule>> = None;
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::var_key_word | Rule::property_type => { is_declaration = true; },
+            Rule::property_call if target.is_none() => { target = Some(String::from(elem.as_str().trim())); },
+            Rule::await_key_word | Rule::semicolon => {},
+            _ => { value = Some(elem); }
+        }
+    }
+
+    Some((is_declaration, target?, value?))
+}
+
+fn get_counter_increment(iter: &Pair<Rule>) -> Option<(String, String, &'static str)> {
+
+    let (_, target, value) = get_assignment_parts(iter)?;
+
+    if value.as_rule() != Rule::math_exp {
+        return None;
+    }
+
+    let expression: String = value.as_str().chars().filter(|a| !a.is_whitespace()).collect();
+
+    let rest = expression.trim_end_matches(';').strip_prefix(&target)?;
+
+    match (rest.strip_prefix('+'), rest.strip_prefix('-')) {
+        (Some(x), _) if x.parse::<i64>().is_ok() => Some((target, String::from(x), "fetch_add")),
+        (_, Some(x)) if x.parse::<i64>().is_ok() => Some((target, String::from(x), "fetch_sub")),
+        _ => None
+    }
+}
+
+fn get_counter_seed(iter: &Pair<Rule>, class_type: &Type, method: &Method, code: &Code, types: &TypeTable) -> Option<(String, String)> {
+
+    let (_, target, value) = get_assignment_parts(iter)?;
+
+    let (root, property) = target.split_once('.')?;
+
+    let parameter = method.parameters.iter().find(|a| a.name == root)?;
+
+    let key = types.find_key(&parameter.type_name).filter(|a| a.property == property)?;
+
+    let (store, _, _) = class_type.properties.iter().filter(|a| matches!(a.kind, Kind::Property | Kind::Readonly))
+        .flat_map(|a| types.find_type(&a.type_name).into_iter().flat_map(|b| b.properties.iter()).map(move |b| format!("{}.{}", a.name, b.name)))
+        .filter_map(|a| get_rust_store_path(&a, class_type, code, types))
+        .find(|a| a.2 && get_simple_type_name(&a.1) == get_simple_type_name(&parameter.type_name))?;
+
+    Some((get_rust_name(value.as_str().trim(), NameKind::Constant), format!("{0}.to_list().unwrap_or_else(|e| panic!(\"{0}: {{}}\", e)).iter().map(|a| a.{1} + 1).max().unwrap_or_default()", store, get_rust_name(&key.property, NameKind::Field))))
+}
+
+fn get_rust_counter_assignment(iter: &Pair<Rule>, next: Option<&Pair<Rule>>, properties: &[Type]) -> Option<(String, bool)> {
+
+    let counters: Vec<&Type> = properties.iter().filter(|a| a.kind == Kind::Static && get_rust_atomic_type(&a.type_name).is_some()).collect();
+
+    let get_counter = |value: &str| counters.iter().find(|a| a.name == value.trim()).map(|a| get_rust_name(&a.name, NameKind::Constant));
+
+    let (is_declaration, target, value) = get_assignment_parts(iter)?;
+
+    let target_path = if is_declaration { format!("let {}", get_rust_name(&target, NameKind::Local)) } else { get_rust_member_path(&target) };
+
+    if let Some(counter) = get_counter(value.as_str()) {
+        return match next.and_then(get_counter_increment) {
+            Some((x, amount, operation)) if x == value.as_str().trim() => {
+                Some((format!("{} = {}.{}({}, Ordering::SeqCst);", target_path, counter, operation, amount), true))
+            },
+            _ => Some((format!("{} = {}.load(Ordering::SeqCst);", target_path, counter), false))
+        };
+    }
+
+    let counter = get_counter(&target)?;
+
+    if let Some((_, amount, operation)) = get_counter_increment(iter) {
+        let read = next.and_then(get_assignment_parts).filter(|a| a.2.as_rule() == Rule::property_call && a.2.as_str().trim() == target);
+
+        if let Some((is_next_declaration, next_target, _)) = read {
+            let next_path = if is_next_declaration { format!("let {}", get_rust_name(&next_target, NameKind::Local)) } else { get_rust_member_path(&next_target) };
+
+            let sign = if operation == "fetch_add" { "+" } else { "-" };
+
+            return Some((format!("{} = {}.{}({}, Ordering::SeqCst) {} {};", next_path, counter, operation, amount, sign, amount), true));
+        }
+
+        return Some((format!("{}.{}({}, Ordering::SeqCst);", counter, operation, amount), false));
+    }
+
+    if !is_declaration && (value.as_rule() == Rule::number || matches!(value.as_str().trim(), "true" | "false")) {
+        return Some((format!("{}.store({}, Ordering::SeqCst);", counter, value.as_str().trim()), false));
+    }
+
+    None
+}
+
+fn match_controller_code_pairs(iter: Pair<Rule>, code: &mut Code, class_name: &str, action: &ControllerAction, extractors: &Extractors,
+    types: &mut TypeTable, class_methods: &mut ClassMethods){
+    
+    let mut is_return_type = false;
+
+    let mut params: Vec<String> = extractors.parameters.clone();
+
+    let param_name = get_action_states(class_name, &[], types, class_methods).last().map(|a| get_rust_name(&a.name, NameKind::Local)).unwrap_or_default();
+
+    let mut method_variables: Vec<Type> = Vec::new();
+
+    for elem in get_action_states(class_name, &action.bindings, types, class_methods) {
+        params.push(format!("{}: {}", get_rust_name(&elem.name, NameKind::Local), elem.type_name));
+    }
+
+    let return_type = if get_awaited_type(&action.method.return_type) == "IActionResult" { " -> Result<impl warp::Reply, warp::Rejection>" } else { "" };
+
+    let mut handler = RustFunction::new(&format!("async fn {}({}){}", get_rust_name(&action.method.name, NameKind::Function), params.join(", "), return_type));
+
+    handler.add_statement(String::from("\n"));
+
+    for elem in extractors.defaults.iter() {
+        handler.add_statement(format!("\t{}\n", elem));
+    }
+
+    let statements: Vec<Pair<Rule>> = iter.into_inner().collect();
+
+    let mut is_closed = false;
+
+    for (index, elem) in statements.iter().cloned().enumerate() {
+        if is_closed {
+            if elem.as_rule() != Rule::semicolon {
+                code.add_warning(format!("line {}: statement after the try block in {} is unreachable and is not translated", elem.as_span().start_pos().line_col().0, action.method.name));
+            }
+
+            continue;
+        }
+
+        types.position = elem.as_span().end();
+
+        let is_awaited = statements[index + 1..].iter().any(|a| a.as_rule() == Rule::await_key_word ||
+            a.clone().into_inner().flatten().any(|b| b.as_rule() == Rule::await_key_word));
+
+        if elem.as_rule() != Rule::semicolon && elem.as_rule() != Rule::return_key_word && elem.as_rule() != Rule::await_key_word {
+            handler.add_statement(code.get_source_marker(&elem));
+        }
+
+        match elem.as_rule(){
+            Rule::try_statement => { 
+                is_closed = is_returning_try(&elem);
+
+                let statement = match_try_statement_pairs(elem, BodyContext::Handler(class_name, action), code, types, class_methods, true, 1);
+                handler.add_statement(statement);
+            },
+            Rule::throw_statement => { 
+                let statement = match_throw_statement_pairs(elem, code, "error", true, 1, types, class_methods);
+                handler.add_statement(statement);
+            },
+            Rule::new_instance if is_return_type => {
+                for statement in match_new_instance_pairs(elem, is_awaited, &param_name, code, &method_variables, types, class_methods) {
+                    handler.add_statement(statement);
+                }
+            },
+            Rule::new_instance => {
+                if let Some(x) = elem.clone().into_inner().find(|a| a.as_rule() == Rule::parameters) {
+                    code.add_warning(format!("line {}: result object {} is not returned and is not translated", elem.as_span().start_pos().line_col().0, x.as_str().trim()));
+                }
+            },
+            Rule::method_call => match get_rust_service_call(&elem, class_name, &action.bindings, code, types, class_methods) {
+                Some(x) => handler.add_statement(format!("\t{}.map_err(warp::reject::custom)?;\n", x)),
+                None => {
+                    let statement = match_method_call_code_pairs(elem, code, types, class_methods);
+
+                    match statement.strip_suffix('?') {
+                        Some(x) => handler.add_statement(format!("\t{}.map_err(warp::reject::custom)?;\n", x)),
+                        None => handler.add_statement(format!("\t{};\n", statement))
+                    }
+                }
+            },
+            Rule::async_method_call => {},
+            Rule::await_key_word => {},
+            Rule::assignment => {
+                let service_call = get_assignment_parts(&elem)
+                    .and_then(|(is_declaration, target, value)| get_rust_service_call(&value, class_name, &action.bindings, code, types, class_methods).map(|a| (is_declaration, target, a)));
+
+                let is_state_list = get_assignment_parts(&elem).is_some_and(|a| a.2.as_rule() == Rule::property_call && a.2.as_str().contains('.'));
+
+                let mut variable = match_assignment_code_pairs(elem.clone(), types, class_methods);
+
+                if let Some((is_declaration, target, x)) = service_call {
+                    let binding = get_local_binding(is_declaration, &target, types);
+
+                    handler.add_statement(format!("\t{}{} = {}.map_err(warp::reject::custom)?;\n\n", binding, get_rust_name(&target, NameKind::Local), x));
+
+                    variable.kind = Kind::Assigned;
+                }
+                else if !(is_state_list && is_list_type(&variable.type_name)) {
+                    let statement = match_assignment_statement_pairs(elem, code, types, class_methods);
+                    handler.add_statement(format!("\t{}\n", statement));
+
+                    variable.kind = Kind::Assigned;
+                }
+
+                method_variables.push(variable);
+            },
+            Rule::return_key_word => {is_return_type = true;},
+            Rule::property_call => {},
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    match handler.to_code() {
+        Ok(x) => code.add_method(x),
+        Err(e) => code.add_failure(e)
+    }
+}
+
+fn match_models_constructor_code_pairs(iter: Pair<Rule>, code: &mut Code, class_name: &str, types: &mut TypeTable,
+    class_methods: &mut ClassMethods, constructor: &Method, chained_constructor: &(Rule, String)) -> String {
+
+    let class_type = match types.get_type_table(class_name) {
+        Ok(x) => x,
+        Err(e) => {
+            code.add_failure(e);
+            return String::from("");
+        }
+    };
+
+    let class_type_properties = class_type.get_type_properties();
+
+    let properties = get_class_properties(&class_type_properties, types);
+
+    let mut assigned_fields: Vec<(String, String)> = Vec::new();
+
+    let mut inherited_fields: Vec<(String, String)> = Vec::new();
+
+    for elem in iter.into_inner() {
+        let line = elem.as_span().start_pos().line_col().0;
+
+        match elem.as_rule(){
+            Rule::assignment => {
+                match match_member_assignment_pairs(elem, code, &properties, constructor, types, class_methods) {
+                    Some(x) => {
+                        if class_type_properties.iter().any(|a| get_rust_name(&a.name, NameKind::Field) == x.0) {
+                            assigned_fields.retain(|a| a.0 != x.0);
+                            assigned_fields.push(x);
+                        }
+                        else {
+                            inherited_fields.push(x);
+                        }
+                    },
+                    None => code.add_warning(format!("line {}: statement in {} constructor is not translated", line, constructor.return_type))
+                }
+            },
+            Rule::semicolon => {},
+            _ => code.add_warning(format!("line {}: statement in {} constructor is not translated", line, constructor.return_type))
+        }
+    }
+
+    let is_chained = chained_constructor.0 == Rule::this_key_word && !chained_constructor.1.is_empty();
+
+    let parameters: Vec<String> = constructor.parameters.iter().map(|a| format!("{}: {}", get_rust_name(&a.name, NameKind::Local), a.type_name)).collect();
+
+    let visibility = if constructor.name == "default" { "" } else { "pub " };
+
+    let mut constructor_code = format!("\n\t{}fn {}({}) -> Self {{", visibility, constructor.name, parameters.join(", "));
+
+    if inherited_fields.is_empty() {
+        constructor_code.push_str(&format!("\n\t\t{} {{", class_name));
+    }
+    else {
+        constructor_code.push_str(&format!("\n\t\tlet mut value = {} {{", class_name));
+    }
+
+    for elem in class_type_properties.into_iter() {
+
+        let field_name = get_rust_name(&elem.name, NameKind::Field);
+
+        match assigned_fields.iter().find(|a| a.0 == field_name) {
+            Some(x) => {
+                constructor_code.push_str(&format!("\n\t\t\t{}: {}, ", x.0, x.1));
+            },
+            None => {
+                if is_chained {
+                    continue;
+                }
+
+                let default_value = if elem.kind == Kind::Base && !chained_constructor.1.is_empty() {
+                    chained_constructor.1.clone()
+                }
+                else if elem.kind == Kind::Base || types.is_enum_type(&elem.type_name) {
+                    format!("{}::default()", elem.type_name)
+                }
+                else if let Some(x) = code.get_store_type(class_name, &elem.name) {
+                    format!("Arc::new({}::default())", x)
+                }
+                else if code.is_navigation_property(class_name, &elem.type_name) {
+                    String::from("Default::default()")
+                }
+                else {
+                    get_rust_type_default_value(elem.type_name)
+                };
+
+                constructor_code.push_str(&format!("\n\t\t\t{}: {}, ", field_name, default_value));
+            }
+        }
+    }
+
+    if is_chained {
+        constructor_code.push_str(&format!("\n\t\t\t..{}", chained_constructor.1));
+    }
+
+    if inherited_fields.is_empty() {
+        constructor_code.push_str("\n\t\t}");
+    }
+    else {
+        constructor_code.push_str("\n\t\t};\n");
+
+        for (name, value) in inherited_fields.iter() {
+            constructor_code.push_str(&format!("\n\t\tvalue.{} = {};", name, value));
+        }
+
+        constructor_code.push_str("\n\n\t\tvalue");
+    }
+
+    constructor_code.push_str("\n\t}\n");
+
+    constructor_code
+}
+
+fn match_member_assignment_pairs(iter: Pair<Rule>, code: &mut Code, properties: &[Type], method: &Method,
+    types: &mut TypeTable, class_methods: &mut ClassMethods) -> Option<(String, String)> {
+
+    let mut property: Option<&Type> = None;
+
+    let mut value = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::var_key_word => return None,
+            Rule::property_type => return None,
+            Rule::property_call => {
+                if property.is_none() {
+                    let name = elem.as_str().trim().trim_start_matches("this.");
+
+                    property = Some(properties.iter().find(|a| a.name == name && get_rust_static_value(name, properties).is_none())?);
+                }
+                else {
+                    value = get_rust_member_value(elem.as_str(), properties, method, code, types, false);
+                }
+            },
+            Rule::await_key_word => return None,
+            Rule::semicolon => {},
+            Rule::math_exp => { value = get_rust_math_expression(elem, properties, method, code, types); },
+            _ => { value = get_rust_expression_value(elem, &property?.type_name, code, types, class_methods); }
+        }
+    }
+
+    let property = property?;
+
+    if is_list_type(&property.type_name) && !method.parameters.iter().any(|a| get_rust_name(&a.name, NameKind::Local) == value) {
+        value = code.get_list_value(&method.return_type, property, &value);
+    }
+
+    Some((get_rust_name(&property.name, NameKind::Field), value))
+}
+
+fn match_parameters_pairs(iter: Pair<Rule>) -> Vec<Parameter>{
+
+    let mut params: Vec<Parameter> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::parameter => {},
+            Rule::action_parameter => {
+                let mut parameter = Parameter { name: String::from(""), type_name: String::from("") };
+
+                for elem in elem.into_inner() {
+                    match elem.as_rule(){
+                        Rule::property_type => { parameter.type_name = String::from(elem.as_str().trim()); },
+                        Rule::identifier => { parameter.name = String::from(elem.as_str()); },
+                        Rule::attribute => {},
+                        Rule::this_key_word => {},
+                        Rule::nullable_marker => {},
+                        Rule::parameter_default => {},
+                        _ => unreachable!()
+                    }
+                }
+
+                params.push(parameter);
+            },
+            Rule::constructor_parameter => { 
+                params.push(match_contructor_parameter_code_pairs(elem));
+            },
+            _ => unreachable!()
+        }
+    }
+
+    params
+}
+
+fn get_parameter_declarations(iter: Pair<Rule>) -> Vec<Parameter> {
+
+    let mut params: Vec<Parameter> = Vec::new();
+
+    for elem in iter.into_inner() {
+        let mut parameter = Parameter { name: String::from(""), type_name: String::from("") };
+
+        for elem in elem.into_inner() {
+            match elem.as_rule(){
+                Rule::property_type => { parameter.type_name = String::from(elem.as_str().trim()); },
+                Rule::identifier => { parameter.name = String::from(elem.as_str()); },
+                _ => {}
+            }
+        }
+
+        params.push(parameter);
+    }
+
+    params
+}
+
+fn match_contructor_parameter_code_pairs(iter: Pair<Rule>) -> Parameter {
+
+    let mut param_class_name = String::from("");
+
+    let mut param_name = "";
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::property_type => {
+                param_class_name = get_rust_property_type(elem);
+            }
+            Rule::identifier => {
+                param_name = elem.as_str();
+            }
+            _ => unreachable!()
+        }
+    }
+
+    Parameter { name: String::from(param_name), type_name: param_class_name }
+}
+
+fn get_type_parameters(iter: &Pair<Rule>) -> Vec<Parameter> {
+
+    let mut type_parameters: Vec<Parameter> = Vec::new();
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::type_parameters => {
+                for identifier in elem.into_inner() {
+                    type_parameters.push(Parameter { name: String::from(identifier.as_str()), type_name: String::from("") });
+                }
+            },
+            Rule::type_constraint_clause => {
+                let mut name = "";
+
+                let mut bounds: Vec<String> = Vec::new();
+
+                for constraint in elem.into_inner() {
+                    match constraint.as_rule(){
+                        Rule::identifier => { name = constraint.as_str(); },
+                        Rule::type_constraint => {
+                            if let Some(x) = get_rust_type_bound(constraint) {
+                                bounds.push(x);
+                            }
+                        },
+                        Rule::where_key_word => {},
+                        _ => unreachable!()
+                    }
+                }
+
+                if let Some(x) = type_parameters.iter_mut().find(|a| a.name == name) {
+                    x.type_name = bounds.join(" + ");
+                }
+            },
+            _ => {}
+        }
+    }
+
+    type_parameters
+}
+
+fn get_rust_type_bound(iter: Pair<Rule>) -> Option<String> {
+
+    let elem = iter.into_inner().next()?;
+
+    match elem.as_rule(){
+        Rule::class_constraint => None,
+        Rule::notnull_constraint => None,
+        Rule::struct_constraint => Some(String::from("Copy")),
+        Rule::new_constraint => Some(String::from("Default")),
+        Rule::property_type => {
+            let name = elem.as_str().split('<').next().unwrap_or("").trim();
+
+            match name {
+                "IComparable" => Some(String::from("Ord")),
+                "IEquatable" => Some(String::from("PartialEq")),
+                _ => {
+                    if is_interface_name(name) {
+                        Some(get_rust_property_type(elem))
+                    }
+                    else {
+                        Some(format!("std::ops::Deref<Target = {}>", get_rust_property_type(elem)))
+                    }
+                }
+            }
+        },
+        _ => unreachable!()
+    }
+}
+
+fn is_interface_name(value: &str) -> bool {
+
+    let mut chars = value.chars();
+
+    chars.next() == Some('I') && chars.next().is_some_and(|a| a.is_ascii_uppercase())
+}
+
+fn get_rust_generics(type_parameters: &[Parameter], default_parameters: &[String]) -> String {
+
+    if type_parameters.is_empty() {
+        return String::from("");
+    }
+
+    let mut generics: Vec<String> = Vec::new();
+
+    for elem in type_parameters.iter() {
+        let mut bounds: Vec<String> = elem.type_name.split(" + ").filter(|a| !a.is_empty()).map(String::from).collect();
+
+        if default_parameters.contains(&elem.name) && !bounds.iter().any(|a| a == "Default") {
+            bounds.push(String::from("Default"));
+        }
+
+        if !bounds.iter().any(|a| a == "Copy") {
+            bounds.push(String::from("Clone"));
+        }
+
+        if bounds.is_empty() {
+            generics.push(elem.name.clone());
+        }
+        else {
+            generics.push(format!("{}: {}", elem.name, bounds.join(" + ")));
+        }
+    }
+
+    format!("<{}>", generics.join(", "))
+}
+
+fn get_rust_generic_arguments(type_parameters: &[Parameter]) -> String {
+
+    if type_parameters.is_empty() {
+        return String::from("");
+    }
+
+    let names: Vec<String> = type_parameters.iter().map(|a| a.name.clone()).collect();
+
+    format!("<{}>", names.join(", "))
+}
+
+fn get_rust_property_type(iter: Pair<Rule>) -> String {
+
+    let mut property_type = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::string_key_word => { property_type = String::from("String"); },
+            Rule::int_key_word => { property_type = String::from("i32"); },
+            Rule::decimal_key_word => { property_type = String::from("f64"); },
+            Rule::bool_key_word => { property_type = String::from("bool"); },
+            Rule::list_type => {
+                let element_types: Vec<String> = elem.into_inner().map(get_rust_property_type).collect();
+                property_type = format!("Vec<{}>", element_types.join(", "));
+            },
+            Rule::generic_type => { property_type = get_rust_generic_type(elem); },
+            Rule::qualified_type => { property_type = get_rust_qualified_type_name(elem.as_str()); },
+            Rule::identifier => { property_type = String::from(elem.as_str()); },
+            _ => unreachable!()
+        }
+    }
+
+    property_type
+}
+
+fn get_rust_generic_type(iter: Pair<Rule>) -> String {
+
+    let mut name = "";
+
+    let mut arguments: Vec<String> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { name = elem.as_str(); },
+            Rule::type_arguments => { arguments = elem.into_inner().map(get_rust_property_type).collect(); },
+            _ => unreachable!()
+        }
+    }
+
+    let rust_name = match name {
+        "Dictionary" | "IDictionary" | "IReadOnlyDictionary" => "HashMap",
+        "HashSet" | "ISet" | "IReadOnlySet" => "HashSet",
+        "Nullable" => "Option",
+        "Task" | "ValueTask" => return arguments.join(", "),
+        _ => name
+    };
+
+    format!("{}<{}>", rust_name, arguments.join(", "))
+}
+
+fn get_rust_qualified_type_name(value: &str) -> String {
+
+    let mut segments: Vec<String> = value.split('.').map(|a| String::from(a.trim())).collect();
+
+    let type_name = segments.pop().unwrap_or_default();
+
+    let modules: Vec<String> = segments.iter().map(|a| get_rust_name(a, NameKind::Module)).collect();
+
+    format!("{}::{}", modules.join("::"), type_name)
+}
+
+fn get_rust_type_name(value: &str) -> String {
+
+    let value = value.trim();
+
+    if value == "void" {
+        return String::from("");
+    }
+
+    match CSParser::parse(Rule::property_type, value) {
+        Ok(mut x) => match x.next() {
+            Some(pair) if pair.as_str().trim() == value => get_rust_property_type(pair),
+            _ => String::from(value)
+        },
+        Err(_) => String::from(value)
+    }
+}
+
+fn get_rust_service_call(iter: &Pair<Rule>, class_name: &str, bindings: &[Binding], code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> Option<String> {
+
+    let mut receiver = "";
+
+    let mut invocation: Option<Pair<Rule>> = None;
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::identifier if receiver.is_empty() && invocation.is_none() => { receiver = elem.as_str().trim(); },
+            Rule::method_invocation if invocation.is_none() && !receiver.is_empty() => { invocation = Some(elem); },
+            Rule::semicolon => {},
+            _ => return None
+        }
+    }
+
+    let field_type = match types.find_type(class_name).and_then(|a| a.properties.iter().find(|b| b.name == receiver)) {
+        Some(x) => &x.type_name,
+        None => &bindings.iter().find(|a| a.source == BindingSource::Services && a.parameter.name == receiver)?.parameter.type_name
+    };
+
+    let implementation = class_methods.get_implementation_name(field_type);
+
+    if !code.repository_types.contains(&implementation) {
+        return None;
+    }
+
+    let mut method_name = "";
+
+    let mut arguments: Vec<String> = get_repository_states(&implementation, types).iter().map(|a| format!("&{}", get_rust_name(&a.name, NameKind::Local))).collect();
+
+    for elem in invocation?.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { method_name = elem.as_str(); },
+            Rule::parameters => arguments.extend(elem.into_inner().map(|a| get_rust_parameter(a, code, types, class_methods))),
+            _ => {}
+        }
+    }
+
+    let awaited = if code.async_methods.contains(&format!("{}.{}", implementation, method_name)) { ".await" } else { "" };
+
+    Some(format!("{}::{}({}){}", implementation, get_rust_name(method_name, NameKind::Function), arguments.join(", "), awaited))
+}
+
+fn match_new_instance_pairs(iter: Pair<Rule>, is_awaited: bool, param_name: &str, code: &mut Code, method_variables: &[Type], types: &TypeTable, class_methods: &ClassMethods) -> Vec<String> {
+
+    let mut statements: Vec<String> = Vec::new();
+
+    if !iter.clone().into_inner().any(|a| a.as_rule() == Rule::parameters) {
+        let reply = get_rust_reply(iter.clone(), code, types, class_methods);
+        statements.push(format!("\tOk({})\n", reply));
+    }
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::parameters => { 
+                let variable = method_variables.iter().find(|a| a.name == elem.as_str().trim());
+
+                let is_list = variable.is_some_and(|a| is_list_type(&a.type_name));
+
+                match variable {
+                    Some(x) if x.kind == Kind::Assigned => {
+                        statements.push(format!("\tOk(warp::reply::json(&{}))\n", get_rust_name(&x.name, NameKind::Local)));
+                    },
+                    Some(x) if is_list && is_awaited => {
+                        statements.push(format!("\tlet {} = {}.{}.read().clone();\n\n", x.name, param_name, x.name));
+                        statements.push(format!("\tOk(warp::reply::json(&{}))\n", x.name));
+                    },
+                    Some(x) if is_list => {
+                        statements.push(format!("\tlet {} = {}.{}.read();\n\n", x.name, param_name, x.name));
+                        statements.push(format!("\tOk(warp::reply::json(&*{}))\n", x.name));
+                    },
+                    _ => {
+                        let reply = get_rust_reply(iter.clone(), code, types, class_methods);
+                        statements.push(format!("\tOk({})\n", reply));
+                    }
+                }
+
+                match_parameters_pairs(elem);
+            },
+            Rule::new_key_word => {},
+            Rule::identifier => {},
+            Rule::list_type => {},
+            Rule::generic_type => {},
+            Rule::object_initializer => {},
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    statements
+}
+
+fn get_rust_reply(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    match iter.clone().into_inner().find(|a| a.as_rule() == Rule::parameters) {
+        Some(x) => {
+            let values: Vec<String> = x.into_inner().map(|a| get_rust_parameter(a, code, types, class_methods)).collect();
+
+            format!("warp::reply::json(&{})", values.join(", "))
+        },
+        None => {
+            let status = match iter.into_inner().find(|a| a.as_rule() == Rule::identifier).map(|a| a.as_str().trim()) {
+                Some("NoContentResult") => "NO_CONTENT",
+                Some("NotFoundResult") => "NOT_FOUND",
+                Some("BadRequestResult") => "BAD_REQUEST",
+                _ => "OK"
+            };
+
+            format!("warp::reply::with_status(warp::reply(), http::StatusCode::{})", status)
+        }
+    }
+}
+
+fn match_assignment_code_pairs(iter: Pair<Rule>, types: &mut TypeTable, class_methods: &mut ClassMethods) -> Type {
+
+    let mut var_name = "";
+
+    let mut var_type = String::from("");
+
+    let mut is_declaration = false;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::var_key_word => { is_declaration = true; },
+            Rule::property_type => {
+                is_declaration = true;
+                var_type = String::from(elem.as_str().trim());
+            },
+            Rule::property_call if var_name.is_empty() => { var_name = elem.as_str().trim(); },
+            Rule::await_key_word => {},
+            Rule::semicolon => {},
+            _ => {
+                if var_type.is_empty() {
+                    var_type = get_expression_type(&elem, types, class_methods);
+                }
+            }
+        }
+    }
+
+    if is_declaration {
+        types.add_local(var_name, &var_type);
+    }
+
+    Type { name: String::from(var_name), type_name: var_type, kind: Kind::Local, properties: Vec::new() }
+}
+
+fn get_expression_type(iter: &Pair<Rule>, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    match iter.as_rule(){
+        Rule::string_literal => {
+            if iter.as_str().trim().starts_with('\'') { String::from("char") } else { String::from("string") }
+        },
+        Rule::number => {
+            if iter.as_str().contains('.') { String::from("decimal") } else { String::from("int") }
+        },
+        Rule::math_exp | Rule::bool_exp => get_math_expression_type(iter, types, class_methods),
+        Rule::new_instance => {
+            match iter.clone().into_inner().find(|a| matches!(a.as_rule(), Rule::list_type | Rule::generic_type | Rule::identifier)) {
+                Some(x) => String::from(x.as_str().trim()),
+                None => String::from("")
+            }
+        },
+        Rule::enum_parse => {
+            match iter.clone().into_inner().find(|a| a.as_rule() == Rule::identifier) {
+                Some(x) => String::from(x.as_str().trim()),
+                None => String::from("")
+            }
+        },
+        Rule::with_expression | Rule::parameter => {
+            match iter.clone().into_inner().next() {
+                Some(x) => get_expression_type(&x, types, class_methods),
+                None => String::from("")
+            }
+        },
+        Rule::identifier | Rule::property_call => get_member_access_type(iter.as_str(), types, class_methods),
+        Rule::method_call => get_method_call_type(iter, types, class_methods),
+        _ => String::from("")
+    }
+}
+
+fn get_math_expression_type(iter: &Pair<Rule>, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut operand_types: Vec<String> = Vec::new();
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::math_op => {
+                if matches!(elem.as_str().trim(), ">" | ">=" | "<" | "<=" | "!=" | "==") {
+                    return String::from("bool");
+                }
+            },
+            Rule::bool_exp => return String::from("bool"),
+            Rule::semicolon => {},
+            _ => operand_types.push(get_expression_type(&elem, types, class_methods))
+        }
+    }
+
+    for elem in ["string", "decimal", "int"] {
+        if operand_types.iter().any(|a| a == elem) {
+            return String::from(elem);
+        }
+    }
+
+    operand_types.into_iter().find(|a| !a.is_empty()).unwrap_or_default()
+}
+
+fn get_member_access_type(value: &str, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut segments = value.split('.').map(|a| a.trim());
+
+    let mut type_name = match segments.next() {
+        Some(x) => get_name_type(x, types, class_methods),
+        None => return String::from("")
+    };
+
+    for elem in segments {
+        type_name = get_member_type(&type_name, elem, types, class_methods);
+    }
+
+    type_name
+}
+
+fn get_name_type(name: &str, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    match name {
+        "this" => return types.class_name.clone(),
+        "string" | "String" => return String::from("string"),
+        _ => {}
+    }
+
+    if let Some(x) = types.find_local(name) {
+        return x.type_name.clone();
+    }
+
+    let member_type = get_member_type(&types.class_name, name, types, class_methods);
+
+    if !member_type.is_empty() {
+        return member_type;
+    }
+
+    if types.find_type(name).is_some() || class_methods.find_class_method(name).is_some() || class_methods.find_record(name).is_some() {
+        return String::from(name);
+    }
+
+    String::from("")
+}
+
+fn get_member_type(type_name: &str, name: &str, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let arguments = get_type_argument_names(type_name);
+
+    match name {
+        _ if type_name.is_empty() => return String::from(""),
+        "Length" if type_name == "string" => return String::from("int"),
+        "Count" if is_list_type(type_name) || is_dictionary_type(type_name) || is_set_type(type_name) => return String::from("int"),
+        "Keys" if is_dictionary_type(type_name) && arguments.len() == 2 => return format!("IEnumerable<{}>", arguments[0]),
+        "Values" if is_dictionary_type(type_name) && arguments.len() == 2 => return format!("IEnumerable<{}>", arguments[1]),
+        "HasValue" if get_generic_type_name(type_name) == "Nullable" => return String::from("bool"),
+        "Value" if get_generic_type_name(type_name) == "Nullable" && arguments.len() == 1 => return arguments[0].clone(),
+        _ => {}
+    }
+
+    if types.is_enum_type(type_name) {
+        return String::from(type_name);
+    }
+
+    let class_name = get_generic_type_name(type_name);
+
+    let substitutions = get_type_substitutions(type_name, class_methods);
+
+    if let Some(x) = types.find_type(class_name).filter(|a| a.kind != Kind::TypeParameter) {
+        if let Some(property) = get_class_properties(&x.properties, types).iter().find(|a| a.name == name) {
+            return substitute_type_parameters(&property.type_name, &substitutions);
+        }
+    }
+
+    if let Some(property) = class_methods.find_record(class_name).and_then(|a| a.properties.iter().find(|b| b.name == name)) {
+        return substitute_type_parameters(&property.type_name, &substitutions);
+    }
+
+    match class_methods.find_interface(class_name).and_then(|a| a.methods.iter().find(|b| b.name == name && b.kind == Kind::Getter)) {
+        Some(x) => substitute_type_parameters(&x.return_type, &substitutions),
+        None => String::from("")
+    }
+}
+
+fn get_type_substitutions(type_name: &str, class_methods: &ClassMethods) -> Vec<(String, String)> {
+
+    let class_name = get_generic_type_name(type_name);
+
+    match class_methods.find_class_method(class_name).or_else(|| class_methods.find_interface(class_name)) {
+        Some(x) => x.type_parameters.iter().map(|a| a.name.clone()).zip(get_type_argument_names(type_name)).collect(),
+        None => Vec::new()
+    }
+}
+
+fn get_method_call_type(iter: &Pair<Rule>, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut path: Vec<&str> = Vec::new();
+
+    let mut type_name: Option<String> = None;
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => match type_name {
+                Some(x) => { type_name = Some(get_member_type(&x, elem.as_str().trim(), types, class_methods)); },
+                None => path.push(elem.as_str().trim())
+            },
+            Rule::method_invocation => {
+                let receiver = match type_name {
+                    Some(x) => x,
+                    None if path.is_empty() => types.class_name.clone(),
+                    None => get_member_access_type(&path.join("."), types, class_methods)
+                };
+
+                type_name = Some(get_invocation_type(&receiver, &elem, types, class_methods));
+            },
+            _ => {}
+        }
+    }
+
+    type_name.unwrap_or_default()
+}
+
+fn get_invocation_type(receiver: &str, iter: &Pair<Rule>, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut method_name = "";
+
+    let mut type_arguments: Vec<String> = Vec::new();
+
+    let mut argument_types: Vec<String> = Vec::new();
+
+    let mut has_lambda = false;
+
+    for elem in iter.clone().into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { method_name = elem.as_str().trim(); },
+            Rule::type_arguments => { type_arguments = elem.into_inner().map(|a| String::from(a.as_str().trim())).collect(); },
+            Rule::parameters => { argument_types = elem.into_inner().map(|a| get_expression_type(&a, types, class_methods)).collect(); },
+            Rule::lambda_expression => { has_lambda = true; },
+            _ => {}
+        }
+    }
+
+    let type_name = if receiver.is_empty() {
+        String::from("")
+    }
+    else if receiver == "string" {
+        get_string_method_type(method_name)
+    }
+    else if is_list_type(receiver) {
+        get_collection_method_type(receiver, method_name, has_lambda)
+    }
+    else if is_dictionary_type(receiver) || is_set_type(receiver) {
+        get_lookup_method_type(receiver, method_name)
+    }
+    else {
+        let method = class_methods.find_class_method(get_generic_type_name(receiver)).map(|_| receiver)
+            .or_else(|| class_methods.find_interface(get_generic_type_name(receiver)).map(|_| receiver));
+
+        match method {
+            Some(x) => get_class_method_type(x, method_name, &type_arguments, &argument_types, class_methods, 0),
+            None => match class_methods.find_implementation(get_generic_type_name(receiver)) {
+                Some(x) => get_class_method_type(&x.class_name, method_name, &type_arguments, &argument_types, class_methods, 0),
+                None => String::from("")
+            }
+        }
+    };
+
+    if !type_name.is_empty() {
+        return type_name;
+    }
+
+    if let Some(x) = class_methods.find_extension(receiver, method_name) {
+        return get_awaited_type(&x.return_type);
+    }
+
+    match method_name {
+        "ToString" => String::from("string"),
+        "Equals" => String::from("bool"),
+        "GetHashCode" | "CompareTo" => String::from("int"),
+        _ => String::from("")
+    }
+}
+
+fn get_class_method_type(type_name: &str, method_name: &str, type_arguments: &[String], argument_types: &[String],
+    class_methods: &ClassMethods, depth: usize) -> String {
+
+    let class_name = get_generic_type_name(type_name);
+
+    let declaration = match class_methods.find_class_method(class_name).or_else(|| class_methods.find_interface(class_name)) {
+        Some(x) if depth < 8 => x,
+        _ => return String::from("")
+    };
+
+    let mut substitutions = get_type_substitutions(type_name, class_methods);
+
+    match declaration.methods.iter().find(|a| a.name == method_name && a.kind != Kind::Setter) {
+        Some(method) => {
+            for (index, elem) in method.type_parameters.iter().enumerate() {
+                let argument = type_arguments.get(index).cloned().or_else(|| method.parameters.iter().zip(argument_types)
+                    .find(|a| a.0.type_name == elem.name && !a.1.is_empty()).map(|a| a.1.clone()));
+
+                if let Some(x) = argument {
+                    substitutions.push((elem.name.clone(), x));
+                }
+            }
+
+            get_awaited_type(&substitute_type_parameters(&method.return_type, &substitutions))
+        },
+        None => {
+            for elem in declaration.bases.iter() {
+                let type_name = get_class_method_type(&substitute_type_parameters(elem, &substitutions), method_name, type_arguments, argument_types, class_methods, depth + 1);
+
+                if !type_name.is_empty() {
+                    return type_name;
+                }
+            }
+
+            String::from("")
+        }
+    }
+}
+
+fn get_awaited_type(value: &str) -> String {
+
+    let value = value.trim().trim_start_matches("async ").trim();
+
+    match get_generic_type_name(value) {
+        "Task" | "ValueTask" if value.contains('<') => get_list_element_type(value),
+        "Task" | "ValueTask" => String::from("void"),
+        _ => String::from(value)
+    }
+}
+
+fn get_string_method_type(method_name: &str) -> String {
+
+    match method_name {
+        "ToUpper" | "ToLower" | "ToUpperInvariant" | "ToLowerInvariant" | "Trim" | "TrimStart" | "TrimEnd" | "Substring" | "Replace" |
+        "PadLeft" | "PadRight" | "Insert" | "Remove" | "Join" | "Format" | "Concat" | "ToString" => String::from("string"),
+        "Contains" | "StartsWith" | "EndsWith" | "Equals" | "IsNullOrEmpty" | "IsNullOrWhiteSpace" => String::from("bool"),
+        "IndexOf" | "LastIndexOf" | "CompareTo" | "Compare" => String::from("int"),
+        "Split" => String::from("List<string>"),
+        _ => String::from("")
+    }
+}
+
+fn get_collection_method_type(type_name: &str, method_name: &str, has_lambda: bool) -> String {
+
+    let element_type = get_list_element_type(type_name);
+
+    match method_name {
+        "Where" | "OrderBy" | "OrderByDescending" | "ThenBy" | "ThenByDescending" | "Skip" | "Take" | "Distinct" | "Reverse" |
+        "AsEnumerable" | "Concat" | "Union" | "Intersect" | "Except" => format!("IEnumerable<{}>", element_type),
+        "ToList" | "ToArray" | "FindAll" => format!("List<{}>", element_type),
+        "First" | "FirstOrDefault" | "Last" | "LastOrDefault" | "Single" | "SingleOrDefault" | "ElementAt" | "ElementAtOrDefault" | "Find" => element_type,
+        "Min" | "Max" | "Sum" if !has_lambda => element_type,
+        "Average" => String::from("decimal"),
+        "Count" | "IndexOf" | "FindIndex" | "RemoveAll" => String::from("int"),
+        "Any" | "All" | "Contains" | "Exists" | "Remove" | "SequenceEqual" => String::from("bool"),
+        "Add" | "AddRange" | "Insert" | "Clear" | "RemoveAt" | "ForEach" | "Sort" => String::from("void"),
+        _ => String::from("")
+    }
+}
+
+fn get_lookup_method_type(type_name: &str, method_name: &str) -> String {
+
+    let arguments = get_type_argument_names(type_name);
+
+    match method_name {
+        "ContainsKey" | "ContainsValue" | "TryGetValue" | "TryAdd" | "Remove" | "Contains" => String::from("bool"),
+        "Add" if is_set_type(type_name) => String::from("bool"),
+        "Add" | "Clear" => String::from("void"),
+        "GetValueOrDefault" if arguments.len() == 2 => arguments[1].clone(),
+        _ => String::from("")
+    }
+}
+
+fn get_rust_local_type(type_name: &str, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    match type_name {
+        "int" | "decimal" | "bool" | "string" | "char" => get_rust_type_name(type_name),
+        _ if type_name.contains('<') => String::from(""),
+        _ if types.is_enum_type(type_name) || class_methods.find_class_method(type_name).is_some() || class_methods.find_record(type_name).is_some() => get_rust_type_name(type_name),
+        _ => String::from("")
+    }
+}
+
+fn match_assignment_properties_pairs(iter: Pair<Rule>, property_type: &Type) -> Type {
+
+    let mut prop_name = String::from("");
+
+    let type_name = String::from(&property_type.type_name);
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::property_call => {
+
+                if type_name.is_empty() {
+                    continue;
+                }
+
+                prop_name = String::from(elem.as_str());
+            },
+            Rule::new_instance => {},
+            Rule::collection_expression => {},
+            Rule::with_expression => {},
+            Rule::number => {},
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    Type { name: prop_name, type_name, kind: property_type.kind, properties: Vec::new() }
+}
+
+fn match_new_instance_code_pairs(iter: Pair<Rule>, target_type: &str, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    let line = iter.as_span().start_pos().line_col().0;
+
+    let mut type_name = String::from(target_type.trim());
+
+    let mut arguments: Vec<Pair<Rule>> = Vec::new();
+
+    let mut initializer: Option<Pair<Rule>> = None;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { type_name = String::from(elem.as_str()); },
+            Rule::list_type => { type_name = String::from(elem.as_str()); },
+            Rule::generic_type => { type_name = String::from(elem.as_str()); },
+            Rule::parameters => { arguments = elem.into_inner().collect(); },
+            Rule::object_initializer => { initializer = Some(elem); },
+            Rule::new_key_word => {},
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    if is_list_type(&type_name) {
+        let element_type = get_list_element_type(&type_name);
+
+        let values = match initializer {
+            Some(x) => x.into_inner().filter(|a| a.as_rule() == Rule::initializer_value).map(|a| get_rust_initializer_value(a, &element_type, code, types, class_methods)).collect(),
+            None => Vec::new()
+        };
+
+        if values.is_empty() {
+            return String::from("Vec::new()");
+        }
+
+        return format!("vec![{}]", values.join(", "));
+    }
+
+    if type_name.is_empty() {
+        if initializer.is_some() {
+            code.add_failure(format!("line {}: target type of new() could not be inferred", line));
+        }
+
+        return String::from("Default::default()");
+    }
+
+    if type_name.contains('<') {
+        return match_new_generic_instance_code_pairs(&type_name, arguments, initializer.is_some(), line, code, types, class_methods);
+    }
+
+    let base = if !arguments.is_empty() {
+        format!("{}::{}", type_name, get_rust_constructor_call(&type_name, arguments.clone(), line, code, types, class_methods))
+    }
+    else if initializer.is_some() {
+        String::from("Default::default()")
+    }
+    else {
+        format!("{}::default()", type_name)
+    };
+
+    let initializer = match initializer {
+        Some(x) => x,
+        None => return base
+    };
+
+    let class_type = match types.get_type_table(&type_name) {
+        Ok(x) => x,
+        Err(e) => {
+            code.add_failure(format!("line {}: {}", line, e));
+            return base;
+        }
+    };
+
+    let mut fields = get_rust_member_initializers(initializer, &class_type, code, types, class_methods);
+
+    let missing: Vec<String> = class_type.get_type_properties().into_iter()
+        .filter(|a| a.kind == Kind::Required && !fields.iter().any(|b| b.starts_with(&format!("{}:", get_rust_name(&a.name, NameKind::Field)))))
+        .map(|a| a.name).collect();
+
+    if !missing.is_empty() {
+        code.add_warning(format!("line {}: required member(s) {} of {} must be set in the object initializer", line, missing.join(", "), type_name));
+    }
+
+    if arguments.is_empty() && fields.len() == class_type.get_type_properties().len() {
+        return format!("{} {{ {} }}", type_name, fields.join(", "));
+    }
+
+    fields.push(format!("..{}", base));
+
+    format!("{} {{ {} }}", type_name, fields.join(", "))
+}
+
+fn match_new_generic_instance_code_pairs(type_name: &str, arguments: Vec<Pair<Rule>>, has_initializer: bool, line: usize, code: &mut Code,
+    types: &TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    let rust_type = get_rust_type_name(type_name);
+
+    let name = get_generic_type_name(&rust_type);
+
+    let type_arguments = rust_type.trim_start_matches(name);
+
+    if has_initializer {
+        code.add_warning(format!("line {}: initializer of generic type {} is not translated", line, type_name));
+    }
+
+    if name == "HashMap" || name == "HashSet" {
+        return format!("{}::new()", name);
+    }
+
+    if !arguments.is_empty() {
+        return format!("{}::{}::{}", name, type_arguments, get_rust_constructor_call(name, arguments, line, code, types, class_methods));
+    }
+
+    format!("{}::{}::default()", name, type_arguments)
+}
+
+fn get_generic_type_name(value: &str) -> &str {
+    value.split('<').next().unwrap_or("").trim()
+}
+
+fn get_rust_member_initializers(iter: Pair<Rule>, class_type: &Type, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> Vec<String> {
+
+    let mut fields: Vec<String> = Vec::new();
+
+    for elem in iter.into_inner().filter(|a| a.as_rule() == Rule::member_initializer) {
+        let line = elem.as_span().start_pos().line_col().0;
+
+        let mut member_name = "";
+
+        for member in elem.into_inner() {
+            match member.as_rule(){
+                Rule::identifier => { member_name = member.as_str(); },
+                Rule::initializer_value => {
+                    let property = match class_type.get_type_property(member_name) {
+                        Ok(x) => x,
+                        Err(e) => {
+                            code.add_failure(format!("line {}: {}", line, e));
+                            continue;
+                        }
+                    };
+
+                    let mut value = get_rust_initializer_value(member, &property.type_name, code, types, class_methods);
+
+                    if is_list_type(&property.type_name) {
+                        value = code.get_list_value(&class_type.name, &property, &value);
+                    }
+
+                    fields.push(format!("{}: {}", get_rust_name(member_name, NameKind::Field), value));
+                },
+                _ => unreachable!()
+            }
+        }
+    }
+
+    fields
+}
+
+fn match_with_expression_code_pairs(iter: Pair<Rule>, target_type: &str, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    let line = iter.as_span().start_pos().line_col().0;
+
+    let mut source = String::from("");
+
+    let mut initializer: Option<Pair<Rule>> = None;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::property_call => { source = get_rust_method_name(elem.as_str().trim()); },
+            Rule::object_initializer => { initializer = Some(elem); },
+            _ => unreachable!()
+        }
+    }
+
+    let initializer = initializer.expect("");
+
+    let members: Vec<String> = initializer.clone().into_inner()
+        .filter(|a| a.as_rule() == Rule::member_initializer)
+        .filter_map(|a| a.into_inner().next().map(|b| String::from(b.as_str())))
+        .collect();
+
+    let has_members = |a: &Type| members.iter().all(|b| a.properties.iter().any(|c| &c.name == b));
+
+    let candidates: Vec<Type> = match types.find_type(target_type.trim()) {
+        Some(x) if has_members(x) => vec![x.clone()],
+        _ => types.get_sorted_types().into_iter().filter(|a| a.kind == Kind::Class && has_members(a)).cloned().collect()
+    };
+
+    let class_type = match candidates.first() {
+        Some(x) => x.clone(),
+        None => {
+            code.add_failure(format!("line {}: type of with expression could not be inferred", line));
+            return format!("{}.clone()", source);
+        }
+    };
+
+    if candidates.len() > 1 {
+        code.add_warning(format!("line {}: type of with expression is ambiguous, using {}", line, class_type.name));
+    }
+
+    let mut fields = get_rust_member_initializers(initializer, &class_type, code, types, class_methods);
+
+    fields.push(format!("..{}.clone()", source));
+
+    format!("{} {{ {} }}", class_type.name, fields.join(", "))
+}
+
+fn match_collection_expression_pairs(iter: Pair<Rule>, target_type: &str, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    let element_type = get_list_element_type(target_type);
+
+    let values: Vec<String> = iter.into_inner().map(|a| get_rust_initializer_value(a, &element_type, code, types, class_methods)).collect();
+
+    if values.is_empty() {
+        return String::from("Vec::new()");
+    }
+
+    format!("vec![{}]", values.join(", "))
+}
+
+fn get_rust_initializer_value(iter: Pair<Rule>, target_type: &str, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    let mut value = String::from("");
+
+    for elem in iter.into_inner() {
+        value = get_rust_expression_value(elem, target_type, code, types, class_methods);
+    }
+
+    value
+}
+
+fn get_rust_expression_value(iter: Pair<Rule>, target_type: &str, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    match iter.as_rule(){
+        Rule::new_instance => match_new_instance_code_pairs(iter, target_type, code, types, class_methods),
+        Rule::collection_expression => match_collection_expression_pairs(iter, target_type, code, types, class_methods),
+        Rule::with_expression => match_with_expression_code_pairs(iter, target_type, code, types, class_methods),
+        Rule::string_literal => get_rust_string_literal(iter, code, types, class_methods),
+        Rule::method_call => match_method_call_code_pairs(iter, code, types, class_methods),
+        Rule::enum_parse => match_enum_parse_pairs(iter, code, types, class_methods),
+        Rule::math_exp => get_rust_operand_expression(iter, code, types, class_methods),
+        Rule::number => {
+            let mut value = String::from(iter.as_str().trim());
+
+            if target_type == "decimal" && !value.contains('.') {
+                value.push_str(".0");
+            }
+
+            value
+        },
+        Rule::property_call => {
+            let split_property: Vec<&str> = iter.as_str().split('.').map(|a| a.trim()).collect();
+
+            if split_property.len() > 1 && types.is_enum_type(split_property[0]) {
+                format!("{}::{}", split_property[0], get_rust_name(split_property[1], NameKind::Type))
+            }
+            else {
+                get_rust_operand(iter.as_str(), false, code, types, class_methods)
+            }
+        },
+        _ => unreachable!()
+    }
+}
+
+fn get_rust_operand(value: &str, is_borrow: bool, code: &Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let value = value.trim().trim_end_matches(';').trim();
+
+    if let Some((collection, "Length" | "Count")) = value.rsplit_once('.').map(|(a, b)| (a.trim(), b.trim())) {
+        let collection_type = get_member_access_type(collection, types, class_methods);
+
+        if collection_type.is_empty() || collection_type == "string" || is_list_type(&collection_type) || is_dictionary_type(&collection_type) || is_set_type(&collection_type) {
+            let (owner_type, member) = match collection.rsplit_once('.') {
+                Some((a, b)) => (get_member_access_type(a, types, class_methods), b.trim()),
+                None if types.find_usage(collection).is_none() => (types.class_name.clone(), collection),
+                None => (String::from(""), collection)
+            };
+
+            return get_rust_length(&get_rust_operand(collection, true, code, types, class_methods), &owner_type, member, code, types);
+        }
+    }
+
+    let (root, path) = value.split_once('.').map(|(a, b)| (a.trim(), b)).unwrap_or((value, ""));
+
+    let operand = match (code.member_values.get(root), types.find_usage(root)) {
+        (Some(x), None) if path.is_empty() => x.clone(),
+        (Some(x), None) => format!("{}.{}", x, get_rust_member_path(path)),
+        (_, Some(_)) if path.is_empty() => return if is_borrow { get_rust_name(root, NameKind::Local) } else { get_rust_local_value(root, types) },
+        (_, Some(x)) if !is_borrow && types.is_read_after(root) => format!("{}.{}", x.rust_name, get_rust_member_path(path)),
+        _ => return get_rust_member_path(value)
+    };
+
+    let (owner_type, member) = match value.rsplit_once('.') {
+        Some((a, b)) => (get_member_access_type(a, types, class_methods), b.trim()),
+        None => (types.class_name.clone(), value)
+    };
+
+    if is_borrow || is_copy_type(&get_member_access_type(value, types, class_methods), types) {
+        operand
+    }
+    else if is_locked_list_property(&owner_type, member, code, types) {
+        format!("{}.read().clone()", operand)
+    }
+    else {
+        format!("{}.clone()", operand)
+    }
+}
+
+fn get_rust_operand_expression(iter: Pair<Rule>, code: &Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let is_comparison = iter.clone().into_inner().any(|a| a.as_rule() == Rule::bool_exp ||
+        (a.as_rule() == Rule::math_op && matches!(a.as_str().trim(), ">" | ">=" | "<" | "<=" | "!=" | "==")));
+
+    let mut expression: Vec<String> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier | Rule::property_call => expression.push(get_rust_operand(elem.as_str(), is_comparison, code, types, class_methods)),
+            Rule::math_exp => expression.push(get_rust_operand_expression(elem, code, types, class_methods)),
+            Rule::semicolon => {},
+            _ => expression.push(String::from(elem.as_str().trim()))
+        }
+    }
+
+    expression.join(" ")
+}
+
+fn get_list_element_type(value: &str) -> String {
+
+    match (value.find('<'), value.rfind('>')) {
+        (Some(start), Some(end)) => String::from(value[start + 1..end].trim()),
+        _ => String::from("")
+    }
+}
+
+fn match_property_type_code_pairs(iter: Pair<Rule>, _code: &mut Code, test: &mut Vec<Type>) -> String {
+
+    let mut propety_type = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::string_key_word => { 
+                propety_type = String::from("String");
+                test.push(Type {name: String::from(""), type_name: String::from(elem.as_str()), kind: Kind::Property, properties: Vec::new()});
+            },
+            Rule::int_key_word => { 
+                propety_type = String::from("i32");
+                test.push(Type {name: String::from(""), type_name: String::from(elem.as_str()), kind: Kind::Property, properties: Vec::new()});
+            },
+            Rule::decimal_key_word => { 
+                propety_type = String::from("f64");
+                test.push(Type {name: String::from(""), type_name: String::from(elem.as_str()), kind: Kind::Property, properties: Vec::new()});
+            },
+            Rule::bool_key_word => { 
+                propety_type = String::from("bool");
+                test.push(Type {name: String::from(""), type_name: String::from(elem.as_str()), kind: Kind::Property, properties: Vec::new()});
+            },
+            Rule::list_type => { 
+                test.push(Type {name: String::from(""), type_name: String::from(elem.as_str()), kind: Kind::Property, properties: Vec::new()});
+                propety_type = match_list_type(elem);
+            },
+            Rule::generic_type => { 
+                test.push(Type {name: String::from(""), type_name: String::from(elem.as_str()), kind: Kind::Property, properties: Vec::new()});
+                propety_type = get_rust_generic_type(elem);
+            },
+            Rule::qualified_type => { 
+                test.push(Type {name: String::from(""), type_name: String::from(elem.as_str()), kind: Kind::Property, properties: Vec::new()});
+                propety_type = get_rust_qualified_type_name(elem.as_str());
+            },
+            Rule::identifier => { 
+                propety_type = String::from(elem.as_str());
+                test.push(Type {name: String::from(""), type_name: String::from(elem.as_str()), kind: Kind::Property, properties: Vec::new()});
+            },
+            _ => { propety_type = String::from("<not_found>") }
+        }
+    }
+
+    propety_type
+}
+
+fn match_list_type(iter: Pair<Rule>) -> String {
+
+    let mut property_type = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule() {
+            Rule::property_type => {
+                property_type = format!("Arc<RwLock<Vec<{}>>>", get_rust_property_type(elem))
+            },
+            _ => unreachable!()
+        }
+    }
+
+    property_type
+}
+
+fn is_exception_class(iter: &Pair<Rule>) -> bool {
+    get_class_declaration(iter).bases.first().is_some_and(|a| a.ends_with("Exception"))
+}
+
+fn get_error_variant_name(exception_name: &str) -> String {
+
+    let variant = exception_name.trim().trim_end_matches("Exception");
+
+    if variant.is_empty() {
+        return String::from("Internal");
+    }
+
+    String::from(variant)
+}
+
+fn match_exception_class_code_pairs(iter: Pair<Rule>, code: &mut Code) {
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::class_name => code.add_error(get_error_variant_name(elem.as_str())),
+            Rule::base_list => {},
+            Rule::nested_type => {},
+            Rule::type_parameters => {},
+            Rule::type_constraint_clause => {},
+            Rule::attribute => {},
+            Rule::public_key_word => {},
+            Rule::abstract_key_word => {},
+            Rule::sealed_key_word => {},
+            Rule::static_key_word => {},
+            Rule::class_key_word => {},
+            Rule::properties => {},
+            Rule::constructor => {},
+            Rule::action => {},
+            Rule::left_bracers => {},
+            Rule::right_bracers => {},
+            _ => unreachable!()
+        }
+    }
+}
+
+fn match_throw_statement_pairs(iter: Pair<Rule>, code: &mut Code, exception_name: &str, is_handler: bool, indent: usize, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let tabs = "\t".repeat(indent);
+
+    let error = get_rust_thrown_error(iter, code, exception_name, types, class_methods);
+
+    if is_handler {
+        return format!("{}return Err(warp::reject::custom({}));\n", tabs, error);
+    }
+
+    format!("{}return Err({});\n", tabs, error)
+}
+
+fn get_rust_thrown_error(iter: Pair<Rule>, code: &mut Code, exception_name: &str, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut error = String::from(exception_name);
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::new_instance => { error = match_error_instance_pairs(elem, code, types, class_methods); },
+            Rule::throw_key_word => {},
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    error
+}
+
+fn match_error_instance_pairs(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut variant = String::from("");
+
+    let mut message = String::from("String::new()");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => {
+                variant = get_error_variant_name(elem.as_str());
+                code.add_error(variant.clone());
+            },
+            Rule::parameters => {
+                if let Some(parameter) = elem.into_inner().next() {
+                    message = get_rust_error_message(parameter, code, types, class_methods);
+                }
+            },
+            Rule::new_key_word => {},
+            Rule::object_initializer => {},
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    format!("{}::{}({})", code.get_runtime_name("Error"), variant, message)
+}
+
+fn get_rust_error_message(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let value = get_rust_parameter(iter, code, types, class_methods);
+
+    if value.starts_with("String::from(") || value.starts_with("format!(") {
+        return value;
+    }
+
+    format!("{}.to_string()", value)
+}
+
+fn match_try_statement_pairs(iter: Pair<Rule>, context: BodyContext, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods, is_handler: bool, indent: usize) -> String {
+
+    let tabs = "\t".repeat(indent);
+
+    let is_async = match context {
+        BodyContext::Handler(..) => true,
+        BodyContext::Repository(class_type, _) => class_type.properties.iter().any(|a| a.kind == Kind::Property && code.database_contexts.contains(&a.type_name)),
+        BodyContext::Method(..) => false
+    };
+
+    let is_reply = is_handler && is_returning_try(&iter);
+
+    let value_type = if is_reply { "warp::reply::Response" } else { "()" };
+
+    let mut statement = String::from("");
+
+    let mut finally = String::from("");
+
+    let mut has_catch_all = false;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::block => {
+                let line = elem.as_span().start_pos().line_col().0;
+
+                let mut statements = get_block_statements(elem);
+
+                let value = if is_reply {
+                    get_rust_reply_tail(&mut statements, context, code, types, class_methods).unwrap_or_else(|| {
+                        code.add_failure(format!("line {}: try block in {} does not return on every path and is not translated", line, context.get_method_name()));
+                        String::from("()")
+                    })
+                }
+                else {
+                    String::from("()")
+                };
+
+                let body = get_rust_block_statements(&statements, context, code, types, class_methods, "error", indent + 1);
+
+                let error = code.get_runtime_name("Error");
+
+                if is_async {
+                    statement.push_str(&format!("{}let result: Result<{}, {}> = async {{\n{}{}\tOk::<{}, {}>({})\n{}}}.await.or_else(|error| match error {{\n",
+                        tabs, value_type, error, body, tabs, value_type, error, value, tabs));
+                }
+                else {
+                    statement.push_str(&format!("{}let result: Result<{}, {}> = (|| -> Result<{}, {}> {{\n{}{}\tOk({})\n{}}})().or_else(|error| match error {{\n",
+                        tabs, value_type, error, value_type, error, body, tabs, value, tabs));
+                }
+            },
+            Rule::catch_clause => {
+                let (catch, is_catch_all) = match_catch_clause_pairs(elem, context, code, types, class_methods, is_reply, indent + 1);
+                has_catch_all = has_catch_all || is_catch_all;
+                statement.push_str(&catch);
+            },
+            Rule::finally_clause => {
+                for block in elem.into_inner() {
+                    if block.as_rule() == Rule::block {
+                        finally = get_rust_block_statements(&get_block_statements(block), context, code, types, class_methods, "error", indent);
+                    }
+                }
+            },
+            Rule::try_key_word => {},
+            _ => unreachable!()
+        }
+    }
+
+    if !has_catch_all {
+        statement.push_str(&format!("{}\terror => Err(error),\n", tabs));
+    }
+
+    statement.push_str(&format!("{}}});\n", tabs));
+    statement.push_str(&finally);
+
+    if is_reply {
+        statement.push_str(&format!("{}result.map_err(warp::reject::custom)\n", tabs));
+    }
+    else if is_handler {
+        statement.push_str(&format!("{}result.map_err(warp::reject::custom)?;\n", tabs));
+    }
+    else {
+        statement.push_str(&format!("{}result?;\n", tabs));
+    }
+
+    statement
+}
+
+fn match_catch_clause_pairs(iter: Pair<Rule>, context: BodyContext, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods,
+    is_reply: bool, indent: usize) -> (String, bool) {
+
+    let tabs = "\t".repeat(indent);
+
+    let line = iter.as_span().start_pos().line_col().0;
+
+    let mut exception_type = "";
+
+    let mut exception_name = "error";
+
+    let mut statements: Vec<Pair<Rule>> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => {
+                if exception_type.is_empty() {
+                    exception_type = elem.as_str();
+                }
+                else {
+                    exception_name = elem.as_str();
+                }
+            },
+            Rule::block => { statements = get_block_statements(elem); },
+            Rule::catch_key_word => {},
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            _ => unreachable!()
+        }
+    }
+
+    let tail = match statements.last() {
+        Some(x) if x.as_rule() == Rule::throw_statement => {
+            let error = get_rust_thrown_error(x.clone(), code, exception_name, types, class_methods);
+            statements.pop();
+
+            format!("{}\tErr({})\n", tabs, error)
+        },
+        _ if is_reply => match get_rust_reply_tail(&mut statements, context, code, types, class_methods) {
+            Some(x) => format!("{}\tOk({})\n", tabs, x),
+            None => {
+                code.add_failure(format!("line {}: catch block in {} does not return on every path and is not translated", line, context.get_method_name()));
+                String::from("")
+            }
+        },
+        _ => format!("{}\tOk(())\n", tabs)
+    };
+
+    let body = format!("{}{}", get_rust_block_statements(&statements, context, code, types, class_methods, exception_name, indent + 1), tail);
+
+    let is_used = body.split(|a: char| !(a.is_alphanumeric() || a == '_')).any(|a| a == exception_name);
+
+    let is_catch_all = exception_type.is_empty() || exception_type == "Exception";
+
+    let pattern = match (is_catch_all, is_used) {
+        (true, true) => String::from(exception_name),
+        (true, false) => String::from("_"),
+        (false, used) => {
+            let variant = get_error_variant_name(exception_type);
+            code.add_error(variant.clone());
+
+            let error = code.get_runtime_name("Error");
+
+            if used { format!("{} @ {}::{}(_)", exception_name, error, variant) } else { format!("{}::{}(_)", error, variant) }
+        }
+    };
+
+    (format!("{}{} => {{\n{}{}}},\n", tabs, pattern, body, tabs), is_catch_all)
+}
+
+fn is_returning_try(iter: &Pair<Rule>) -> bool {
+
+    iter.clone().into_inner()
+        .flat_map(|a| if a.as_rule() == Rule::block { vec![a] } else { a.into_inner().filter(|b| b.as_rule() == Rule::block).collect() })
+        .flat_map(get_block_statements)
+        .any(|a| a.as_rule() == Rule::return_key_word)
+}
+
+fn get_rust_reply_tail(statements: &mut Vec<Pair<Rule>>, context: BodyContext, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> Option<String> {
+
+    let [.., keyword, value] = statements.as_slice() else {
+        return None;
+    };
+
+    if keyword.as_rule() != Rule::return_key_word {
+        return None;
+    }
+
+    let reply = match value.as_rule() {
+        Rule::new_instance => get_rust_reply(value.clone(), code, types, class_methods),
+        _ => {
+            code.add_failure(format!("line {}: return value {} in {} is not translated", value.as_span().start_pos().line_col().0, value.as_str().trim(), context.get_method_name()));
+            String::from("warp::reply()")
+        }
+    };
+
+    statements.truncate(statements.len() - 2);
+
+    Some(format!("warp::Reply::into_response({})", reply))
+}
+
+fn get_block_statements(iter: Pair<Rule>) -> Vec<Pair<Rule>> {
+    iter.into_inner().filter(|a| a.as_rule() == Rule::code).flat_map(|a| a.into_inner())
+        .filter(|a| a.as_rule() != Rule::semicolon && a.as_rule() != Rule::await_key_word).collect()
+}
+
+fn get_rust_block_statements(statements: &[Pair<Rule>], context: BodyContext, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods,
+    exception_name: &str, indent: usize) -> String {
+
+    let mut body = String::from("");
+
+    let mut is_skipped = false;
+
+    for (index, elem) in statements.iter().cloned().enumerate() {
+        if is_skipped {
+            is_skipped = false;
+            continue;
+        }
+
+        types.position = elem.as_span().end();
+
+        let line = elem.as_span().start_pos().line_col().0;
+
+        if elem.as_rule() == Rule::return_key_word {
+            code.add_warning(format!("line {}: return inside a try block in {} is not translated", line, context.get_method_name()));
+            is_skipped = true;
+            continue;
+        }
+
+        let (statement, is_fused) = match elem.as_rule() {
+            Rule::throw_statement => (match_throw_statement_pairs(elem, code, exception_name, false, indent, types, class_methods), false),
+            _ => match_statement_code_pairs(elem, statements.get(index + 1), context, code, types, class_methods, indent)
+        };
+
+        if statement.is_empty() {
+            code.add_warning(format!("line {}: statement in {} is not translated", line, context.get_method_name()));
+        }
+
+        is_skipped = is_fused;
+        body.push_str(&statement);
+    }
+
+    body
+}
+
+fn match_statement_code_pairs(iter: Pair<Rule>, next: Option<&Pair<Rule>>, context: BodyContext, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods,
+    indent: usize) -> (String, bool) {
+
+    let tabs = "\t".repeat(indent);
+
+    let source = code.get_source_marker(&iter);
+
+    let (statement, is_fused) = match (iter.as_rule(), context) {
+        (Rule::try_statement, _) => (match_try_statement_pairs(iter, context, code, types, class_methods, false, indent), false),
+        (Rule::throw_statement, _) => (match_throw_statement_pairs(iter, code, "error", false, indent, types, class_methods), false),
+        (Rule::assignment, BodyContext::Repository(class_type, method)) => match get_rust_counter_assignment(&iter, next, &class_type.properties) {
+            Some((x, is_fused)) => {
+                if is_fused && matches!(code.storage, Storage::Json | Storage::Sqlite) {
+                    match get_counter_seed(&iter, class_type, method, code, types) {
+                        Some((counter, seed)) => { code.counter_seeds.insert(counter, seed); },
+                        None => code.add_warning(format!("{}.{}: the counter restarts on every run and can reuse keys already persisted by the {:?} storage",
+                            class_type.name, method.name, code.storage))
+                    }
+                }
+
+                (format!("{}{}\n", tabs, x), is_fused)
+            },
+            None => match get_rust_static_assignment(&iter, class_type, method, code, types, class_methods).or_else(|| get_rust_database_assignment(&iter, class_type, code, types, class_methods)) {
+                Some(x) => (format!("{}{}\n", tabs, x), false),
+                None => (format!("{}{}\n", tabs, match_assignment_statement_pairs(iter, code, types, class_methods)), false)
+            }
+        },
+        (Rule::method_call, BodyContext::Repository(class_type, _)) => match get_rust_store_call(&iter, class_type, code, types, class_methods).or_else(|| get_rust_database_call(&iter, class_type, code, types, class_methods)) {
+            Some(x) => (format!("{}{};\n", tabs, x), false),
+            None => (format!("{}{};\n", tabs, match_method_call_code_pairs(iter, code, types, class_methods)), false)
+        },
+        (Rule::assignment, BodyContext::Method(properties, method)) => match match_member_assignment_pairs(iter.clone(), code, properties, method, types, class_methods) {
+            Some(x) => (format!("{}self.{} = {};\n", tabs, x.0, x.1), false),
+            None => match get_rust_counter_assignment(&iter, None, properties).map(|a| a.0).or_else(|| get_rust_static_member_assignment(&iter, properties, method, code, types, class_methods)) {
+                Some(x) => (format!("{}{}\n", tabs, x), false),
+                None => (format!("{}{}\n", tabs, match_assignment_statement_pairs(iter, code, types, class_methods)), false)
+            }
+        },
+        (Rule::method_call, BodyContext::Handler(class_name, action)) => match get_rust_service_call(&iter, class_name, &action.bindings, code, types, class_methods) {
+            Some(x) => (format!("{}{}?;\n", tabs, x), false),
+            None => (format!("{}{};\n", tabs, match_method_call_code_pairs(iter, code, types, class_methods)), false)
+        },
+        (Rule::assignment, BodyContext::Handler(class_name, action)) => {
+            let service_call = get_assignment_parts(&iter)
+                .and_then(|(is_declaration, target, value)| get_rust_service_call(&value, class_name, &action.bindings, code, types, class_methods).map(|a| (is_declaration, target, a)));
+
+            match service_call {
+                Some((is_declaration, target, x)) => {
+                    let binding = get_local_binding(is_declaration, &target, types);
+
+                    (format!("{}{}{} = {}?;\n", tabs, binding, get_rust_name(&target, NameKind::Local), x), false)
+                },
+                None => (format!("{}{}\n", tabs, match_assignment_statement_pairs(iter, code, types, class_methods)), false)
+            }
+        },
+        (Rule::method_call, _) => (format!("{}{};\n", tabs, match_method_call_code_pairs(iter, code, types, class_methods)), false),
+        _ => (String::from(""), false)
+    };
+
+    if statement.is_empty() {
+        return (statement, is_fused);
+    }
+
+    (format!("{}{}", source, statement), is_fused)
+}
+
+fn match_method_call_code_pairs(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let line = iter.as_span().start_pos().line_col().0;
+
+    let mut receiver = String::from("");
+
+    let mut path: Vec<&str> = Vec::new();
+
+    let mut is_chained = false;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => {
+                path.push(elem.as_str().trim());
+
+                if receiver.is_empty() {
+                    receiver = code.member_values.get(elem.as_str().trim()).cloned().unwrap_or_else(|| get_rust_method_name(elem.as_str()));
+                }
+                else {
+                    receiver = format!("{}.{}", receiver, get_rust_method_name(elem.as_str()));
+                }
+            },
+            Rule::method_invocation => {
+                let owner = if is_chained { None } else { get_invocation_owner(&path, &elem, types, class_methods) };
+
+                if let Some(x) = get_static_path(&path, &elem, code) {
+                    receiver = format!("{}::", x);
+                }
+                else if let (Some((_, x)), [] | ["this"]) = (&owner, path.as_slice()) {
+                    receiver = String::from(if x.kind == Kind::Static { "Self::" } else { "self" });
+                }
+
+                let receiver_type = if is_chained || path.is_empty() { String::from("") } else { get_member_access_type(&path.join("."), types, class_methods) };
+
+                path.clear();
+                is_chained = true;
+                receiver = match_method_invocation_pairs(elem, &receiver, &receiver_type, line, code, types, class_methods);
+
+                if owner.is_some_and(|(a, b)| code.is_fallible_method(&a, &b.name)) {
+                    receiver.push('?');
+                }
+            },
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    receiver
+}
+
+fn get_invocation_owner(path: &[&str], invocation: &Pair<Rule>, types: &TypeTable, class_methods: &ClassMethods) -> Option<(String, Method)> {
+
+    let method_name = invocation.clone().into_inner().find(|a| a.as_rule() == Rule::identifier)?.as_str().trim();
+
+    let class_name = match path {
+        [] | ["this"] => types.class_name.clone(),
+        _ => match get_member_access_type(&path.join("."), types, class_methods) {
+            x if x.is_empty() => path.join("."),
+            x => x
+        }
+    };
+
+    let owner = find_method_owner(&class_name, method_name, class_methods)?;
+
+    let method = class_methods.find_class_method(&owner)?.methods.iter().find(|a| a.name == method_name)?.clone();
+
+    Some((owner, method))
+}
+
+fn get_static_path(path: &[&str], invocation: &Pair<Rule>, code: &Code) -> Option<String> {
+
+    let [class_name] = path else {
+        return None;
+    };
+
+    let method_name = invocation.clone().into_inner().find(|a| a.as_rule() == Rule::identifier)?;
+
+    code.static_paths.get(*class_name).or_else(|| code.static_paths.get(&format!("{}.{}", class_name, method_name.as_str().trim()))).cloned()
+}
+
+fn match_method_invocation_pairs(iter: Pair<Rule>, receiver: &str, receiver_type: &str, line: usize, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut method_name = "";
+
+    let mut arguments: Vec<Pair<Rule>> = Vec::new();
+
+    let mut lambda = "";
+
+    let mut type_arguments = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { method_name = elem.as_str(); },
+            Rule::type_arguments => {
+                let values: Vec<String> = elem.into_inner().map(get_rust_property_type).collect();
+                type_arguments = format!("::<{}>", values.join(", "));
+            },
+            Rule::parameters => { arguments = elem.into_inner().collect(); },
+            Rule::lambda_expression | Rule::lambda_block | Rule::instance_parameters => { lambda = elem.as_str(); },
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            _ => unreachable!()
+        }
+    }
+
+    if !receiver.is_empty() {
+        for elem in code.extension_paths.get(method_name).cloned().unwrap_or_default() {
+            code.add_using(elem);
+        }
+    }
+
+    if receiver.ends_with("::") {
+        let mut values: Vec<String> = arguments.into_iter().map(|a| get_rust_parameter(a, code, types, class_methods)).collect();
+
+        let is_extension = code.extension_paths.get(method_name).is_some_and(|a| a.iter().any(|b| b.starts_with(&format!("use {}", receiver))));
+
+        if is_extension && !values.is_empty() {
+            let target = values.remove(0);
+            return format!("{}.{}{}({})", target, get_rust_method_name(method_name), type_arguments, values.join(", "));
+        }
+
+        return format!("{}{}{}({})", receiver, get_rust_method_name(method_name), type_arguments, values.join(", "));
+    }
+
+    if receiver == "string" {
+        match (method_name, arguments.len()) {
+            ("IsNullOrEmpty", 1) => return format!("{}.is_empty()", get_rust_receiver_parameter(arguments.remove(0), code, types, class_methods)),
+            ("IsNullOrWhiteSpace", 1) => return format!("{}.trim().is_empty()", get_rust_receiver_parameter(arguments.remove(0), code, types, class_methods)),
+            ("Join", 2) => {
+                let separator = get_rust_str_parameter(arguments.remove(0), code, types, class_methods);
+                let values = arguments.remove(0);
+                let element_type = get_list_element_type(&get_expression_type(&values, types, class_methods));
+                let list = get_rust_receiver_parameter(values, code, types, class_methods);
+
+                if element_type.is_empty() || element_type == "string" {
+                    return format!("{}.join({})", list, separator);
+                }
+
+                return format!("{}.iter().map(|a| a.to_string()).collect::<Vec<_>>().join({})", list, separator);
+            },
+            ("Format", x) if x > 0 => {
+                let format = get_rust_composite_format(arguments.remove(0), line, code, types, class_methods);
+                let values: Vec<String> = arguments.into_iter().map(|a| get_rust_parameter(a, code, types, class_methods)).collect();
+
+                if values.is_empty() {
+                    return format!("format!({})", format);
+                }
+
+                return format!("format!({}, {})", format, values.join(", "));
+            },
+            _ => {}
+        }
+    }
+
+    match (method_name, arguments.len()) {
+        ("Contains", 1) | ("StartsWith", 1) | ("EndsWith", 1) | ("Replace", 2) => {
+            let values: Vec<String> = arguments.into_iter().map(|a| get_rust_str_parameter(a, code, types, class_methods)).collect();
+            return format!("{}.{}({})", receiver, get_rust_method_name(method_name), values.join(", "));
+        },
+        ("ToUpper", 0) | ("ToUpperInvariant", 0) => {
+            if method_name == "ToUpper" {
+                code.add_warning(format!("line {}: ToUpper is culture-sensitive in C#, to_uppercase uses the Unicode default casing", line));
+            }
+
+            return format!("{}.to_uppercase()", receiver);
+        },
+        ("ToLower", 0) | ("ToLowerInvariant", 0) => {
+            if method_name == "ToLower" {
+                code.add_warning(format!("line {}: ToLower is culture-sensitive in C#, to_lowercase uses the Unicode default casing", line));
+            }
+
+            return format!("{}.to_lowercase()", receiver);
+        },
+        ("Trim", 0) => return format!("{}.trim().to_string()", receiver),
+        ("TrimStart", 0) => return format!("{}.trim_start().to_string()", receiver),
+        ("TrimEnd", 0) => return format!("{}.trim_end().to_string()", receiver),
+        ("Trim", 1) => return format!("{}.trim_matches({}).to_string()", receiver, get_rust_str_parameter(arguments.remove(0), code, types, class_methods)),
+        ("TrimStart", 1) => return format!("{}.trim_start_matches({}).to_string()", receiver, get_rust_str_parameter(arguments.remove(0), code, types, class_methods)),
+        ("TrimEnd", 1) => return format!("{}.trim_end_matches({}).to_string()", receiver, get_rust_str_parameter(arguments.remove(0), code, types, class_methods)),
+        ("IndexOf", 1) | ("LastIndexOf", 1) if receiver_type == "string" => {
+            code.add_warning(format!("line {}: {} counts UTF-16 code units in C#, the generated code returns a byte offset", line, method_name));
+
+            let search = if method_name == "IndexOf" { "find" } else { "rfind" };
+
+            return format!("{}.{}({}).map_or(-1, |a| a as i32)", receiver, search, get_rust_str_parameter(arguments.remove(0), code, types, class_methods));
+        },
+        ("Split", 1) => {
+            return format!("{}.split({}).map(String::from).collect::<Vec<String>>()", receiver, get_rust_str_parameter(arguments.remove(0), code, types, class_methods));
+        },
+        ("Substring", 1) | ("Substring", 2) => {
+            code.add_warning(format!("line {}: Substring counts UTF-16 code units in C#, the generated code counts Unicode scalar values", line));
+
+            let start = get_rust_index_parameter(arguments.remove(0), code, types, class_methods);
+
+            if arguments.is_empty() {
+                return format!("{}.chars().skip({}).collect::<String>()", receiver, start);
+            }
+
+            return format!("{}.chars().skip({}).take({}).collect::<String>()", receiver, start, get_rust_index_parameter(arguments.remove(0), code, types, class_methods));
+        },
+        _ => {}
+    }
+
+    let values: Vec<String> = if lambda.is_empty() {
+        arguments.into_iter().map(|a| get_rust_parameter(a, code, types, class_methods)).collect()
+    }
+    else {
+        vec![String::from(lambda)]
+    };
+
+    if receiver.is_empty() {
+        return format!("{}{}({})", get_rust_method_name(method_name), type_arguments, values.join(", "));
+    }
+
+    format!("{}.{}{}({})", receiver, get_rust_method_name(method_name), type_arguments, values.join(", "))
+}
+
+fn get_rust_member_path(value: &str) -> String {
+
+    let mut segments: Vec<String> = Vec::new();
+
+    for (index, elem) in value.trim().split('.').map(|a| a.trim()).enumerate() {
+        match (index, elem) {
+            (0, "this") => segments.push(String::from("self")),
+            (0, "base") => segments.push(String::from("self.base")),
+            (0, _) => segments.push(get_rust_name(elem, NameKind::Local)),
+            _ => segments.push(get_rust_name(elem, NameKind::Field))
+        }
+    }
+
+    segments.join(".")
+}
+
+fn get_rust_method_name(value: &str) -> String {
+
+    let method_name = match value {
+        "ToString" => "to_string",
+        "StartsWith" => "starts_with",
+        "EndsWith" => "ends_with",
+        "String" => "string",
+        "this" => "self",
+        "base" => "self.base",
+        _ => return get_rust_name(value, NameKind::Function)
+    };
+
+    String::from(method_name)
+}
+
+fn get_rust_parameter(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut value = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::string_literal => { value = get_rust_string_literal(elem, code, types, class_methods); },
+            Rule::identifier => { value = get_rust_operand(elem.as_str(), false, code, types, class_methods); },
+            Rule::number => { value = String::from(elem.as_str().trim()); },
+            Rule::math_exp => { value = get_rust_operand_expression(elem, code, types, class_methods); },
+            _ => unreachable!()
+        }
+    }
+
+    value
+}
+
+fn get_rust_receiver_parameter(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let value = get_rust_str_parameter(iter, code, types, class_methods);
+
+    String::from(value.trim_start_matches('&'))
+}
+
+fn get_rust_str_parameter(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut value = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::string_literal => { value = get_rust_str_literal(elem, code, types, class_methods); },
+            Rule::identifier => { value = format!("&{}", get_rust_operand(elem.as_str(), true, code, types, class_methods)); },
+            Rule::number => { value = String::from(elem.as_str().trim()); },
+            Rule::math_exp => { value = get_rust_operand_expression(elem, code, types, class_methods); },
+            _ => unreachable!()
+        }
+    }
+
+    value
+}
+
+fn get_rust_index_parameter(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let value = get_rust_parameter(iter, code, types, class_methods);
+
+    if value.chars().all(|a| a.is_ascii_digit()) {
+        return value;
+    }
+
+    format!("{} as usize", value)
+}
+
+fn get_rust_string_literal(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let line = iter.as_span().start_pos().line_col().0;
+
+    let elem = iter.into_inner().next().expect("");
+
+    match elem.as_rule(){
+        Rule::char_literal => format!("String::from({})", get_rust_char_literal(elem)),
+        Rule::interpolated_string | Rule::interpolated_verbatim_string => match_interpolated_string_pairs(elem, line, code, types, class_methods),
+        _ => format!("String::from({})", get_rust_str_literal_value(elem))
+    }
+}
+
+fn get_rust_str_literal(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let line = iter.as_span().start_pos().line_col().0;
+
+    let elem = iter.into_inner().next().expect("");
+
+    match elem.as_rule(){
+        Rule::char_literal => get_rust_char_literal(elem),
+        Rule::interpolated_string | Rule::interpolated_verbatim_string => format!("&{}", match_interpolated_string_pairs(elem, line, code, types, class_methods)),
+        _ => get_rust_str_literal_value(elem)
+    }
+}
+
+fn get_rust_str_literal_value(iter: Pair<Rule>) -> String {
+
+    let rule = iter.as_rule();
+
+    let inner = iter.into_inner().next().map(|a| a.as_str()).unwrap_or("");
+
+    match rule {
+        Rule::string => format!("\"{}\"", get_rust_escaped_string(inner)),
+        Rule::verbatim_string => get_rust_raw_string(&inner.replace("\"\"", "\"")),
+        Rule::raw_string => get_rust_raw_string(&get_raw_string_content(inner)),
+        _ => unreachable!()
+    }
+}
+
+fn get_rust_char_literal(iter: Pair<Rule>) -> String {
+
+    let inner = iter.into_inner().next().map(|a| a.as_str()).unwrap_or("");
+
+    if inner == "\"" {
+        return String::from("'\"'");
+    }
+
+    format!("'{}'", get_rust_escaped_string(inner))
+}
+
+fn get_rust_escaped_string(value: &str) -> String {
+
+    let mut escaped = String::from("");
+
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            escaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                escaped.push_str(&format!("\\u{{{}}}", hex));
+            },
+            Some('U') => {
+                let hex: String = chars.by_ref().take(8).collect();
+                escaped.push_str(&format!("\\u{{{}}}", hex.trim_start_matches('0')));
+            },
+            Some('x') => {
+                let mut hex = String::from("");
+
+                while hex.len() < 4 && chars.peek().is_some_and(|a| a.is_ascii_hexdigit()) {
+                    hex.push(chars.next().expect(""));
+                }
+
+                escaped.push_str(&format!("\\u{{{}}}", hex));
+            },
+            Some('a') => escaped.push_str("\\u{7}"),
+            Some('b') => escaped.push_str("\\u{8}"),
+            Some('f') => escaped.push_str("\\u{c}"),
+            Some('v') => escaped.push_str("\\u{b}"),
+            Some(x) => {
+                escaped.push('\\');
+                escaped.push(x);
+            },
+            None => escaped.push_str("\\\\")
+        }
+    }
+
+    escaped
+}
+
+fn get_rust_raw_string(value: &str) -> String {
+
+    let mut hashes = String::from("");
+
+    while value.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+
+    format!("r{}\"{}\"{}", hashes, value, hashes)
+}
+
+fn get_raw_string_content(value: &str) -> String {
+
+    if !value.starts_with('\n') && !value.starts_with("\r\n") {
+        return String::from(value);
+    }
+
+    let lines: Vec<&str> = value.lines().skip(1).collect();
+
+    let indentation = lines.last().map(|a| a.len()).unwrap_or(0);
+
+    let content: Vec<&str> = lines[..lines.len().saturating_sub(1)].iter().map(|a| if a.len() >= indentation { &a[indentation..] } else { a.trim_start() }).collect();
+
+    content.join("\n")
+}
+
+fn match_interpolated_string_pairs(iter: Pair<Rule>, line: usize, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut format = String::from("");
+
+    let mut values: Vec<String> = Vec::new();
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::interpolated_text => format.push_str(&get_rust_escaped_string(elem.as_str())),
+            Rule::verbatim_text => format.push_str(&elem.as_str().replace("\"\"", "\"").replace('\\', "\\\\").replace('"', "\\\"")),
+            Rule::interpolation => {
+                let mut alignment = "";
+
+                let mut format_specifier = "";
+
+                for part in elem.into_inner() {
+                    match part.as_rule(){
+                        Rule::interpolation_expression => values.push(get_rust_interpolation_expression(part.as_str(), code, types, class_methods)),
+                        Rule::interpolation_alignment => { alignment = part.as_str(); },
+                        Rule::interpolation_format => { format_specifier = part.as_str(); },
+                        _ => unreachable!()
+                    }
+                }
+
+                format.push_str(&format!("{{{}}}", get_rust_format_spec(alignment, format_specifier, line, code)));
+            },
+            _ => unreachable!()
+        }
+    }
+
+    if values.is_empty() {
+        return format!("String::from(\"{}\")", format.replace("{{", "{").replace("}}", "}"));
+    }
+
+    format!("format!(\"{}\", {})", format, values.join(", "))
+}
+
+fn get_rust_interpolation_expression(value: &str, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let value = value.trim();
+
+    if let Ok(mut pairs) = CSParser::parse(Rule::method_call, value) {
+        let method_call = pairs.next().expect("");
+
+        if method_call.as_str() == value {
+            return match_method_call_code_pairs(method_call, code, types, class_methods);
+        }
+    }
+
+    if value.chars().all(|a| a.is_alphanumeric() || a == '_' || a == '.') {
+        return get_rust_operand(value, true, code, types, class_methods);
+    }
+
+    let (root, path) = value.split_once('.').unwrap_or((value, ""));
+
+    match code.member_values.get(root.trim()) {
+        Some(x) if path.is_empty() => x.clone(),
+        Some(x) => format!("{}.{}", x, get_rust_member_path(path)),
+        None => get_rust_member_path(value)
+    }
+}
+
+fn get_rust_format_spec(alignment: &str, format: &str, line: usize, code: &mut Code) -> String {
+
+    let mut spec = String::from("");
+
+    if let Some(width) = alignment.strip_prefix('-') {
+        spec.push_str(&format!("<{}", width));
+    }
+    else if !alignment.is_empty() {
+        spec.push_str(&format!(">{}", alignment));
+    }
+
+    let format = format.trim();
+
+    let mut format_chars = format.chars();
+
+    let format_type = format_chars.next();
+
+    let digits: String = format_chars.collect();
+
+    if !digits.is_empty() && !digits.chars().all(|a| a.is_ascii_digit()) {
+        code.add_warning(format!("line {}: custom format string \"{}\" is not supported and was ignored", line, format));
+    }
+    else {
+        match format_type {
+            Some('N') | Some('n') => {
+                code.add_warning(format!("line {}: format \"{}\" groups thousands in C#, the generated code does not", line, format));
+                spec.push_str(&format!(".{}", if digits.is_empty() { "2" } else { &digits }));
+            },
+            Some('F') | Some('f') => spec.push_str(&format!(".{}", if digits.is_empty() { "2" } else { &digits })),
+            Some('D') | Some('d') if spec.is_empty() && !digits.is_empty() => spec.push_str(&format!("0{}", digits)),
+            Some('D') | Some('d') => {},
+            Some('X') | Some('x') => {
+                if spec.is_empty() && !digits.is_empty() {
+                    spec.push_str(&format!("0{}", digits));
+                }
+
+                spec.push(format_type.expect(""));
+            },
+            Some('E') => spec.push_str(&format!(".{}E", if digits.is_empty() { "6" } else { &digits })),
+            Some('e') => spec.push_str(&format!(".{}e", if digits.is_empty() { "6" } else { &digits })),
+            Some(_) => code.add_warning(format!("line {}: format \"{}\" is not supported and was ignored", line, format)),
+            None => {}
+        }
+    }
+
+    if spec.is_empty() {
+        return spec;
+    }
+
+    format!(":{}", spec)
+}
+
+fn get_rust_composite_format(iter: Pair<Rule>, line: usize, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let value = get_rust_str_parameter(iter, code, types, class_methods);
+
+    if !value.starts_with('"') {
+        code.add_warning(format!("line {}: string.Format needs a literal format string", line));
+        return String::from("\"{}\"");
+    }
+
+    let mut format = String::from("");
+
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                format.push_str("{{");
+            },
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                format.push_str("}}");
+            },
+            '{' => {
+                let item: String = chars.by_ref().take_while(|a| *a != '}').collect();
+
+                let (index_alignment, format_specifier) = item.split_once(':').unwrap_or((&item, ""));
+
+                let (index, alignment) = index_alignment.split_once(',').unwrap_or((index_alignment, ""));
+
+                format.push_str(&format!("{{{}{}}}", index.trim(), get_rust_format_spec(alignment.trim(), format_specifier, line, code)));
+            },
+            _ => format.push(c)
+        }
+    }
+
+    format
+}
+
+fn match_assignment_statement_pairs(iter: Pair<Rule>, code: &mut Code, types: &mut TypeTable, class_methods: &mut ClassMethods) -> String {
+
+    let mut target = String::from("");
+
+    let mut value = String::from("");
+
+    let mut target_type = String::from("");
+
+    let mut annotation = String::from("");
+
+    let mut is_declaration = false;
+
+    let mut is_var = false;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::var_key_word => {
+                is_declaration = true;
+                is_var = true;
+            },
+            Rule::property_type => {
+                is_declaration = true;
+                target_type = String::from(elem.as_str().trim());
+            },
+            Rule::property_call if target.is_empty() => { target = String::from(elem.as_str().trim()); },
+            Rule::await_key_word => {},
+            Rule::semicolon => {},
+            _ => {
+                if is_var {
+                    target_type = get_expression_type(&elem, types, class_methods);
+                }
+
+                if is_declaration && elem.as_rule() != Rule::method_call {
+                    annotation = get_rust_local_type(&target_type, types, class_methods);
+                }
+
+                value = match elem.as_rule(){
+                    Rule::string_literal if elem.as_str().starts_with('\'') => get_rust_str_literal(elem, code, types, class_methods),
+                    Rule::property_call if types.find_usage(get_local_root_name(elem.as_str())).is_some() && !elem.as_str().contains('.') => {
+                        get_rust_local_value(elem.as_str().trim(), types)
+                    },
+                    _ => get_rust_expression_value(elem, &target_type, code, types, class_methods)
+                };
+            }
+        }
+    }
+
+    if !is_declaration {
+        return format!("{} = {};", get_rust_member_path(&target), value);
+    }
+
+    types.add_local(&target, &target_type);
+
+    let binding = if types.find_usage(&target).is_some_and(|a| a.is_mutated) { "let mut" } else { "let" };
+
+    if annotation.is_empty() {
+        format!("{} {} = {};", binding, get_rust_name(&target, NameKind::Local), value)
+    }
+    else {
+        format!("{} {}: {} = {};", binding, get_rust_name(&target, NameKind::Local), annotation, value)
+    }
+}
+
+fn match_enum_parse_pairs(iter: Pair<Rule>, code: &mut Code, types: &TypeTable, class_methods: &ClassMethods) -> String {
+
+    let mut enum_name = "";
+
+    let mut value = String::from("");
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { enum_name = elem.as_str(); },
+            Rule::typeof_expression => {
+                for identifier in elem.into_inner() {
+                    if identifier.as_rule() == Rule::identifier {
+                        enum_name = identifier.as_str();
+                    }
+                }
+            },
+            Rule::parameter => { value = get_rust_str_parameter(elem, code, types, class_methods); },
+            Rule::left_parenthesis => {},
+            Rule::right_parenthesis => {},
+            Rule::semicolon => {},
+            _ => unreachable!()
+        }
+    }
+
+    format!("{}.parse::<{}>()?", value, enum_name)
+}
+
+fn get_rust_enum_repr_type(value: &str) -> String {
+
+    let repr_type = match value {
+        "byte" => "u8",
+        "sbyte" => "i8",
+        "short" => "i16",
+        "ushort" => "u16",
+        "uint" => "u32",
+        "long" => "i64",
+        "ulong" => "u64",
+        _ => "i32"
+    };
+
+    String::from(repr_type)
+}
+
+fn match_enum_code_pairs(iter: Pair<Rule>, code: &mut Code, types: &mut TypeTable) {
+
+    code.add_struct(&code.get_source_marker(&iter));
+
+    let mut enum_name = "";
+
+    let mut repr_type = String::from("i32");
+
+    let mut is_flags = false;
+
+    let mut is_string = code.is_string_enums;
+
+    let mut members: Vec<Type> = Vec::new();
+
+    let mut next_value: i64 = 0;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::attribute => {
+                if elem.as_str().contains("Flags") {
+                    is_flags = true;
+                }
+
+                if elem.as_str().contains("JsonStringEnumConverter") {
+                    is_string = true;
+                }
+            },
+            Rule::enum_member => {
+                let (member, value) = match_enum_member_pairs(elem, &members, next_value, code);
+                next_value = value + 1;
+                members.push(member);
+            },
+            Rule::identifier => { enum_name = elem.as_str(); },
+            Rule::enum_base_type => { repr_type = get_rust_enum_repr_type(elem.as_str()); },
+            Rule::public_key_word => {},
+            Rule::enum_key_word => {},
+            Rule::left_bracers => {},
+            Rule::right_bracers => {},
+            _ => unreachable!()
+        }
+    }
+
+    types.add_type(Type { name: String::from(enum_name), type_name: repr_type.clone(), kind: Kind::Enum, properties: members.clone() });
+
+    code.add_error(String::from("Argument"));
+
+    if is_flags {
+        add_flags_enum_code(code, enum_name, &repr_type, &members, is_string);
+    }
+    else {
+        add_enum_code(code, enum_name, &repr_type, &members, is_string);
+    }
+}
+
+fn match_enum_member_pairs(iter: Pair<Rule>, members: &[Type], next_value: i64, code: &mut Code) -> (Type, i64) {
+
+    let mut member_name = "";
+
+    let mut value = next_value;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::identifier => { member_name = elem.as_str(); },
+            Rule::enum_value => { value = get_enum_value(elem, members, code); },
+            Rule::attribute => {},
+            _ => unreachable!()
+        }
+    }
+
+    (Type { name: String::from(member_name), type_name: value.to_string(), kind: Kind::EnumMember, properties: Vec::new() }, value)
+}
+
+fn get_enum_value(iter: Pair<Rule>, members: &[Type], code: &mut Code) -> i64 {
+
+    let line = iter.as_span().start_pos().line_col().0;
+
+    let mut value: i64 = 0;
+
+    for elem in iter.into_inner() {
+        match elem.as_rule(){
+            Rule::number => { value |= get_enum_number(elem.as_str(), line, code); },
+            Rule::enum_shift => {
+                let operands: Vec<i64> = elem.into_inner().map(|a| get_enum_number(a.as_str(), line, code)).collect();
+                value |= operands[0].checked_shl(operands[1] as u32).unwrap_or_default();
+            },
+            Rule::identifier => {
+                match members.iter().find(|a| a.name == elem.as_str()) {
+                    Some(x) => { value |= x.type_name.parse::<i64>().unwrap_or_default(); },
+                    None => code.add_failure(format!("line {}: enum member {} not found", line, elem.as_str()))
+                }
+            },
+            _ => unreachable!()
+        }
+    }
+
+    value
+}
+
+fn get_enum_number(value: &str, line: usize, code: &mut Code) -> i64 {
+
+    match value.trim().parse::<i64>() {
+        Ok(x) => x,
+        Err(_) => {
+            code.add_failure(format!("line {}: enum value {} is not an integer", line, value.trim()));
+            0
+        }
+    }
+}
+
+fn add_enum_code(code: &mut Code, enum_name: &str, repr_type: &str, members: &[Type], is_string: bool) {
+
+    let mut variants: Vec<&Type> = Vec::new();
+
+    let mut aliases: Vec<(&Type, &Type)> = Vec::new();
+
+    for elem in members.iter() {
+        match variants.iter().find(|a| a.type_name == elem.type_name) {
+            Some(variant) => aliases.push((elem, variant)),
+            None => variants.push(elem)
+        }
+    }
+
+    let default_variant = variants.iter().find(|a| a.type_name == "0").or(variants.first()).map(|a| a.name.clone()).unwrap_or_default();
+
+    let variant_names: Vec<String> = variants.iter().map(|a| a.name.clone()).collect();
+
+    for elem in naming::find_collisions(&variant_names, NameKind::Type) {
+        code.add_failure(format!("{}: variant names {}; rename one of them", enum_name, elem));
+    }
+
+    let database_derive = if code.is_database() { ", sqlx::Type" } else { "" };
+
+    let mut rust_enum = RustEnum::new(enum_name);
+
+    if is_string {
+        rust_enum.add_attribute(format!("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize{})]", database_derive));
+    }
+    else {
+        code.add_using(String::from("use serde_repr::{Serialize_repr, Deserialize_repr};\n"));
+        rust_enum.add_attribute(format!("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize_repr, Deserialize_repr{})]", database_derive));
+    }
+
+    rust_enum.add_attribute(format!("#[repr({})]", repr_type));
+
+    for elem in variants.iter() {
+        let mut variant = String::from("");
+
+        if elem.name == default_variant {
+            variant.push_str("   #[default]\n");
+        }
+
+        let variant_name = get_rust_name(&elem.name, NameKind::Type);
+
+        if is_string && variant_name != elem.name {
+            variant.push_str(&format!("   #[serde(rename = \"{}\")]\n", elem.name));
+        }
+
+        variant.push_str(&format!("   {} = {},\n", variant_name, elem.type_name));
+
+        rust_enum.add_variant(variant);
+    }
+
+    code.add_struct(&rust_enum.to_code());
+
+    if !aliases.is_empty() {
+        let mut alias_impl = RustImpl::new("", None, enum_name);
+
+        for (alias, variant) in aliases.iter() {
+            alias_impl.add_item(format!("\n\tpub const {}: {} = {}::{};", alias.name, enum_name, enum_name, get_rust_name(&variant.name, NameKind::Type)));
+        }
+
+        code.add_struct(&format!("\n#[allow(non_upper_case_globals)]{}", alias_impl.to_code()));
+    }
+
+    let display_arms: Vec<String> = variants.iter()
+        .map(|a| format!("\n\t\t\t{}::{} => write!(f, \"{}\"),", enum_name, get_rust_name(&a.name, NameKind::Type), a.name)).collect();
+
+    let mut fmt = RustFunction::new("fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result");
+    fmt.add_statement(format!("\n\t\tmatch self {{{}\n\t\t}}", display_arms.concat()));
+
+    match fmt.to_code() {
+        Ok(x) => {
+            let mut display_impl = RustImpl::new("", Some("std::fmt::Display"), enum_name);
+            display_impl.add_item(x);
+
+            code.add_struct(&display_impl.to_code());
+        },
+        Err(e) => code.add_failure(format!("{}: {}", enum_name, e))
+    }
+
+    let mut parse_arms: Vec<String> = Vec::new();
+
+    for elem in variants.iter() {
+        let mut names: Vec<String> = vec![format!("\"{}\"", elem.name)];
+
+        for (alias, _) in aliases.iter().filter(|(_, a)| a.name == elem.name) {
+            names.push(format!("\"{}\"", alias.name));
+        }
+
+        names.push(format!("\"{}\"", elem.type_name));
+
+        parse_arms.push(format!("\n\t\t\t{} => Ok({}::{}),", names.join(" | "), enum_name, get_rust_name(&elem.name, NameKind::Type)));
+    }
+
+    let error = code.get_runtime_name("Error");
+
+    parse_arms.push(format!("\n\t\t\t_ => Err({}::Argument(format!(\"Requested value '{{}}' was not found.\", value))),", error));
+
+    let mut from_str_impl = RustImpl::new("", Some("std::str::FromStr"), enum_name);
+    from_str_impl.add_item(format!("\n\ttype Err = {};\n", error));
+    from_str_impl.add_item(format!("\n\tfn from_str(value: &str) -> Result<Self, Self::Err> {{\n\t\tmatch value.trim() {{{}\n\t\t}}\n\t}}\n", parse_arms.concat()));
+
+    code.add_struct(&from_str_impl.to_code());
+}
+
+fn add_flags_enum_code(code: &mut Code, enum_name: &str, repr_type: &str, members: &[Type], is_string: bool) {
+
+    let mut sorted_members: Vec<&Type> = members.iter().collect();
+
+    sorted_members.sort_by_key(|a| std::cmp::Reverse(a.type_name.parse::<i64>().unwrap_or_default()));
+
+    code.add_struct("\n#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]");
+
+    if is_string {
+        code.add_struct("\n#[serde(into = \"String\", try_from = \"String\")]");
+    }
+    else {
+        code.add_struct("\n#[serde(transparent)]");
+    }
+
+    code.add_struct(&format!("\npub struct {}(pub {});\n", enum_name, repr_type));
+
+    code.add_struct(&format!("\nimpl {} {{", enum_name));
+
+    for elem in members.iter() {
+        code.add_struct(&format!("\n\tpub const {}: {} = {}({});", elem.name.to_uppercase(), enum_name, enum_name, elem.type_name));
+    }
+
+    let names: Vec<String> = sorted_members.iter().map(|a| format!("(\"{}\", {})", a.name, a.type_name)).collect();
+
+    code.add_struct(&format!("\n\n\tconst NAMES: [(&'static str, {}); {}] = [{}];", repr_type, names.len(), names.join(", ")));
+    code.add_struct(&format!("\n\n\tpub fn has_flag(&self, flag: {}) -> bool {{", enum_name));
+    code.add_struct("\n\t\tself.0 & flag.0 == flag.0");
+    code.add_struct("\n\t}\n}\n");
+
+    for (operator_trait, operator_fn, operator) in [("BitOr", "bitor", "|"), ("BitAnd", "bitand", "&")] {
+        code.add_struct(&format!("\nimpl std::ops::{} for {} {{", operator_trait, enum_name));
+        code.add_struct("\n\ttype Output = Self;\n");
+        code.add_struct(&format!("\n\tfn {}(self, other: Self) -> Self {{", operator_fn));
+        code.add_struct(&format!("\n\t\t{}(self.0 {} other.0)", enum_name, operator));
+        code.add_struct("\n\t}\n}\n");
+    }
+
+    let mut fmt = RustFunction::new("fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result");
+    fmt.add_statement(String::from("\n\t\tif let Some((name, _)) = Self::NAMES.iter().find(|(_, a)| *a == self.0) {\n\t\t\treturn write!(f, \"{}\", name);\n\t\t}\n"));
+    fmt.add_statement(String::from("\n\t\tlet mut remaining = self.0;"));
+    fmt.add_statement(String::from("\n\t\tlet mut names = Vec::new();\n"));
+    fmt.add_statement(String::from("\n\t\tfor (name, value) in Self::NAMES.iter().filter(|(_, a)| *a != 0) {\n\t\t\tif remaining & value == *value {\n\t\t\t\tnames.insert(0, *name);\n\t\t\t\tremaining &= !value;\n\t\t\t}\n\t\t}\n"));
+    fmt.add_statement(String::from("\n\t\tif names.is_empty() || remaining != 0 {\n\t\t\treturn write!(f, \"{}\", self.0);\n\t\t}\n"));
+    fmt.add_statement(String::from("\n\t\twrite!(f, \"{}\", names.join(\", \"))"));
+
+    match fmt.to_code() {
+        Ok(x) => {
+            let mut display_impl = RustImpl::new("", Some("std::fmt::Display"), enum_name);
+            display_impl.add_item(x);
+
+            code.add_struct(&display_impl.to_code());
+        },
+        Err(e) => code.add_failure(format!("{}: {}", enum_name, e))
+    }
+
+    let error = code.get_runtime_name("Error");
+
+    code.add_struct(&format!("\nimpl std::str::FromStr for {} {{", enum_name));
+    code.add_struct(&format!("\n\ttype Err = {};\n", error));
+    code.add_struct("\n\tfn from_str(value: &str) -> Result<Self, Self::Err> {");
+    code.add_struct("\n\t\tlet mut result = 0;\n");
+    code.add_struct("\n\t\tfor name in value.split(',').map(|a| a.trim()) {");
+    code.add_struct("\n\t\t\tresult |= match Self::NAMES.iter().find(|(a, _)| *a == name) {");
+    code.add_struct("\n\t\t\t\tSome((_, flag)) => *flag,");
+    code.add_struct(&format!("\n\t\t\t\tNone => name.parse().map_err(|_| {}::Argument(format!(\"Requested value '{{}}' was not found.\", value)))?,", error));
+    code.add_struct("\n\t\t\t};\n\t\t}\n");
+    code.add_struct(&format!("\n\t\tOk({}(result))", enum_name));
+    code.add_struct("\n\t}\n}\n");
+
+    if is_string {
+        code.add_struct(&format!("\nimpl From<{}> for String {{", enum_name));
+        code.add_struct(&format!("\n\tfn from(value: {}) -> String {{", enum_name));
+        code.add_struct("\n\t\tvalue.to_string()");
+        code.add_struct("\n\t}\n}\n");
+
+        code.add_struct(&format!("\nimpl TryFrom<String> for {} {{", enum_name));
+        code.add_struct(&format!("\n\ttype Error = {};\n", error));
+        code.add_struct("\n\tfn try_from(value: String) -> Result<Self, Self::Error> {");
+        code.add_struct("\n\t\tvalue.parse()");
+        code.add_struct("\n\t}\n}\n");
+    }
+
+    code.add_struct("\n");
+}
+
+fn match_error_enum_code(code: &mut Code) {
+
+    let mut error_enum = String::from("\n#[derive(Debug, Error)]\npub enum Error\n{\n");
+
+    for elem in code.errors.iter() {
+        error_enum.push_str(&format!("   #[error(\"{{0}}\")]\n   {}(String),\n", elem));
+    }
+
+    error_enum.push_str("}\n\nimpl warp::reject::Reject for Error {}\n");
+
+    code.add_type(&error_enum);
+}
+
+fn add_database_code(code: &mut Code, types: &TypeTable) {
+
+    if !code.is_database() {
+        return;
+    }
+
+    code.add_error(String::from("Database"));
+
+    code.add_using(String::from("use parking_lot::Mutex;\n"));
+    code.add_using(String::from("use sqlx::sqlite::SqlitePool;\n"));
+
+    code.add_using(String::from("use sqlx::{Arguments, Row};\n"));
+    code.add_using(String::from("use sqlx::sqlite::{Sqlite, SqliteArguments, SqliteConnectOptions, SqlitePoolOptions, SqliteRow};\n"));
+    code.add_using(String::from("use std::marker::PhantomData;\n"));
+    code.add_using(String::from("use std::str::FromStr;\n"));
+
+    let mut runtime = String::from("\npub struct Change {\n\tsql: String,\n\targuments: SqliteArguments<'static>\n}\n");
+
+    runtime.push_str("\nimpl From<sqlx::Error> for Error {\n\tfn from(error: sqlx::Error) -> Self {\n\t\tError::Database(error.to_string())\n\t}\n}\n");
+    runtime.push_str(&format!("\n{}", code.get_runtime_visibility()));
+    runtime.push_str("fn add_argument<V>(arguments: &mut SqliteArguments<'static>, value: V) -> Result<(), Error> where V: sqlx::Encode<'static, Sqlite> + sqlx::Type<Sqlite> + Send + 'static {");
+    runtime.push_str("\n\targuments.add(value).map_err(|e| Error::Database(e.to_string()))\n}\n");
+
+    runtime.push_str("\npub trait Record: Entity + Default + Send + Unpin + 'static {\n\tconst TABLE: &'static str;\n\n\tconst COLUMNS: &'static [&'static str];\n\n");
+    runtime.push_str("\tconst IS_GENERATED: bool;\n\n\tconst SCHEMA: &'static str;\n\n\tfn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error>;\n\n");
+    runtime.push_str("\tfn arguments(&self) -> Result<SqliteArguments<'static>, Error>;\n}\n");
+
+    runtime.push_str("\npub struct DbSet<T> {\n\tpool: SqlitePool,\n\tchanges: Arc<Mutex<Vec<Change>>>,\n\tmarker: PhantomData<T>\n}\n");
+    runtime.push_str("\nimpl<T: Record> DbSet<T> where T::Key: Default + sqlx::Encode<'static, Sqlite> + sqlx::Type<Sqlite> + Send + 'static {");
+    runtime.push_str("\n\tpub fn new(pool: SqlitePool, changes: Arc<Mutex<Vec<Change>>>) -> Self {\n\t\tDbSet { pool, changes, marker: PhantomData }\n\t}\n");
+    runtime.push_str("\n\tpub async fn to_list(&self) -> Result<Vec<T>, Error> {\n\t\tself.select(String::from(\"\"), SqliteArguments::default()).await\n\t}\n");
+    runtime.push_str("\n\tpub async fn find(&self, key: T::Key) -> Result<Option<T>, Error> {\n\t\tOk(self.filter(T::KEY_NAME, \"=\", key).await?.into_iter().next())\n\t}\n");
+    runtime.push_str("\n\tpub async fn filter<V>(&self, column: &str, operator: &str, value: V) -> Result<Vec<T>, Error> where V: sqlx::Encode<'static, Sqlite> + sqlx::Type<Sqlite> + Send + 'static {");
+    runtime.push_str("\n\t\tlet mut arguments = SqliteArguments::default();\n\n\t\tadd_argument(&mut arguments, value)?;\n\n");
+    runtime.push_str("\t\tself.select(format!(\"WHERE {} {} ?\", column, operator), arguments).await\n\t}\n");
+    runtime.push_str("\n\tpub async fn execute_delete<V>(&self, column: &str, operator: &str, value: V) -> Result<i32, Error> where V: sqlx::Encode<'static, Sqlite> + sqlx::Type<Sqlite> + Send + 'static {");
+    runtime.push_str("\n\t\tlet mut arguments = SqliteArguments::default();\n\n\t\tadd_argument(&mut arguments, value)?;\n\n");
+    runtime.push_str("\t\tlet sql = format!(\"DELETE FROM {} WHERE {} {} ?\", T::TABLE, column, operator);\n\n");
+    runtime.push_str("\t\tOk(sqlx::query_with(&sql, arguments).execute(&self.pool).await?.rows_affected() as i32)\n\t}\n");
+    runtime.push_str("\n\tasync fn select(&self, filter: String, arguments: SqliteArguments<'static>) -> Result<Vec<T>, Error> {");
+    runtime.push_str("\n\t\tlet sql = format!(\"SELECT * FROM {} {}\", T::TABLE, filter);\n\n");
+    runtime.push_str("\t\tlet rows = sqlx::query_with(&sql, arguments).fetch_all(&self.pool).await?;\n\n");
+    runtime.push_str("\t\tOk(rows.iter().map(T::from_row).collect::<Result<Vec<T>, sqlx::Error>>()?)\n\t}\n");
+    runtime.push_str("\n\tpub fn add(&self, entity: T) -> Result<(), Error> {\n\t\tlet mut arguments = entity.arguments()?;\n\n\t\tlet mut columns = T::COLUMNS.to_vec();\n\n");
+    runtime.push_str("\t\tif !T::IS_GENERATED || entity.key() != T::Key::default() {\n\t\t\tadd_argument(&mut arguments, entity.key())?;\n\t\t\tcolumns.push(T::KEY_NAME);\n\t\t}\n\n");
+    runtime.push_str("\t\tlet sql = format!(\"INSERT INTO {} ({}) VALUES ({})\", T::TABLE, columns.join(\", \"), vec![\"?\"; columns.len()].join(\", \"));\n\n");
+    runtime.push_str("\t\tself.changes.lock().push(Change { sql, arguments });\n\n\t\tOk(())\n\t}\n");
+    runtime.push_str("\n\tpub fn update(&self, entity: T) -> Result<(), Error> {\n\t\tlet mut arguments = entity.arguments()?;\n\n\t\tadd_argument(&mut arguments, entity.key())?;\n\n");
+    runtime.push_str("\t\tlet assignments: Vec<String> = T::COLUMNS.iter().map(|a| format!(\"{} = ?\", a)).collect();\n\n");
+    runtime.push_str("\t\tlet sql = format!(\"UPDATE {} SET {} WHERE {} = ?\", T::TABLE, assignments.join(\", \"), T::KEY_NAME);\n\n");
+    runtime.push_str("\t\tself.changes.lock().push(Change { sql, arguments });\n\n\t\tOk(())\n\t}\n");
+    runtime.push_str("\n\tpub fn remove(&self, entity: T) -> Result<(), Error> {\n\t\tlet mut arguments = SqliteArguments::default();\n\n\t\tadd_argument(&mut arguments, entity.key())?;\n\n");
+    runtime.push_str("\t\tlet sql = format!(\"DELETE FROM {} WHERE {} = ?\", T::TABLE, T::KEY_NAME);\n\n");
+    runtime.push_str("\t\tself.changes.lock().push(Change { sql, arguments });\n\n\t\tOk(())\n\t}\n}\n");
+
+    runtime.push_str(&format!("\n{}", code.get_runtime_visibility()));
+    runtime.push_str("async fn connect_database(url: &str) -> Result<SqlitePool, Error> {\n\tlet options = SqliteConnectOptions::from_str(url)?.create_if_missing(true);\n\n");
+    runtime.push_str("\tlet connections = if url.contains(\":memory:\") || url.contains(\"mode=memory\") { 1 } else { 5 };\n\n");
+    runtime.push_str("\tOk(SqlitePoolOptions::new().max_connections(connections).idle_timeout(None).max_lifetime(None).connect_with(options).await?)\n}\n");
+
+    runtime.push_str(&format!("\n{}", code.get_runtime_visibility()));
+    runtime.push_str("async fn save_changes(pool: &SqlitePool, changes: &Mutex<Vec<Change>>) -> Result<i32, Error> {\n\tlet changes = std::mem::take(&mut *changes.lock());\n\n");
+    runtime.push_str("\tlet mut transaction = pool.begin().await?;\n\n\tlet mut count = 0;\n\n\tfor elem in changes {\n");
+    runtime.push_str("\t\tcount += sqlx::query_with(&elem.sql, elem.arguments).execute(&mut *transaction).await?.rows_affected();\n\t}\n\n");
+    runtime.push_str("\ttransaction.commit().await?;\n\n\tOk(count as i32)\n}\n");
+
+    for (table, entity) in get_database_tables(code, types) {
+        runtime.push_str(&get_database_record_code(&table, &entity, types));
+    }
+
+    code.add_type(&runtime);
+}
+
+fn get_database_record_code(table: &migrations::Table, entity: &str, types: &TypeTable) -> String {
+
+    let properties = types.find_type(entity).map(|a| a.properties.clone()).unwrap_or_default();
+
+    let struct_name = get_simple_type_name(entity);
+
+    let mut columns: Vec<String> = Vec::new();
+
+    let mut fields = String::from("");
+
+    let mut arguments = String::from("");
+
+    let mut is_skipped = false;
+
+    let mut is_generated = false;
+
+    for elem in properties.iter().filter(|a| a.kind == Kind::Property || a.kind == Kind::Required) {
+        let Some(column) = table.columns.iter().find(|a| a.name == elem.name) else {
+            is_skipped = true;
+            continue;
+        };
+
+        let field = get_rust_name(&elem.name, NameKind::Field);
+
+        fields.push_str(&format!("\t\t\t{}: row.try_get(\"{}\")?,\n", field, elem.name));
+
+        if column.is_primary_key {
+            is_generated = column.is_generated;
+            continue;
+        }
+
+        let value = if is_copy_type(&elem.type_name, types) { format!("self.{}", field) } else { format!("self.{}.clone()", field) };
+
+        columns.push(format!("\"{}\"", elem.name));
+        arguments.push_str(&format!("\t\tadd_argument(&mut arguments, {})?;\n", value));
+    }
+
+    if is_skipped {
+        fields.push_str("\t\t\t..Default::default()\n");
+    }
+
+    let schema = migrations::get_ensure_created_sql(table);
+
+    let mut record_impl = RustImpl::new("", Some("Record"), struct_name);
+    record_impl.add_item(format!("\n\tconst TABLE: &'static str = \"{}\";\n\n\tconst COLUMNS: &'static [&'static str] = &[{}];\n\n\tconst IS_GENERATED: bool = {};\n",
+        table.name, columns.join(", "), is_generated));
+    record_impl.add_item(format!("\n\tconst SCHEMA: &'static str = {:?};\n", schema));
+    record_impl.add_item(format!("\n\tfn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {{\n\t\tOk({} {{\n{}\t\t}})\n\t}}\n", struct_name, fields));
+    record_impl.add_item(format!("\n\tfn arguments(&self) -> Result<SqliteArguments<'static>, Error> {{\n\t\tlet mut arguments = SqliteArguments::default();\n\n{}\n\t\tOk(arguments)\n\t}}\n",
+        arguments));
+
+    record_impl.to_code()
+}
+
+fn get_database_tables(code: &mut Code, types: &TypeTable) -> Vec<(migrations::Table, String)> {
+
+    let mut entities: Vec<(String, String)> = code.database_tables.iter()
+        .map(|a| (a.name.clone(), a.scope.resolve(&types.types, &a.entity, &types.diagnostics).unwrap_or_else(|| a.entity.clone()))).collect();
+
+    if code.database_contexts.is_empty() {
+        entities = types.keys.keys().map(|a| (String::from(get_simple_type_name(a)), a.clone())).collect();
+        entities.sort();
+    }
+
+    for (table_name, entity) in entities.iter_mut() {
+        if let Some(x) = types.find_configuration(entity).and_then(|a| a.table.clone()) {
+            *table_name = x;
+        }
+    }
+
+    let find_relationship = |entity: &str, principal: &str, is_principal: bool, name: &str| {
+        let own = types.find_configuration(entity).into_iter().flat_map(|a| a.relationships.iter())
+            .find(|a| a.is_principal == is_principal && if is_principal { a.collection.as_deref() == Some(name) } else { a.reference.as_deref() == Some(name) });
+
+        let inverse = types.find_configuration(principal).into_iter().flat_map(|a| a.relationships.iter())
+            .find(|a| a.is_principal != is_principal && if is_principal { a.collection.as_deref() == Some(name) } else { a.reference.as_deref() == Some(name) });
+
+        own.or(inverse).cloned().unwrap_or_default()
+    };
+
+    let find_table = |value: &str| entities.iter().find(|a| get_simple_type_name(&a.1) == get_simple_type_name(value.trim_end_matches('?'))).cloned();
+
+    let mut tables: Vec<(migrations::Table, String)> = Vec::new();
+
+    for (table_name, entity) in entities.iter() {
+        let Some(key) = types.find_key(entity) else {
+            code.add_warning(format!("{} has no key and is not mapped to the {} table", entity, table_name));
+            continue;
+        };
+
+        let properties: Vec<Type> = types.find_type(entity).map(|a| a.properties.iter().filter(|b| b.kind == Kind::Property || b.kind == Kind::Required).cloned().collect())
+            .unwrap_or_default();
+
+        let mut columns: Vec<migrations::Column> = Vec::new();
+
+        let mut navigations: Vec<(&Type, (String, String))> = Vec::new();
+
+        for elem in properties.iter() {
+            let constraint = types.find_constraint(entity, &elem.name).cloned().unwrap_or_default();
+
+            let type_name = elem.type_name.trim_end_matches('?');
+
+            if let Some(x) = find_table(type_name) {
+                navigations.push((elem, x));
+            }
+            else if get_database_column_type(type_name, types).is_some() {
+                let is_key = elem.name == key.property;
+
+                columns.push(migrations::Column { name: elem.name.clone(), type_name: get_database_type_name(type_name, types), is_nullable: elem.type_name.ends_with('?') && !constraint.is_required && !is_key,
+                    is_primary_key: is_key, is_generated: is_key && key.is_generated, max_length: constraint.max_length, references: None });
+            }
+            else if !(is_list_type(type_name) && find_table(&get_list_element_type(type_name)).is_some()) {
+                code.add_warning(format!("{}.{} has no column type and is not mapped to the {} table", get_simple_type_name(entity), elem.name, table_name));
+            }
+        }
+
+        for (elem, (principal, principal_entity)) in navigations {
+            let Some(principal_key) = types.find_key(&principal_entity) else {
+                continue;
+            };
+
+            let relationship = find_relationship(entity, &principal_entity, false, &elem.name);
+
+            let is_required = relationship.is_required || types.find_constraint(entity, &elem.name).is_some_and(|a| a.is_required);
+
+            let names = match relationship.foreign_key {
+                Some(x) => [x.clone(), x],
+                None => [format!("{}Id", elem.name), format!("{}{}", elem.name, principal_key.property)]
+            };
+
+            match columns.iter_mut().find(|a| names.contains(&a.name) && a.references.is_none()) {
+                Some(x) => {
+                    x.references = Some((principal, principal_key.property.clone()));
+                    x.is_nullable &= !is_required;
+                },
+                None => columns.push(get_foreign_key_column(&names[1], &principal_key.type_name, !is_required, &principal, principal_key))
+            }
+        }
+
+        let configuration = types.find_configuration(entity).cloned().unwrap_or_default();
+
+        let mut indexes: Vec<migrations::Index> = Vec::new();
+
+        for elem in configuration.indexes.iter() {
+            if let Some(x) = elem.properties.iter().find(|a| !columns.iter().any(|b| &&b.name == a)) {
+                code.add_warning(format!("{}: the index on {} has no column and is not created", get_simple_type_name(entity), x));
+                continue;
+            }
+
+            indexes.push(migrations::Index { name: elem.name.clone().unwrap_or_else(|| format!("IX_{}_{}", table_name, elem.properties.join("_"))), columns: elem.properties.clone(),
+                is_unique: elem.is_unique });
+        }
+
+        let mut rows: Vec<Vec<(String, String)>> = Vec::new();
+
+        for elem in configuration.seeds.iter() {
+            let row: Option<Vec<(String, String)>> = elem.iter()
+                .map(|(name, value)| columns.iter().find(|a| &a.name == name).and_then(|_| get_seed_literal(value, types)).map(|a| (name.clone(), a))).collect();
+
+            match row {
+                Some(x) => rows.push(x),
+                None => code.add_warning(format!("{}: seed data {{ {} }} is not translated", get_simple_type_name(entity),
+                    elem.iter().map(|a| format!("{} = {}", a.0, a.1)).collect::<Vec<String>>().join(", ")))
+            }
+        }
+
+        tables.push((migrations::Table { name: table_name.clone(), columns, indexes, rows }, entity.clone()));
+    }
+
+    for (table_name, entity) in entities.iter() {
+        let Some(key) = types.find_key(entity) else {
+            continue;
+        };
+
+        let properties = types.find_type(entity).map(|a| a.properties.clone()).unwrap_or_default();
+
+        for elem in properties.iter().filter(|a| is_list_type(&a.type_name)) {
+            let Some((dependent_name, dependent_entity)) = find_table(&get_list_element_type(&elem.type_name)) else {
+                continue;
+            };
+
+            let Some((dependent, _)) = tables.iter_mut().find(|a| a.0.name == dependent_name) else {
+                continue;
+            };
+
+            if dependent.columns.iter().any(|a| a.references.as_ref().is_some_and(|b| b.0 == *table_name)) {
+                continue;
+            }
+
+            let principal_name = get_simple_type_name(entity);
+
+            let relationship = find_relationship(entity, &dependent_entity, true, &elem.name);
+
+            let names = match relationship.foreign_key {
+                Some(x) => [x.clone(), x],
+                None => [format!("{}Id", principal_name), format!("{}{}", principal_name, key.property)]
+            };
+
+            match dependent.columns.iter_mut().find(|a| names.contains(&a.name)) {
+                Some(x) => {
+                    x.references = Some((table_name.clone(), key.property.clone()));
+                    x.is_nullable &= !relationship.is_required;
+                },
+                None => dependent.columns.push(get_foreign_key_column(&names[1], &key.type_name, !relationship.is_required, table_name, key))
+            }
+        }
+    }
+
+    tables
+}
+
+fn get_seed_literal(value: &str, types: &TypeTable) -> Option<String> {
+
+    let number = value.trim_end_matches(['m', 'M', 'f', 'F', 'd', 'D', 'L']);
+
+    match value {
+        "true" => Some(String::from("TRUE")),
+        "false" => Some(String::from("FALSE")),
+        "null" => Some(String::from("NULL")),
+        x if x.len() >= 2 && x.starts_with('"') && x.ends_with('"') && !x[1..x.len() - 1].contains('\\') => Some(format!("'{}'", x[1..x.len() - 1].replace('\'', "''"))),
+        _ if number.parse::<f64>().is_ok() => Some(String::from(number)),
+        x => {
+            let (enum_name, member) = x.rsplit_once('.')?;
+
+            types.find_type(enum_name).filter(|a| a.kind == Kind::Enum)?.properties.iter().find(|a| a.name == member).map(|a| a.type_name.clone())
+        }
+    }
+}
+
+fn get_foreign_key_column(name: &str, type_name: &str, is_nullable: bool, principal: &str, principal_key: &EntityKey) -> migrations::Column {
+    migrations::Column { name: String::from(name), type_name: String::from(type_name), is_nullable, is_primary_key: false, is_generated: false, max_length: None,
+        references: Some((String::from(principal), principal_key.property.clone())) }
+}
+
+fn get_database_type_name(value: &str, types: &TypeTable) -> String {
+    if types.is_enum_type(value) { String::from("int") } else { String::from(value) }
+}
+
+fn get_database_column_type(value: &str, types: &TypeTable) -> Option<&'static str> {
+
+    match value.trim_end_matches('?') {
+        "int" | "long" | "short" | "byte" | "bool" => Some("INTEGER"),
+        "decimal" | "double" | "float" => Some("REAL"),
+        "string" => Some("TEXT"),
+        x if types.is_enum_type(x) => Some("INTEGER"),
+        _ => None
+    }
+}
+
+const RUNTIME_MODULE: &str = "csrust_rt";
+
+const RUNTIME_NAMES: [&str; 11] = ["Entity", "EntityStore", "IndexedStore", "JsonStore", "SqliteStore", "Error", "Record", "DbSet", "Change", "Validate", "ValidationErrors"];
+
+const DUPLICATE_KEY_MESSAGE: &str = "The instance of entity type '{}' cannot be tracked because another instance with the same key value for {{'{}'}} is already being tracked.";
+
+fn add_entity_store_code(code: &mut Code, types: &TypeTable) {
+
+    if types.keys.is_empty() {
+        return;
+    }
+
+    code.add_error(String::from("DuplicateKey"));
+
+    let mut store = String::from("\npub trait Entity {\n\ttype Key: PartialEq;\n\n\tconst NAME: &'static str;\n\n\tconst KEY_NAME: &'static str;\n\n\tfn key(&self) -> Self::Key;\n}\n");
+
+    store.push_str("\npub trait EntityStore<T: Entity> {\n\tfn find(&self, key: &T::Key) -> Result<Option<T>, Error>;\n\n\tfn insert(&self, entity: T) -> Result<(), Error>;\n\n");
+    store.push_str("\tfn update(&self, entity: T) -> Result<bool, Error>;\n\n\tfn remove(&self, key: &T::Key) -> Result<bool, Error>;\n");
+
+    if code.storage != Storage::Memory {
+        store.push_str("\n\tfn to_list(&self) -> Result<Vec<T>, Error>;\n\n\tfn retain<F: FnMut(&T) -> bool>(&self, predicate: F) -> Result<(), Error>;\n\n\tfn clear(&self) -> Result<(), Error>;\n");
+    }
+    store.push_str("}\n");
+
+    store.push_str("\nimpl<T: Entity + Clone> EntityStore<T> for RwLock<Vec<T>> {");
+    store.push_str("\n\tfn find(&self, key: &T::Key) -> Result<Option<T>, Error> {\n\t\tOk(self.read().iter().find(|a| a.key() == *key).cloned())\n\t}\n");
+    store.push_str("\n\tfn insert(&self, entity: T) -> Result<(), Error> {\n\t\tlet mut entities = self.write();\n\n");
+    store.push_str("\t\tif entities.iter().any(|a| a.key() == entity.key()) {\n");
+    store.push_str(&format!("\t\t\treturn Err(Error::DuplicateKey(format!(\"{}\", T::NAME, T::KEY_NAME)));\n", DUPLICATE_KEY_MESSAGE));
+    store.push_str("\t\t}\n\n\t\tentities.push(entity);\n\n\t\tOk(())\n\t}\n");
+    store.push_str("\n\tfn update(&self, entity: T) -> Result<bool, Error> {\n\t\tlet mut entities = self.write();\n\n");
+    store.push_str("\t\tmatch entities.iter_mut().find(|a| a.key() == entity.key()) {\n\t\t\tSome(x) => {\n\t\t\t\t*x = entity;\n\t\t\t\tOk(true)\n\t\t\t},\n\t\t\tNone => Ok(false)\n\t\t}\n\t}\n");
+    store.push_str("\n\tfn remove(&self, key: &T::Key) -> Result<bool, Error> {\n\t\tlet mut entities = self.write();\n\n\t\tlet count = entities.len();\n\n");
+    store.push_str("\t\tentities.retain(|a| a.key() != *key);\n\n\t\tOk(entities.len() != count)\n\t}\n");
+
+    if code.storage != Storage::Memory {
+        store.push_str("\n\tfn to_list(&self) -> Result<Vec<T>, Error> {\n\t\tOk(self.read().clone())\n\t}\n");
+        store.push_str("\n\tfn retain<F: FnMut(&T) -> bool>(&self, predicate: F) -> Result<(), Error> {\n\t\tself.write().retain(predicate);\n\n\t\tOk(())\n\t}\n");
+        store.push_str("\n\tfn clear(&self) -> Result<(), Error> {\n\t\tself.write().clear();\n\n\t\tOk(())\n\t}\n");
+    }
+    store.push_str("}\n");
+
+    code.add_type(&store);
+}
+
+fn add_storage_code(code: &mut Code, types: &TypeTable) {
+
+    let Some(store_type) = code.storage.get_store_type().filter(|_| !types.keys.is_empty() && !code.store_properties.is_empty()) else {
+        return;
+    };
+
+    let mut store = String::from("");
+
+    match code.storage {
+        Storage::Indexed => {
+            code.add_using(String::from("use std::collections::HashMap;\n"));
+            code.add_using(String::from("use std::hash::Hash;\n"));
+
+            store.push_str("\npub struct IndexedStore<T: Entity> {\n\tentities: RwLock<HashMap<T::Key, T>>\n}\n");
+            store.push_str("\nimpl<T: Entity> From<Vec<T>> for IndexedStore<T> where T::Key: Hash + Eq {\n\tfn from(entities: Vec<T>) -> Self {");
+            store.push_str("\n\t\tIndexedStore { entities: RwLock::new(entities.into_iter().map(|a| (a.key(), a)).collect()) }\n\t}\n}\n");
+            store.push_str("\nimpl<T: Entity + Clone> EntityStore<T> for IndexedStore<T> where T::Key: Hash + Ord {");
+            store.push_str("\n\tfn find(&self, key: &T::Key) -> Result<Option<T>, Error> {\n\t\tOk(self.entities.read().get(key).cloned())\n\t}\n");
+            store.push_str("\n\tfn insert(&self, entity: T) -> Result<(), Error> {\n\t\tlet mut entities = self.entities.write();\n\n");
+            store.push_str("\t\tif entities.contains_key(&entity.key()) {\n");
+            store.push_str(&format!("\t\t\treturn Err(Error::DuplicateKey(format!(\"{}\", T::NAME, T::KEY_NAME)));\n", DUPLICATE_KEY_MESSAGE));
+            store.push_str("\t\t}\n\n\t\tentities.insert(entity.key(), entity);\n\n\t\tOk(())\n\t}\n");
+            store.push_str("\n\tfn update(&self, entity: T) -> Result<bool, Error> {\n\t\tmatch self.entities.write().get_mut(&entity.key()) {");
+            store.push_str("\n\t\t\tSome(x) => {\n\t\t\t\t*x = entity;\n\t\t\t\tOk(true)\n\t\t\t},\n\t\t\tNone => Ok(false)\n\t\t}\n\t}\n");
+            store.push_str("\n\tfn remove(&self, key: &T::Key) -> Result<bool, Error> {\n\t\tOk(self.entities.write().remove(key).is_some())\n\t}\n");
+            store.push_str("\n\tfn to_list(&self) -> Result<Vec<T>, Error> {\n\t\tlet mut entities: Vec<T> = self.entities.read().values().cloned().collect();\n\n");
+            store.push_str("\t\tentities.sort_by_key(|a| a.key());\n\n\t\tOk(entities)\n\t}\n");
+            store.push_str("\n\tfn retain<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Result<(), Error> {\n\t\tself.entities.write().retain(|_, a| predicate(a));\n\n\t\tOk(())\n\t}\n");
+            store.push_str("\n\tfn clear(&self) -> Result<(), Error> {\n\t\tself.entities.write().clear();\n\n\t\tOk(())\n\t}\n}\n");
+        },
+        Storage::Json => {
+            code.add_error(String::from("Storage"));
+            code.add_using(String::from("use parking_lot::Mutex;\n"));
+            code.add_using(String::from("use serde::de::DeserializeOwned;\n"));
+            code.add_using(String::from("use std::fs;\n"));
+            code.add_using(String::from("use std::path::{Path, PathBuf};\n"));
+
+            store.push_str("\nimpl From<std::io::Error> for Error {\n\tfn from(error: std::io::Error) -> Self {\n\t\tError::Storage(error.to_string())\n\t}\n}\n");
+            store.push_str("\nimpl From<serde_json::Error> for Error {\n\tfn from(error: serde_json::Error) -> Self {\n\t\tError::Storage(error.to_string())\n\t}\n}\n");
+            store.push_str("\npub struct JsonStore<T> {\n\tpath: PathBuf,\n\tfile: Mutex<()>,\n\tentities: RwLock<Vec<T>>\n}\n");
+            store.push_str("\nimpl<T: Entity + DeserializeOwned> From<Vec<T>> for JsonStore<T> {\n\tfn from(entities: Vec<T>) -> Self {");
+            store.push_str("\n\t\tlet path = Path::new(&std::env::var(\"DATA_DIR\").unwrap_or_else(|_| String::from(\"data\"))).join(format!(\"{}.json\", T::NAME));\n\n");
+            store.push_str("\t\tlet entities = match fs::read_to_string(&path) {\n");
+            store.push_str("\t\t\tOk(x) => serde_json::from_str(&x).unwrap_or_else(|e| panic!(\"{} is not a valid {} snapshot: {}\", path.display(), T::NAME, e)),\n");
+            store.push_str("\t\t\tErr(_) => entities\n\t\t};\n\n\t\tJsonStore { path, file: Mutex::new(()), entities: RwLock::new(entities) }\n\t}\n}\n");
+            store.push_str("\nimpl<T: Serialize> JsonStore<T> {\n\tfn flush(&self) -> Result<(), Error> {\n\t\tlet _file = self.file.lock();\n\n");
+            store.push_str("\t\tlet json = serde_json::to_string_pretty(&*self.entities.read())?;\n\n");
+            store.push_str("\t\tif let Some(x) = self.path.parent() {\n\t\t\tfs::create_dir_all(x)?;\n\t\t}\n\n");
+            store.push_str("\t\tlet temporary = self.path.with_extension(\"json.tmp\");\n\n\t\tfs::write(&temporary, json)?;\n\n");
+            store.push_str("\t\tOk(fs::rename(&temporary, &self.path)?)\n\t}\n");
+            store.push_str("\n\tfn save(&self, is_changed: bool) -> Result<bool, Error> {\n\t\tif is_changed {\n\t\t\tself.flush()?;\n\t\t}\n\n\t\tOk(is_changed)\n\t}\n}\n");
+            store.push_str("\nimpl<T: Entity + Clone + Serialize> EntityStore<T> for JsonStore<T> {");
+            store.push_str("\n\tfn find(&self, key: &T::Key) -> Result<Option<T>, Error> {\n\t\tself.entities.find(key)\n\t}\n");
+            store.push_str("\n\tfn insert(&self, entity: T) -> Result<(), Error> {\n\t\tself.entities.insert(entity)?;\n\n\t\tself.flush()\n\t}\n");
+            store.push_str("\n\tfn update(&self, entity: T) -> Result<bool, Error> {\n\t\tself.save(self.entities.update(entity)?)\n\t}\n");
+            store.push_str("\n\tfn remove(&self, key: &T::Key) -> Result<bool, Error> {\n\t\tself.save(self.entities.remove(key)?)\n\t}\n");
+            store.push_str("\n\tfn to_list(&self) -> Result<Vec<T>, Error> {\n\t\tself.entities.to_list()\n\t}\n");
+            store.push_str("\n\tfn retain<F: FnMut(&T) -> bool>(&self, predicate: F) -> Result<(), Error> {\n\t\tself.entities.retain(predicate)?;\n\n\t\tself.flush()\n\t}\n");
+            store.push_str("\n\tfn clear(&self) -> Result<(), Error> {\n\t\tself.entities.clear()?;\n\n\t\tself.flush()\n\t}\n}\n");
+        },
+        _ => {
+            let bounds = "T::Key: Default + Clone + sqlx::Encode<'static, Sqlite> + sqlx::Type<Sqlite> + Send + 'static";
+
+            store.push_str(&format!("\n{}", code.get_runtime_visibility()));
+            store.push_str("fn block_on<F: std::future::Future>(future: F) -> F::Output {\n\ttokio::task::block_in_place(|| tokio::runtime::Handle::current().block_on(future))\n}\n");
+            store.push_str("\npub struct SqliteStore<T> {\n\tpool: SqlitePool,\n\tmarker: PhantomData<T>\n}\n");
+            store.push_str(&format!("\nimpl<T: Record> SqliteStore<T> where {} {{", bounds));
+            store.push_str("\n\tasync fn open(url: &str, entities: Vec<T>) -> Result<Self, Error> {\n\t\tlet pool = connect_database(url).await?;\n\n");
+            store.push_str("\t\tsqlx::query(T::SCHEMA).execute(&pool).await?;\n\n\t\tlet store = SqliteStore { pool, marker: PhantomData };\n\n\t\tlet set = store.set();\n\n");
+            store.push_str("\t\tif !entities.is_empty() && set.to_list().await?.is_empty() {\n\t\t\tfor elem in entities {\n\t\t\t\tstore.add(elem).await?;\n\t\t\t}\n\t\t}\n\n\t\tOk(store)\n\t}\n");
+            store.push_str("\n\tasync fn add(&self, entity: T) -> Result<(), Error> {\n\t\tlet mut arguments = entity.arguments()?;\n\n\t\tadd_argument(&mut arguments, entity.key())?;\n\n");
+            store.push_str("\t\tlet mut columns = T::COLUMNS.to_vec();\n\n\t\tcolumns.push(T::KEY_NAME);\n\n");
+            store.push_str("\t\tlet sql = format!(\"INSERT INTO {} ({}) VALUES ({})\", T::TABLE, columns.join(\", \"), vec![\"?\"; columns.len()].join(\", \"));\n\n");
+            store.push_str("\t\tmatch sqlx::query_with(&sql, arguments).execute(&self.pool).await {\n\t\t\tOk(_) => Ok(()),\n");
+            store.push_str(&format!("\t\t\tErr(sqlx::Error::Database(e)) if e.is_unique_violation() => Err(Error::DuplicateKey(format!(\"{}\", T::NAME, T::KEY_NAME))),\n", DUPLICATE_KEY_MESSAGE));
+            store.push_str("\t\t\tErr(e) => Err(Error::from(e))\n\t\t}\n\t}\n");
+            store.push_str("\n\tfn set(&self) -> DbSet<T> {\n\t\tDbSet::new(self.pool.clone(), Arc::new(Mutex::new(Vec::new())))\n\t}\n}\n");
+            store.push_str(&format!("\nimpl<T: Record> From<Vec<T>> for SqliteStore<T> where {} {{\n\tfn from(entities: Vec<T>) -> Self {{", bounds));
+            store.push_str("\n\t\tlet url = std::env::var(\"DATABASE_URL\").unwrap_or_else(|_| String::from(\"sqlite:store.db\"));\n\n");
+            store.push_str("\t\tblock_on(Self::open(&url, entities)).unwrap_or_else(|e| panic!(\"{}: {}\", url, e))\n\t}\n}\n");
+            store.push_str(&format!("\nimpl<T: Record + Clone> EntityStore<T> for SqliteStore<T> where {} {{", bounds));
+            store.push_str("\n\tfn find(&self, key: &T::Key) -> Result<Option<T>, Error> {\n\t\tblock_on(self.set().find(key.clone()))\n\t}\n");
+            store.push_str("\n\tfn insert(&self, entity: T) -> Result<(), Error> {\n\t\tblock_on(self.add(entity))\n\t}\n");
+            store.push_str("\n\tfn update(&self, entity: T) -> Result<bool, Error> {\n\t\tlet set = self.set();\n\n\t\tset.update(entity)?;\n\n");
+            store.push_str("\t\tOk(block_on(save_changes(&set.pool, &set.changes))? > 0)\n\t}\n");
+            store.push_str("\n\tfn remove(&self, key: &T::Key) -> Result<bool, Error> {\n\t\tOk(block_on(self.set().execute_delete(T::KEY_NAME, \"=\", key.clone()))? > 0)\n\t}\n");
+            store.push_str("\n\tfn to_list(&self) -> Result<Vec<T>, Error> {\n\t\tblock_on(self.set().to_list())\n\t}\n");
+            store.push_str("\n\tfn retain<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Result<(), Error> {\n\t\tfor elem in self.to_list()?.into_iter().filter(|a| !predicate(a)) {");
+            store.push_str("\n\t\t\tself.remove(&elem.key())?;\n\t\t}\n\n\t\tOk(())\n\t}\n");
+            store.push_str("\n\tfn clear(&self) -> Result<(), Error> {\n\t\tlet sql = format!(\"DELETE FROM {}\", T::TABLE);\n\n");
+            store.push_str("\t\tblock_on(sqlx::query(&sql).execute(&self.pool))?;\n\n\t\tOk(())\n\t}\n}\n");
+        }
+    }
+
+    store.push_str(&format!("\nimpl<T: Entity> Default for {0}<T> where Self: From<Vec<T>> {{\n\tfn default() -> Self {{\n\t\tSelf::from(Vec::new())\n\t}}\n}}\n", store_type));
+    store.push_str(&format!("\nimpl<T: Entity + std::fmt::Debug> std::fmt::Debug for {0}<T> where Self: EntityStore<T> {{", store_type));
+    store.push_str("\n\tfn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {\n\t\tmatch self.to_list() {\n\t\t\tOk(x) => f.debug_list().entries(x).finish(),\n\t\t\tErr(e) => write!(f, \"{}\", e)\n\t\t}\n\t}\n}\n");
+    store.push_str(&format!("\nimpl<T: Entity + Serialize> Serialize for {0}<T> where Self: EntityStore<T> {{", store_type));
+    store.push_str("\n\tfn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {\n\t\tself.to_list().map_err(serde::ser::Error::custom)?.serialize(serializer)\n\t}\n}\n");
+    store.push_str(&format!("\nimpl<'de, T: Entity + Deserialize<'de>> Deserialize<'de> for {0}<T> where Self: From<Vec<T>> {{", store_type));
+    store.push_str("\n\tfn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {\n\t\tVec::<T>::deserialize(deserializer).map(Self::from)\n\t}\n}\n");
+
+    code.add_type(&store);
+}
+
+fn add_validation_code(code: &mut Code) {
+
+    if code.validated_types.is_empty() {
+        return;
+    }
+
+    let mut validation = String::from("\npub trait Validate {\n\tfn validate(&self) -> BTreeMap<String, Vec<String>>;\n}\n");
+
+    validation.push_str("\n#[derive(Debug)]\npub struct ValidationErrors(pub BTreeMap<String, Vec<String>>);\n\nimpl warp::reject::Reject for ValidationErrors {}\n");
+    validation.push_str(&format!("\n{}", code.get_runtime_visibility()));
+    validation.push_str("async fn validate_request<T: Validate>(value: T) -> Result<T, warp::Rejection> {\n\tlet errors = value.validate();\n\n");
+    validation.push_str("\tif errors.is_empty() {\n\t\tOk(value)\n\t} else {\n\t\tErr(warp::reject::custom(ValidationErrors(errors)))\n\t}\n}\n");
+
+    code.add_type(&validation);
+}
+
+fn add_rejection_handler_code(code: &mut Code) {
+
+    let mut handler = RustFunction::new("async fn handle_rejection(err: warp::Rejection) -> Result<impl warp::Reply, std::convert::Infallible>");
+
+    if !code.validated_types.is_empty() {
+        let mut validation = format!("\n\tif let Some(errors) = err.find::<{}>() {{", code.get_runtime_name("ValidationErrors"));
+        validation.push_str("\n\t\tlet problem = serde_json::json!({");
+        validation.push_str("\n\t\t\t\"type\": \"https://tools.ietf.org/html/rfc9110#section-15.5.1\",");
+        validation.push_str("\n\t\t\t\"title\": \"One or more validation errors occurred.\",");
+        validation.push_str("\n\t\t\t\"status\": 400,");
+        validation.push_str("\n\t\t\t\"errors\": errors.0,");
+        validation.push_str("\n\t\t});");
+        validation.push_str("\n\n\t\treturn Ok(warp::reply::with_status(warp::reply::json(&problem), http::StatusCode::BAD_REQUEST));");
+        validation.push_str("\n\t}\n");
+        handler.add_statement(validation);
+    }
+
+    let mut binding = String::from("\n\tlet binding_error = if let Some(error) = err.find::<warp::filters::body::BodyDeserializeError>() {");
+    binding.push_str("\n\t\tSome((\"$\", error.to_string()))");
+    binding.push_str("\n\t} else if let Some(error) = err.find::<warp::reject::InvalidQuery>() {");
+    binding.push_str("\n\t\tSome((\"query\", error.to_string()))");
+    binding.push_str("\n\t} else {");
+    binding.push_str("\n\t\terr.find::<warp::reject::MissingHeader>().map(|error| (error.name(), error.to_string()))");
+    binding.push_str("\n\t};");
+    handler.add_statement(binding);
+
+    let mut binding_reply = String::from("\n\n\tif let Some((key, message)) = binding_error {");
+    binding_reply.push_str("\n\t\tlet problem = serde_json::json!({");
+    binding_reply.push_str("\n\t\t\t\"type\": \"https://tools.ietf.org/html/rfc9110#section-15.5.1\",");
+    binding_reply.push_str("\n\t\t\t\"title\": \"One or more validation errors occurred.\",");
+    binding_reply.push_str("\n\t\t\t\"status\": 400,");
+    binding_reply.push_str("\n\t\t\t\"errors\": { key: [message] },");
+    binding_reply.push_str("\n\t\t});");
+    binding_reply.push_str("\n\n\t\treturn Ok(warp::reply::with_status(warp::reply::json(&problem), http::StatusCode::BAD_REQUEST));");
+    binding_reply.push_str("\n\t}\n");
+    handler.add_statement(binding_reply);
+
+    let mut status = format!("\n\tlet (status, section, title, detail) = if let Some(error) = err.find::<{}>() {{", code.get_runtime_name("Error"));
+    status.push_str("\n\t\t(http::StatusCode::INTERNAL_SERVER_ERROR, \"15.6.1\", \"An error occurred while processing your request.\", error.to_string())");
+    status.push_str("\n\t} else if err.is_not_found() {");
+    status.push_str("\n\t\t(http::StatusCode::NOT_FOUND, \"15.5.5\", \"Not Found\", String::new())");
+    status.push_str("\n\t} else if err.find::<warp::reject::MethodNotAllowed>().is_some() {");
+    status.push_str("\n\t\t(http::StatusCode::METHOD_NOT_ALLOWED, \"15.5.6\", \"Method Not Allowed\", String::new())");
+    status.push_str("\n\t} else {");
+    status.push_str("\n\t\t(http::StatusCode::INTERNAL_SERVER_ERROR, \"15.6.1\", \"An error occurred while processing your request.\", String::new())");
+    status.push_str("\n\t};");
+    handler.add_statement(status);
+
+    let mut problem = String::from("\n\n\tlet problem = serde_json::json!({");
+    problem.push_str("\n\t\t\"type\": format!(\"https://tools.ietf.org/html/rfc9110#section-{}\", section),");
+    problem.push_str("\n\t\t\"title\": title,");
+    problem.push_str("\n\t\t\"status\": status.as_u16(),");
+    problem.push_str("\n\t\t\"detail\": detail,");
+    problem.push_str("\n\t});");
+    handler.add_statement(problem);
+
+    handler.add_statement(String::from("\n\n\tOk(warp::reply::with_status(warp::reply::json(&problem), status))"));
+
+    match handler.to_code() {
+        Ok(x) => code.add_method(x),
+        Err(e) => code.add_failure(e)
+    }
+}
+
+fn get_rust_type_default_value(value: String) -> String {
+
+    if value.to_lowercase() == "string" {
+        return String::from("String::from(\"\")")
+    }
+    else if value == "int" {
+        return String::from("0")
+    }
+    else if value == "decimal" {
+        return String::from("0.0")
+    }
+    else if value == "bool" {
+        return String::from("false")
+    }
+    else if is_list_type(&value) {
+        return String::from("Arc::new(RwLock::new(Vec::new()))")
+    }
+    else if is_dictionary_type(&value) {
+        return String::from("HashMap::new()")
+    }
+    else if is_set_type(&value) {
+        return String::from("HashSet::new()")
+    }
+
+    String::from("Default::default()")
+}
+
+fn is_list_type(value: &str) -> bool {
+    value.contains('<') && matches!(get_simple_type_name(get_generic_type_name(value)),
+        "List" | "IEnumerable" | "IList" | "ICollection" | "IReadOnlyList" | "IReadOnlyCollection")
+}
+
+fn is_dictionary_type(value: &str) -> bool {
+    value.contains('<') && matches!(get_simple_type_name(get_generic_type_name(value)), "Dictionary" | "IDictionary" | "IReadOnlyDictionary")
+}
+
+fn is_set_type(value: &str) -> bool {
+    value.contains('<') && matches!(get_simple_type_name(get_generic_type_name(value)), "HashSet" | "ISet" | "IReadOnlySet" | "SortedSet")
+}
+
+fn get_output(code: Code, types: &TypeTable, class_methods: &ClassMethods) -> Result<Output, Diagnostics> {
+
+    let runtime: String = if code.runtime_collisions.is_empty() {
+        code.types.concat()
+    }
+    else {
+        let runtime = code.types.concat();
+
+        let traits: String = code.runtime_collisions.iter().filter(|a| runtime.match_indices(&format!("pub trait {}", a)).any(|(b, c)| !runtime[b + c.len()..].starts_with(|d: char| d.is_alphanumeric() || d == '_')))
+            .map(|a| format!("#[allow(unused_imports)]\nuse {}::{} as _;\n", RUNTIME_MODULE, a)).collect();
+
+        format!("\npub mod {0} {{\n\tuse super::*;\n{1}}}\n\nuse {0}::*;\n{2}", RUNTIME_MODULE, runtime, traits)
+    };
+
+    let output: String = code.usings.iter().chain(std::iter::once(&runtime)).chain(code.structs.iter()).chain(code.methods.iter()).map(|a| a.as_str()).collect();
+
+    let mut formatted = match emitter::format_file(&output) {
+        Ok(x) => x,
+        Err(e) => return Err(Diagnostics { errors: vec![e], warnings: code.warnings })
+    };
+
+    if code.is_source_comments {
+        formatted = source_map::add_source_comments(&formatted, &source_map::get_mappings(&output, &formatted));
+    }
+
+    let source_map = if code.is_source_map { source_map::get_mappings(&output, &formatted) } else { Vec::new() };
+
+    let mut class_names: Vec<&String> = class_methods.class_methods.keys().collect();
+
+    class_names.sort();
+
+    Ok(Output {
+        code: formatted,
+        source_map,
+        types: types.get_sorted_types().into_iter().cloned().collect(),
+        classes: class_names.into_iter().map(|a| class_methods.class_methods[a].clone()).collect(),
+        warnings: code.warnings
+    })
+}
@@ -0,0 +1,630 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Schema {
+    pub tables: Vec<Table>,
+    pub warnings: Vec<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Table {
+    pub name: String,
+    pub columns: Vec<Column>,
+    pub indexes: Vec<Index>,
+    pub rows: Vec<Vec<(String, String)>>
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Column {
+    pub name: String,
+    pub type_name: String,
+    pub is_nullable: bool,
+    pub is_primary_key: bool,
+    pub is_generated: bool,
+    pub max_length: Option<usize>,
+    pub references: Option<(String, String)>
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Index {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub is_unique: bool
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    columns: Vec<ColumnEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    indexes: Vec<IndexEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rows: Vec<RowEntry>
+}
+
+#[derive(Serialize, Deserialize)]
+struct ColumnEntry {
+    table: String,
+    column: String,
+    #[serde(rename = "type")]
+    type_name: String,
+    nullable: bool,
+    primary_key: bool,
+    generated: bool,
+    max_length: Option<usize>,
+    references: Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+struct IndexEntry {
+    table: String,
+    index: String,
+    columns: String,
+    unique: bool
+}
+
+#[derive(Serialize, Deserialize)]
+struct RowEntry {
+    table: String,
+    row: usize,
+    column: String,
+    value: String
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dialect {
+    Sqlite,
+    Postgres
+}
+
+impl Dialect {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "sqlite" => Some(Dialect::Sqlite),
+            "postgres" | "postgresql" => Some(Dialect::Postgres),
+            _ => None
+        }
+    }
+}
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name)
+}
+
+fn get_sql_type(column: &Column, dialect: Dialect) -> String {
+
+    match (column.type_name.as_str(), dialect) {
+        ("int" | "short" | "byte" | "long" | "bool", Dialect::Sqlite) => String::from("INTEGER"),
+        ("decimal" | "double" | "float", Dialect::Sqlite) => String::from("REAL"),
+        ("int", Dialect::Postgres) => String::from("INTEGER"),
+        ("short" | "byte", Dialect::Postgres) => String::from("SMALLINT"),
+        ("long", Dialect::Postgres) => String::from("BIGINT"),
+        ("bool", Dialect::Postgres) => String::from("BOOLEAN"),
+        ("decimal" | "double", Dialect::Postgres) => String::from("DOUBLE PRECISION"),
+        ("float", Dialect::Postgres) => String::from("REAL"),
+        ("string", Dialect::Postgres) if column.max_length.is_some() => format!("VARCHAR({})", column.max_length.unwrap_or_default()),
+        _ => String::from("TEXT")
+    }
+}
+
+fn get_default_value(column: &Column, dialect: Dialect) -> &'static str {
+
+    match (column.type_name.as_str(), dialect) {
+        ("string", _) => "''",
+        ("bool", Dialect::Postgres) => "FALSE",
+        ("decimal" | "double" | "float", _) => "0.0",
+        _ => "0"
+    }
+}
+
+fn get_sql_literal(value: &str, dialect: Dialect) -> &str {
+
+    match (value, dialect) {
+        ("TRUE", Dialect::Sqlite) => "1",
+        ("FALSE", Dialect::Sqlite) => "0",
+        _ => value
+    }
+}
+
+fn get_column_definition(column: &Column, dialect: Dialect) -> String {
+
+    let mut definition = format!("{} {}", quote(&column.name), get_sql_type(column, dialect));
+
+    match dialect {
+        Dialect::Sqlite if column.is_primary_key && column.is_generated => definition.push_str(" PRIMARY KEY AUTOINCREMENT"),
+        Dialect::Sqlite if column.is_primary_key => definition.push_str(" NOT NULL PRIMARY KEY"),
+        Dialect::Postgres if column.is_primary_key && column.is_generated => definition.push_str(" GENERATED BY DEFAULT AS IDENTITY"),
+        _ if !column.is_nullable => definition.push_str(" NOT NULL"),
+        _ => {}
+    }
+
+    if let (Dialect::Sqlite, Some(x)) = (dialect, column.max_length) {
+        definition.push_str(&format!(" CHECK (length({}) <= {})", quote(&column.name), x));
+    }
+
+    definition
+}
+
+fn get_primary_key_name(table: &Table) -> String {
+    format!("PK_{}", table.name)
+}
+
+fn get_foreign_key_name(table: &Table, column: &Column) -> Option<String> {
+    column.references.as_ref().map(|a| format!("FK_{}_{}_{}", table.name, a.0, column.name))
+}
+
+fn get_foreign_key_definition(table: &Table, column: &Column) -> Option<String> {
+
+    let (principal, key) = column.references.as_ref()?;
+
+    let on_delete = if column.is_nullable { "" } else { " ON DELETE CASCADE" };
+
+    Some(format!("CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({}){}", quote(&get_foreign_key_name(table, column)?), quote(&column.name),
+        quote(principal), quote(key), on_delete))
+}
+
+pub fn get_create_table_sql(table: &Table, dialect: Dialect) -> String {
+
+    let mut definitions: Vec<String> = table.columns.iter().map(|a| get_column_definition(a, dialect)).collect();
+
+    let keys: Vec<String> = table.columns.iter().filter(|a| a.is_primary_key).map(|a| quote(&a.name)).collect();
+
+    if dialect == Dialect::Postgres && !keys.is_empty() {
+        definitions.push(format!("CONSTRAINT {} PRIMARY KEY ({})", quote(&get_primary_key_name(table)), keys.join(", ")));
+    }
+
+    definitions.extend(table.columns.iter().filter_map(|a| get_foreign_key_definition(table, a)));
+
+    format!("CREATE TABLE {} ({})", quote(&table.name), definitions.join(", "))
+}
+
+fn get_create_index_sql(table: &Table, index: &Index) -> String {
+
+    let unique = if index.is_unique { "UNIQUE " } else { "" };
+
+    let columns: Vec<String> = index.columns.iter().map(|a| quote(a)).collect();
+
+    format!("CREATE {}INDEX {} ON {} ({})", unique, quote(&index.name), quote(&table.name), columns.join(", "))
+}
+
+fn get_insert_sql(table: &Table, row: &[(String, String)], dialect: Dialect) -> String {
+
+    let columns: Vec<String> = row.iter().map(|a| quote(&a.0)).collect();
+
+    let values: Vec<&str> = row.iter().map(|a| get_sql_literal(&a.1, dialect)).collect();
+
+    format!("INSERT INTO {} ({}) VALUES ({})", quote(&table.name), columns.join(", "), values.join(", "))
+}
+
+fn get_key_condition(table: &Table, row: &[(String, String)], dialect: Dialect) -> Option<String> {
+
+    let conditions: Vec<String> = table.columns.iter().filter(|a| a.is_primary_key)
+        .map(|a| row.iter().find(|b| b.0 == a.name).map(|b| format!("{} = {}", quote(&b.0), get_sql_literal(&b.1, dialect))))
+        .collect::<Option<Vec<String>>>()?;
+
+    if conditions.is_empty() { None } else { Some(conditions.join(" AND ")) }
+}
+
+pub fn get_ensure_created_sql(table: &Table) -> String {
+
+    let mut statements = vec![get_create_table_sql(table, Dialect::Sqlite).replacen("CREATE TABLE", "CREATE TABLE IF NOT EXISTS", 1)];
+
+    statements.extend(table.indexes.iter().map(|a| get_create_index_sql(table, a).replacen("INDEX", "INDEX IF NOT EXISTS", 1)));
+    statements.extend(table.rows.iter().map(|a| get_insert_sql(table, a, Dialect::Sqlite).replacen("INSERT", "INSERT OR IGNORE", 1)));
+
+    statements.join("; ")
+}
+
+fn get_ordered_tables(tables: &[Table]) -> Vec<&Table> {
+
+    let mut ordered: Vec<&Table> = Vec::new();
+
+    let mut pending: Vec<&Table> = tables.iter().collect();
+
+    while !pending.is_empty() {
+        let index = pending.iter().position(|a| a.columns.iter().filter_map(|b| b.references.as_ref())
+            .all(|b| b.0 == a.name || !pending.iter().any(|c| c.name == b.0))).unwrap_or(0);
+
+        ordered.push(pending.remove(index));
+    }
+
+    ordered
+}
+
+pub fn get_create_sql(tables: &[Table], dialect: Dialect) -> String {
+
+    let mut statements: Vec<String> = Vec::new();
+
+    for elem in get_ordered_tables(tables) {
+        statements.push(get_create_table_sql(elem, dialect));
+        statements.extend(elem.rows.iter().map(|a| get_insert_sql(elem, a, dialect)));
+        statements.extend(elem.indexes.iter().map(|a| get_create_index_sql(elem, a)));
+    }
+
+    statements.iter().map(|a| format!("{};\n\n", a)).collect()
+}
+
+pub fn get_migration_sql(previous: &[Table], current: &[Table], dialect: Dialect) -> String {
+
+    let mut statements: Vec<String> = Vec::new();
+
+    let mut is_rebuilt = false;
+
+    for elem in get_ordered_tables(current) {
+        match previous.iter().find(|a| a.name == elem.name) {
+            None => {
+                statements.push(get_create_table_sql(elem, dialect));
+                statements.extend(elem.rows.iter().map(|a| get_insert_sql(elem, a, dialect)));
+                statements.extend(elem.indexes.iter().map(|a| get_create_index_sql(elem, a)));
+            },
+            Some(x) if x == elem => {},
+            Some(x) if dialect == Dialect::Sqlite && is_rebuild_required(x, elem) => {
+                statements.append(&mut get_rebuild_statements(x, elem));
+                statements.append(&mut get_data_statements(x, elem, dialect));
+                statements.extend(elem.indexes.iter().map(|a| get_create_index_sql(elem, a)));
+                is_rebuilt = true;
+            },
+            Some(x) => {
+                let is_changed = |a: &Index| !x.indexes.contains(a);
+
+                statements.extend(x.indexes.iter().filter(|a| !elem.indexes.contains(a)).map(|a| format!("DROP INDEX IF EXISTS {}", quote(&a.name))));
+                statements.append(&mut get_alter_statements(x, elem, dialect));
+                statements.append(&mut get_data_statements(x, elem, dialect));
+                statements.extend(elem.indexes.iter().filter(|a| is_changed(a)).map(|a| get_create_index_sql(elem, a)));
+            }
+        }
+    }
+
+    for elem in previous.iter().filter(|a| !current.iter().any(|b| b.name == a.name)) {
+        statements.push(format!("DROP TABLE {}", quote(&elem.name)));
+    }
+
+    if is_rebuilt {
+        statements.insert(0, String::from("PRAGMA foreign_keys = 0"));
+        statements.push(String::from("PRAGMA foreign_keys = 1"));
+    }
+
+    statements.iter().map(|a| format!("{};\n\n", a)).collect()
+}
+
+fn is_rebuild_required(previous: &Table, current: &Table) -> bool {
+
+    let is_changed = current.columns.iter().any(|a| previous.columns.iter().any(|b| b.name == a.name && b != a));
+
+    let is_added = current.columns.iter().filter(|a| !previous.columns.iter().any(|b| b.name == a.name)).any(|a| a.is_primary_key || a.references.is_some());
+
+    let is_dropped = previous.columns.iter().filter(|a| !current.columns.iter().any(|b| b.name == a.name)).any(|a| a.is_primary_key || a.references.is_some());
+
+    is_changed || is_added || is_dropped
+}
+
+fn get_rebuild_statements(previous: &Table, current: &Table) -> Vec<String> {
+
+    let temporary = quote(&format!("{}_new", current.name));
+
+    let mut targets: Vec<String> = Vec::new();
+
+    let mut values: Vec<String> = Vec::new();
+
+    for elem in current.columns.iter() {
+        let previous_column = previous.columns.iter().find(|a| a.name == elem.name);
+
+        if previous_column.is_none() && (elem.is_nullable || elem.is_primary_key) {
+            continue;
+        }
+
+        targets.push(quote(&elem.name));
+
+        match previous_column {
+            None => values.push(String::from(get_default_value(elem, Dialect::Sqlite))),
+            Some(x) if x.is_nullable && !elem.is_nullable => values.push(format!("COALESCE({}, {})", quote(&elem.name), get_default_value(elem, Dialect::Sqlite))),
+            Some(_) => values.push(quote(&elem.name))
+        }
+    }
+
+    let create = get_create_table_sql(current, Dialect::Sqlite).replacen(&quote(&current.name), &temporary, 1);
+
+    vec![
+        create,
+        format!("INSERT INTO {} ({}) SELECT {} FROM {}", temporary, targets.join(", "), values.join(", "), quote(&previous.name)),
+        format!("DROP TABLE {}", quote(&previous.name)),
+        format!("ALTER TABLE {} RENAME TO {}", temporary, quote(&current.name))
+    ]
+}
+
+fn get_data_statements(previous: &Table, current: &Table, dialect: Dialect) -> Vec<String> {
+
+    let mut statements: Vec<String> = Vec::new();
+
+    let find_row = |rows: &[Vec<(String, String)>], row: &[(String, String)]| {
+        let condition = get_key_condition(current, row, dialect);
+
+        rows.iter().find(|a| condition.is_some() && get_key_condition(current, a, dialect) == condition).cloned()
+    };
+
+    for elem in previous.rows.iter().filter(|a| find_row(&current.rows, a).is_none()) {
+        if let Some(x) = get_key_condition(previous, elem, dialect) {
+            statements.push(format!("DELETE FROM {} WHERE {}", quote(&current.name), x));
+        }
+    }
+
+    for elem in current.rows.iter() {
+        match find_row(&previous.rows, elem) {
+            None => statements.push(get_insert_sql(current, elem, dialect)),
+            Some(x) if x == *elem => {},
+            Some(_) => {
+                let keys: Vec<&String> = current.columns.iter().filter(|a| a.is_primary_key).map(|a| &a.name).collect();
+
+                let assignments: Vec<String> = elem.iter().filter(|a| !keys.contains(&&a.0)).map(|a| format!("{} = {}", quote(&a.0), get_sql_literal(&a.1, dialect))).collect();
+
+                if let (false, Some(x)) = (assignments.is_empty(), get_key_condition(current, elem, dialect)) {
+                    statements.push(format!("UPDATE {} SET {} WHERE {}", quote(&current.name), assignments.join(", "), x));
+                }
+            }
+        }
+    }
+
+    statements
+}
+
+fn get_alter_statements(previous: &Table, current: &Table, dialect: Dialect) -> Vec<String> {
+
+    let table_name = quote(&current.name);
+
+    let mut statements: Vec<String> = Vec::new();
+
+    for elem in previous.columns.iter().filter(|a| !current.columns.iter().any(|b| b.name == a.name)) {
+        statements.push(format!("ALTER TABLE {} DROP COLUMN {}", table_name, quote(&elem.name)));
+    }
+
+    let previous_keys: Vec<&String> = previous.columns.iter().filter(|a| a.is_primary_key).map(|a| &a.name).collect();
+
+    let current_keys: Vec<&String> = current.columns.iter().filter(|a| a.is_primary_key).map(|a| &a.name).collect();
+
+    if previous_keys != current_keys && !previous_keys.is_empty() {
+        statements.push(format!("ALTER TABLE {} DROP CONSTRAINT IF EXISTS {}", table_name, quote(&get_primary_key_name(previous))));
+    }
+
+    for elem in current.columns.iter() {
+        let Some(previous_column) = previous.columns.iter().find(|a| a.name == elem.name) else {
+            let default_value = if elem.is_nullable || elem.is_generated { String::from("") } else { format!(" DEFAULT {}", get_default_value(elem, dialect)) };
+
+            statements.push(format!("ALTER TABLE {} ADD COLUMN {}{}", table_name, get_column_definition(elem, dialect), default_value));
+
+            if let Some(x) = get_foreign_key_definition(current, elem) {
+                statements.push(format!("ALTER TABLE {} ADD {}", table_name, x));
+            }
+
+            continue;
+        };
+
+        let column_name = quote(&elem.name);
+
+        if get_sql_type(previous_column, dialect) != get_sql_type(elem, dialect) {
+            let sql_type = get_sql_type(elem, dialect);
+
+            statements.push(format!("ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{}", table_name, column_name, sql_type, column_name, sql_type));
+        }
+
+        match (previous_column.is_nullable || previous_column.is_primary_key, elem.is_nullable || elem.is_primary_key) {
+            (true, false) => {
+                statements.push(format!("UPDATE {} SET {} = {} WHERE {} IS NULL", table_name, column_name, get_default_value(elem, dialect), column_name));
+                statements.push(format!("ALTER TABLE {} ALTER COLUMN {} SET NOT NULL", table_name, column_name));
+            },
+            (false, true) if !elem.is_primary_key => statements.push(format!("ALTER TABLE {} ALTER COLUMN {} DROP NOT NULL", table_name, column_name)),
+            _ => {}
+        }
+
+        match (previous_column.is_primary_key && previous_column.is_generated, elem.is_primary_key && elem.is_generated) {
+            (false, true) => statements.push(format!("ALTER TABLE {} ALTER COLUMN {} ADD GENERATED BY DEFAULT AS IDENTITY", table_name, column_name)),
+            (true, false) => statements.push(format!("ALTER TABLE {} ALTER COLUMN {} DROP IDENTITY IF EXISTS", table_name, column_name)),
+            _ => {}
+        }
+
+        if previous_column.references != elem.references || previous_column.is_nullable != elem.is_nullable {
+            if let Some(x) = get_foreign_key_name(previous, previous_column) {
+                statements.push(format!("ALTER TABLE {} DROP CONSTRAINT IF EXISTS {}", table_name, quote(&x)));
+            }
+
+            if let Some(x) = get_foreign_key_definition(current, elem) {
+                statements.push(format!("ALTER TABLE {} ADD {}", table_name, x));
+            }
+        }
+    }
+
+    if previous_keys != current_keys && !current_keys.is_empty() {
+        let keys: Vec<String> = current_keys.iter().map(|a| quote(a)).collect();
+
+        statements.push(format!("ALTER TABLE {} ADD CONSTRAINT {} PRIMARY KEY ({})", table_name, quote(&get_primary_key_name(current)), keys.join(", ")));
+    }
+
+    statements
+}
+
+pub fn to_json(tables: &[Table]) -> String {
+
+    let columns = tables.iter().flat_map(|a| a.columns.iter().map(move |b| ColumnEntry {
+        table: a.name.clone(),
+        column: b.name.clone(),
+        type_name: b.type_name.clone(),
+        nullable: b.is_nullable,
+        primary_key: b.is_primary_key,
+        generated: b.is_generated,
+        max_length: b.max_length,
+        references: b.references.as_ref().map(|c| format!("{}.{}", c.0, c.1))
+    })).collect();
+
+    let indexes = tables.iter().flat_map(|a| a.indexes.iter().map(move |b| IndexEntry {
+        table: a.name.clone(),
+        index: b.name.clone(),
+        columns: b.columns.join(","),
+        unique: b.is_unique
+    })).collect();
+
+    let rows = tables.iter().flat_map(|a| a.rows.iter().enumerate().flat_map(move |(b, c)| c.iter().map(move |d| RowEntry {
+        table: a.name.clone(),
+        row: b,
+        column: d.0.clone(),
+        value: d.1.clone()
+    }))).collect();
+
+    let mut json = serde_json::to_string_pretty(&Snapshot { columns, indexes, rows }).unwrap_or_default();
+    json.push('\n');
+
+    json
+}
+
+fn get_table<'a>(tables: &'a mut Vec<Table>, name: &str) -> &'a mut Table {
+
+    let index = match tables.iter().position(|a| a.name == name) {
+        Some(x) => x,
+        None => {
+            tables.push(Table { name: String::from(name), columns: Vec::new(), indexes: Vec::new(), rows: Vec::new() });
+            tables.len() - 1
+        }
+    };
+
+    &mut tables[index]
+}
+
+pub fn from_json(json: &str) -> Result<Vec<Table>, String> {
+
+    let snapshot: Snapshot = serde_json::from_str(json).map_err(|e| e.to_string())?;
+
+    let mut tables: Vec<Table> = Vec::new();
+
+    for elem in snapshot.columns {
+        get_table(&mut tables, &elem.table).columns.push(Column {
+            name: elem.column,
+            type_name: elem.type_name,
+            is_nullable: elem.nullable,
+            is_primary_key: elem.primary_key,
+            is_generated: elem.generated,
+            max_length: elem.max_length,
+            references: elem.references.and_then(|a| a.split_once('.').map(|b| (String::from(b.0), String::from(b.1))))
+        });
+    }
+
+    for elem in snapshot.indexes {
+        get_table(&mut tables, &elem.table).indexes.push(Index { name: elem.index, columns: elem.columns.split(',').map(String::from).collect(), is_unique: elem.unique });
+    }
+
+    for elem in snapshot.rows {
+        let table = get_table(&mut tables, &elem.table);
+
+        if table.rows.len() <= elem.row {
+            table.rows.resize(elem.row + 1, Vec::new());
+        }
+
+        table.rows[elem.row].push((elem.column, elem.value));
+    }
+
+    Ok(tables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, type_name: &str) -> Column {
+        Column { name: String::from(name), type_name: String::from(type_name), is_nullable: false, is_primary_key: false, is_generated: false, max_length: None, references: None }
+    }
+
+    fn items_table() -> Table {
+        let mut id = column("Id", "int");
+        id.is_primary_key = true;
+        id.is_generated = true;
+
+        Table { name: String::from("Items"), columns: vec![id, column("Name", "string")], indexes: Vec::new(), rows: Vec::new() }
+    }
+
+    #[test]
+    fn unchanged_schema_produces_no_migration() {
+        assert_eq!(get_migration_sql(&[items_table()], &[items_table()], Dialect::Sqlite), "");
+    }
+
+    #[test]
+    fn added_nullable_column_is_altered_in_place() {
+        let mut current = items_table();
+        let mut note = column("Note", "string");
+        note.is_nullable = true;
+        current.columns.push(note);
+
+        let sql = get_migration_sql(&[items_table()], &[current], Dialect::Sqlite);
+
+        assert_eq!(sql, "ALTER TABLE \"Items\" ADD COLUMN \"Note\" TEXT;\n\n");
+    }
+
+    #[test]
+    fn added_required_column_gets_a_default_value() {
+        let mut current = items_table();
+        current.columns.push(column("Quantity", "int"));
+
+        let sql = get_migration_sql(&[items_table()], &[current], Dialect::Postgres);
+
+        assert_eq!(sql, "ALTER TABLE \"Items\" ADD COLUMN \"Quantity\" INTEGER NOT NULL DEFAULT 0;\n\n");
+    }
+
+    #[test]
+    fn changed_column_rebuilds_the_table_on_sqlite() {
+        let mut current = items_table();
+        current.columns[1].max_length = Some(50);
+
+        let sql = get_migration_sql(&[items_table()], &[current], Dialect::Sqlite);
+
+        assert!(sql.starts_with("PRAGMA foreign_keys = 0;\n\n"));
+        assert!(sql.contains("CREATE TABLE \"Items_new\""));
+        assert!(sql.contains("INSERT INTO \"Items_new\" (\"Id\", \"Name\") SELECT \"Id\", \"Name\" FROM \"Items\""));
+        assert!(sql.contains("ALTER TABLE \"Items_new\" RENAME TO \"Items\""));
+        assert!(sql.ends_with("PRAGMA foreign_keys = 1;\n\n"));
+    }
+
+    #[test]
+    fn changed_column_is_altered_on_postgres() {
+        let mut current = items_table();
+        current.columns[1].max_length = Some(50);
+
+        let sql = get_migration_sql(&[items_table()], &[current], Dialect::Postgres);
+
+        assert_eq!(sql, "ALTER TABLE \"Items\" ALTER COLUMN \"Name\" TYPE VARCHAR(50) USING \"Name\"::VARCHAR(50);\n\n");
+    }
+
+    #[test]
+    fn added_foreign_key_rebuilds_the_table_on_sqlite() {
+        let mut current = items_table();
+        let mut category = column("CategoryId", "int");
+        category.references = Some((String::from("Categories"), String::from("Id")));
+        current.columns.push(category);
+
+        assert!(is_rebuild_required(&items_table(), &current));
+        assert!(!is_rebuild_required(&items_table(), &items_table()));
+    }
+
+    #[test]
+    fn removed_table_is_dropped() {
+        let sql = get_migration_sql(&[items_table()], &[], Dialect::Sqlite);
+
+        assert_eq!(sql, "DROP TABLE \"Items\";\n\n");
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_json() {
+        let mut items = items_table();
+        items.columns[1].max_length = Some(50);
+        items.indexes.push(Index { name: String::from("IX_Items_Name"), columns: vec![String::from("Name")], is_unique: true });
+        items.rows.push(vec![(String::from("Id"), String::from("1")), (String::from("Name"), String::from("Apple \"Gala\""))]);
+
+        let mut category = column("CategoryId", "int");
+        category.is_nullable = true;
+        category.references = Some((String::from("Categories"), String::from("Id")));
+        items.columns.push(category);
+
+        let tables = vec![items, Table { name: String::from("Categories"), columns: vec![column("Id", "int")], indexes: Vec::new(), rows: Vec::new() }];
+
+        assert_eq!(from_json(&to_json(&tables)), Ok(tables));
+    }
+
+    #[test]
+    fn invalid_snapshot_is_an_error() {
+        assert!(from_json("{ \"columns\": 1 }").is_err());
+    }
+}
@@ -0,0 +1,185 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NameKind {
+    Field,
+    Function,
+    Local,
+    Module,
+    Type,
+    Constant
+}
+
+const KEYWORDS: [&str; 51] = [
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let",
+    "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+    "true", "type", "unsafe", "use", "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final",
+    "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try"
+];
+
+const RESERVED_PATHS: [&str; 4] = ["self", "Self", "super", "crate"];
+
+pub fn get_rust_name(value: &str, kind: NameKind) -> String {
+
+    let name = match kind {
+        NameKind::Type => to_upper_camel_case(value),
+        NameKind::Constant => to_snake_case(value).to_uppercase(),
+        _ => to_snake_case(value)
+    };
+
+    escape_keyword(&name)
+}
+
+pub fn to_snake_case(value: &str) -> String {
+
+    let chars: Vec<char> = value.trim().trim_start_matches('_').chars().collect();
+
+    let mut name = String::from("");
+
+    for (index, c) in chars.iter().enumerate() {
+        if *c == '_' {
+            if !name.is_empty() && !name.ends_with('_') {
+                name.push('_');
+            }
+
+            continue;
+        }
+
+        let is_word_start = index > 0 && c.is_ascii_uppercase() &&
+            (chars[index - 1].is_ascii_lowercase() || chars[index - 1].is_ascii_digit() ||
+            (chars[index - 1].is_ascii_uppercase() && chars.get(index + 1).is_some_and(|a| a.is_ascii_lowercase())));
+
+        if is_word_start && !name.ends_with('_') {
+            name.push('_');
+        }
+
+        name.push(c.to_ascii_lowercase());
+    }
+
+    String::from(name.trim_end_matches('_'))
+}
+
+pub fn to_upper_camel_case(value: &str) -> String {
+
+    let mut name = String::from("");
+
+    for word in value.trim().split('_') {
+        let mut chars = word.chars();
+
+        if let Some(c) = chars.next() {
+            name.push(c.to_ascii_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+
+    name
+}
+
+pub fn to_camel_case(value: &str) -> String {
+
+    let chars: Vec<char> = value.trim().chars().collect();
+
+    let mut name = String::from("");
+
+    let mut is_leading = true;
+
+    for (index, c) in chars.iter().enumerate() {
+        if is_leading && index > 0 && chars.get(index + 1).is_some_and(|a| !a.is_ascii_uppercase()) {
+            is_leading = false;
+        }
+
+        if is_leading && c.is_ascii_uppercase() {
+            name.push(c.to_ascii_lowercase());
+        }
+        else {
+            is_leading = false;
+            name.push(*c);
+        }
+    }
+
+    name
+}
+
+pub fn escape_keyword(name: &str) -> String {
+
+    if RESERVED_PATHS.contains(&name) {
+        return format!("{}_", name);
+    }
+
+    if KEYWORDS.contains(&name) {
+        return format!("r#{}", name);
+    }
+
+    String::from(name)
+}
+
+pub fn get_wire_name(name: &str) -> &str {
+    name.trim_start_matches("r#")
+}
+
+pub fn find_collisions(names: &[String], kind: NameKind) -> Vec<String> {
+
+    let mut converted: Vec<(String, &String)> = names.iter().map(|a| (get_rust_name(a, kind), a)).collect();
+
+    converted.sort();
+    converted.dedup();
+
+    let mut collisions: Vec<String> = Vec::new();
+
+    let mut index = 0;
+
+    while index < converted.len() {
+        let sources: Vec<&str> = converted[index..].iter().take_while(|a| a.0 == converted[index].0).map(|a| a.1.as_str()).collect();
+
+        if sources.len() > 1 {
+            collisions.push(format!("'{}' all convert to '{}'", sources.join("', '"), converted[index].0));
+        }
+
+        index += sources.len();
+    }
+
+    collisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_snake_case_splits_words_and_acronyms() {
+        assert_eq!(to_snake_case("GetItemsAsync"), "get_items_async");
+        assert_eq!(to_snake_case("HTTPRequest"), "http_request");
+        assert_eq!(to_snake_case("Item2Id"), "item2_id");
+        assert_eq!(to_snake_case("_privateField"), "private_field");
+        assert_eq!(to_snake_case("already_snake"), "already_snake");
+    }
+
+    #[test]
+    fn get_rust_name_converts_per_kind() {
+        assert_eq!(get_rust_name("itemCategory", NameKind::Type), "ItemCategory");
+        assert_eq!(get_rust_name("MaxItems", NameKind::Constant), "MAX_ITEMS");
+        assert_eq!(get_rust_name("ItemName", NameKind::Field), "item_name");
+    }
+
+    #[test]
+    fn escape_keyword_uses_raw_identifiers_and_suffixes_reserved_paths() {
+        assert_eq!(get_rust_name("Type", NameKind::Field), "r#type");
+        assert_eq!(get_rust_name("Match", NameKind::Function), "r#match");
+        assert_eq!(escape_keyword("self"), "self_");
+        assert_eq!(escape_keyword("Self"), "Self_");
+        assert_eq!(escape_keyword("item"), "item");
+        assert_eq!(get_wire_name("r#type"), "type");
+    }
+
+    #[test]
+    fn find_collisions_reports_names_that_convert_to_the_same_identifier() {
+        let names = vec![String::from("ItemName"), String::from("itemName"), String::from("Quantity")];
+
+        assert_eq!(find_collisions(&names, NameKind::Field), vec![String::from("'ItemName', 'itemName' all convert to 'item_name'")]);
+    }
+
+    #[test]
+    fn find_collisions_ignores_distinct_and_repeated_names() {
+        let names = vec![String::from("Id"), String::from("Id"), String::from("Name")];
+
+        assert!(find_collisions(&names, NameKind::Field).is_empty());
+    }
+}
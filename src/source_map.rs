@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use syn::spanned::Spanned;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mapping {
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub source: String,
+    pub source_line: usize,
+    pub source_column: usize,
+    pub is_marked: bool
+}
+
+#[derive(Serialize, Deserialize)]
+struct SourceMapFile {
+    file: String,
+    mappings: Vec<Mapping>
+}
+
+struct Node {
+    start: proc_macro2::LineColumn,
+    end: proc_macro2::LineColumn,
+    parent: Option<usize>
+}
+
+pub fn get_marker(file: &str, line: usize, column: usize) -> String {
+    format!("/*@{}:{}:{}*/", file, line, column)
+}
+
+pub fn get_mappings(raw: &str, formatted: &str) -> Vec<Mapping> {
+
+    let (raw_file, formatted_file) = match (syn::parse_file(raw), syn::parse_file(formatted)) {
+        (Ok(x), Ok(y)) => (x, y),
+        _ => return Vec::new()
+    };
+
+    let raw_nodes = get_file_nodes(&raw_file);
+
+    let formatted_nodes = get_file_nodes(&formatted_file);
+
+    let line_offsets = get_line_offsets(raw);
+
+    let mut sources: Vec<Option<(String, usize, usize, bool)>> = Vec::new();
+
+    let mut previous: Option<(String, usize, usize, bool)> = None;
+
+    for elem in raw_nodes.iter() {
+        let offset = get_offset(raw, &line_offsets, &elem.start);
+
+        let source = match find_marker(&raw[..offset]) {
+            Some(x) => Some((x.0, x.1, x.2, true)),
+            None => match elem.parent {
+                Some(x) => sources[x].clone().map(|a| (a.0, a.1, a.2, false)),
+                None => previous.clone().map(|a| (a.0, a.1, a.2, false))
+            }
+        };
+
+        if elem.parent.is_none() {
+            previous = source.clone();
+        }
+
+        sources.push(source);
+    }
+
+    let mut mappings: Vec<Mapping> = Vec::new();
+
+    for (node, source) in formatted_nodes.iter().zip(sources) {
+        if let Some((file, line, column, is_marked)) = source {
+            mappings.retain(|a| a.line != node.start.line || a.column != node.start.column + 1);
+
+            mappings.push(Mapping { line: node.start.line, column: node.start.column + 1, end_line: node.end.line,
+                source: file, source_line: line, source_column: column, is_marked });
+        }
+    }
+
+    mappings
+}
+
+fn get_file_nodes(file: &syn::File) -> Vec<Node> {
+
+    let mut nodes: Vec<Node> = Vec::new();
+
+    for elem in file.items.iter() {
+        add_item_nodes(elem, None, &mut nodes);
+    }
+
+    nodes
+}
+
+fn add_node<T: Spanned>(value: &T, parent: Option<usize>, nodes: &mut Vec<Node>) -> usize {
+
+    let span = value.span();
+
+    nodes.push(Node { start: span.start(), end: span.end(), parent });
+
+    nodes.len() - 1
+}
+
+fn add_item_nodes(item: &syn::Item, parent: Option<usize>, nodes: &mut Vec<Node>) {
+
+    let index = add_node(item, parent, nodes);
+
+    match item {
+        syn::Item::Struct(x) => {
+            for elem in x.fields.iter() {
+                add_node(elem, Some(index), nodes);
+            }
+        },
+        syn::Item::Enum(x) => {
+            for elem in x.variants.iter() {
+                add_node(elem, Some(index), nodes);
+            }
+        },
+        syn::Item::Fn(x) => add_block_nodes(&x.block, index, nodes),
+        syn::Item::Impl(x) => {
+            for elem in x.items.iter() {
+                let child = add_node(elem, Some(index), nodes);
+
+                if let syn::ImplItem::Fn(method) = elem {
+                    add_block_nodes(&method.block, child, nodes);
+                }
+            }
+        },
+        syn::Item::Trait(x) => {
+            for elem in x.items.iter() {
+                let child = add_node(elem, Some(index), nodes);
+
+                if let syn::TraitItem::Fn(syn::TraitItemFn { default: Some(block), .. }) = elem {
+                    add_block_nodes(block, child, nodes);
+                }
+            }
+        },
+        syn::Item::Mod(x) => {
+            for elem in x.content.iter().flat_map(|a| a.1.iter()) {
+                add_item_nodes(elem, Some(index), nodes);
+            }
+        },
+        _ => {}
+    }
+}
+
+fn add_block_nodes(block: &syn::Block, parent: usize, nodes: &mut Vec<Node>) {
+
+    for elem in block.stmts.iter() {
+        let index = add_node(elem, Some(parent), nodes);
+
+        let inner = match elem {
+            syn::Stmt::Expr(x, _) => get_inner_blocks(x),
+            _ => Vec::new()
+        };
+
+        for block in inner {
+            add_block_nodes(block, index, nodes);
+        }
+    }
+}
+
+fn get_inner_blocks(expr: &syn::Expr) -> Vec<&syn::Block> {
+
+    match expr {
+        syn::Expr::Block(x) => vec![&x.block],
+        syn::Expr::Unsafe(x) => vec![&x.block],
+        syn::Expr::Loop(x) => vec![&x.body],
+        syn::Expr::While(x) => vec![&x.body],
+        syn::Expr::ForLoop(x) => vec![&x.body],
+        syn::Expr::If(x) => {
+            let mut blocks = vec![&x.then_branch];
+
+            if let Some((_, y)) = &x.else_branch {
+                blocks.append(&mut get_inner_blocks(y));
+            }
+
+            blocks
+        },
+        _ => Vec::new()
+    }
+}
+
+fn get_line_offsets(text: &str) -> Vec<usize> {
+
+    let mut offsets = vec![0];
+
+    for (index, c) in text.char_indices() {
+        if c == '\n' {
+            offsets.push(index + 1);
+        }
+    }
+
+    offsets
+}
+
+fn get_offset(text: &str, line_offsets: &[usize], position: &proc_macro2::LineColumn) -> usize {
+
+    let start = line_offsets.get(position.line.saturating_sub(1)).copied().unwrap_or(text.len());
+
+    text[start..].char_indices().nth(position.column).map(|a| start + a.0).unwrap_or(text.len())
+}
+
+fn find_marker(text: &str) -> Option<(String, usize, usize)> {
+
+    let text = text.trim_end();
+
+    if !text.ends_with("*/") {
+        return None;
+    }
+
+    let start = text.rfind("/*@")?;
+
+    let mut parts = text[start + 3..text.len() - 2].rsplitn(3, ':');
+
+    let column = parts.next()?.parse().ok()?;
+    let line = parts.next()?.parse().ok()?;
+    let file = parts.next()?;
+
+    Some((String::from(file), line, column))
+}
+
+pub fn add_source_comments(formatted: &str, mappings: &[Mapping]) -> String {
+
+    let mut lines: Vec<String> = formatted.lines().map(String::from).collect();
+
+    let mut marked: Vec<&Mapping> = mappings.iter().filter(|a| a.is_marked).collect();
+
+    marked.sort_by_key(|a| a.line);
+    marked.dedup_by_key(|a| a.line);
+
+    for elem in marked.iter().rev() {
+        let line = &lines[elem.line - 1];
+
+        let indent = &line[..line.len() - line.trim_start().len()];
+
+        let file_name = elem.source.rsplit(['/', '\\']).next().unwrap_or_default();
+
+        lines.insert(elem.line - 1, format!("{}// from {}:{}", indent, file_name, elem.source_line));
+    }
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+
+    text
+}
+
+pub fn to_json(output_file: &str, mappings: &[Mapping]) -> String {
+
+    let file = SourceMapFile { file: String::from(output_file), mappings: mappings.to_vec() };
+
+    serde_json::to_string_pretty(&file).unwrap_or_default()
+}
+
+pub fn from_json(json: &str) -> Result<Vec<Mapping>, String> {
+    serde_json::from_str::<SourceMapFile>(json).map(|a| a.mappings).map_err(|e| e.to_string())
+}
+
+pub fn query(map_file: &str, line: usize) -> Result<Option<Mapping>, String> {
+
+    let json = fs::read_to_string(map_file).map_err(|e| format!("{}: {}", map_file, e))?;
+
+    let mappings = from_json(&json).map_err(|e| format!("{}: {}", map_file, e))?;
+
+    Ok(mappings.into_iter().filter(|a| a.line <= line && line <= a.end_line).max_by_key(|a| (a.line, a.column)))
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use common::{project, transpile, transpile_errors, ITEM_MODEL};
+use csrust::Kind;
+
+#[test]
+fn transpile_returns_the_parsed_model_as_serializable_data() {
+    let model = r#"
+namespace Shop.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+        public string Name { get; set; }
+
+        public string Label()
+        {
+            return Name;
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[], &[]));
+
+    let item = output.types.iter().find(|a| a.name == "Item").expect("Item type");
+
+    assert_eq!(item.kind, Kind::Class);
+    assert_eq!(item.properties.iter().map(|a| (a.name.as_str(), a.type_name.as_str())).collect::<Vec<_>>(), vec![("Id", "int"), ("Name", "string")]);
+
+    let class = output.classes.iter().find(|a| a.class_name == "Item").expect("Item class");
+
+    assert_eq!(class.methods.iter().map(|a| (a.name.as_str(), a.kind)).collect::<Vec<_>>(), vec![("Label", Kind::Method)]);
+
+    let json = serde_json::to_value(&output).expect("serializable output");
+
+    assert_eq!(json["classes"][0]["methods"][0]["name"], "Label");
+}
+
+#[test]
+fn parse_errors_are_returned_as_diagnostics() {
+    let broken = "namespace Shop.Models { public class { }";
+
+    let diagnostics = transpile_errors(&project(&[ITEM_MODEL, broken], &[], &[]));
+
+    assert_eq!(diagnostics.errors.len(), 1);
+    assert!(diagnostics.errors[0].starts_with("Models/Model1.cs:"), "{:?}", diagnostics.errors);
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use common::{assert_contains, assert_not_contains, project, transpile};
+
+const REPOSITORY: &str = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        Item GetOne(int id);
+        void Delete(int id);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        public Item GetOne(int id)
+        {
+            return new Item();
+        }
+
+        public void Delete(int id)
+        {
+        }
+    }
+}
+"#;
+
+#[test]
+fn services_injected_only_through_from_services_resolve_to_the_repository() {
+    let controller = r#"
+using Microsoft.AspNetCore.Mvc;
+using Shop.Models;
+using Shop.Repositories;
+
+namespace Shop.Controllers
+{
+    [ApiController]
+    [Route("v1/[controller]")]
+    public class ItemsController : ControllerBase
+    {
+        [HttpGet("{id}")]
+        public IActionResult Get(int id, [FromServices] IItemRepository repository)
+        {
+            var item = repository.GetOne(id);
+            return new OkObjectResult(item);
+        }
+
+        [HttpDelete("{id}")]
+        public IActionResult Delete(int id, [FromServices] IItemRepository repository)
+        {
+            repository.Delete(id);
+            return new NoContentResult();
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[common::ITEM_MODEL], &[REPOSITORY], &[controller]));
+
+    assert_contains(&output.code, "async fn get(id: i32) -> Result<impl warp::Reply, warp::Rejection> {");
+    assert_contains(&output.code, "let item = ItemRepository::get_one(id).map_err(warp::reject::custom)?;");
+    assert_contains(&output.code, "ItemRepository::delete(id).map_err(warp::reject::custom)?;");
+    assert_not_contains(&output.code, "repository.get_one(id)");
+}
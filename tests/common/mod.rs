@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use csrust::{Diagnostics, Options, Output, Project, SourceFile};
+
+pub const ITEM_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+        public string Name { get; set; }
+    }
+}
+"#;
+
+pub fn source(path: &str, text: &str) -> SourceFile {
+    SourceFile { path: String::from(path), text: String::from(text.trim_start()) }
+}
+
+pub fn project(models: &[&str], repositories: &[&str], controllers: &[&str]) -> Project {
+    Project {
+        models: models.iter().enumerate().map(|(i, a)| source(&format!("Models/Model{}.cs", i), a)).collect(),
+        repositories: repositories.iter().enumerate().map(|(i, a)| source(&format!("Repositories/Repository{}.cs", i), a)).collect(),
+        controllers: controllers.iter().enumerate().map(|(i, a)| source(&format!("Controllers/Controller{}.cs", i), a)).collect(),
+        program: None
+    }
+}
+
+pub fn transpile(project: &Project) -> Output {
+    transpile_with(project, &Options::default())
+}
+
+pub fn transpile_with(project: &Project, options: &Options) -> Output {
+    match csrust::transpile(project, options) {
+        Ok(x) => x,
+        Err(e) => panic!("transpile failed: {:?}", e)
+    }
+}
+
+pub fn transpile_errors(project: &Project) -> Diagnostics {
+    match csrust::transpile(project, &Options::default()) {
+        Ok(x) => panic!("transpile succeeded:\n{}", x.code),
+        Err(e) => e
+    }
+}
+
+pub fn assert_contains(code: &str, expected: &str) {
+    assert!(code.contains(expected), "expected `{}` in:\n{}", expected, code);
+}
+
+pub fn assert_not_contains(code: &str, unexpected: &str) {
+    assert!(!code.contains(unexpected), "unexpected `{}` in:\n{}", unexpected, code);
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{assert_contains, assert_not_contains, project, transpile};
+
+#[test]
+fn constructor_overloads_are_named_by_parameter_and_the_parameterless_one_is_default() {
+    let model = r#"
+namespace Shop.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+        public string Name { get; set; }
+        public int Quantity { get; set; }
+
+        public Item()
+        {
+            Quantity = 1;
+        }
+
+        public Item(string name) : this()
+        {
+            Name = name;
+        }
+
+        public Item(int quantity)
+        {
+            Quantity = quantity;
+        }
+    }
+
+    public class DbContext
+    {
+        public List<Item> Items { get; set; }
+
+        public DbContext()
+        {
+            Items = new List<Item>();
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[], &[]));
+
+    assert_contains(&output.code, "pub fn with_name(name: String) -> Self {\n        Item {\n            name: name,\n            ..Self::default()\n        }");
+    assert_contains(&output.code, "pub fn with_quantity(quantity: i32) -> Self {");
+    assert_contains(&output.code, "impl Default for Item {\n    fn default() -> Self {\n        Item {\n            id: 0,\n            name: String::from(\"\"),\n            quantity: 1,");
+    assert_contains(&output.code, "items: Arc::new(RwLock::new(Vec::new())),");
+    assert_not_contains(&output.code, "fn new0(");
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+const LABEL_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class Label
+    {
+        public string Text { get; set; }
+
+        public override string ToString()
+        {
+            return Text;
+        }
+    }
+}
+"#;
+
+const REPOSITORY: &str = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        void Touch(int id);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        public void Touch(int id)
+        {
+        }
+    }
+}
+"#;
+
+#[test]
+fn handlers_without_a_return_still_close_their_body() {
+    let controller = r#"
+using Microsoft.AspNetCore.Mvc;
+using Shop.Models;
+using Shop.Repositories;
+
+namespace Shop.Controllers
+{
+    [ApiController]
+    [Route("v1/[controller]")]
+    public class ItemsController : ControllerBase
+    {
+        private readonly IItemRepository _repository;
+
+        public ItemsController(IItemRepository repository)
+        {
+            _repository = repository;
+        }
+
+        [HttpPut("{id}")]
+        public void Touch(int id)
+        {
+            _repository.Touch(id);
+        }
+
+        [HttpGet("{id}")]
+        public IActionResult Get(int id)
+        {
+            return new OkResult();
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[common::ITEM_MODEL, LABEL_MODEL], &[REPOSITORY], &[controller]));
+
+    assert!(syn::parse_file(&output.code).is_ok(), "{}", output.code);
+    assert_contains(&output.code, "async fn touch(id: i32) {");
+    assert_contains(&output.code, "#[tokio::main]\nasync fn main() {");
+    assert_contains(&output.code, "impl std::fmt::Display for Label {");
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+const ITEM_MODEL: &str = r#"
+using System.ComponentModel.DataAnnotations;
+
+namespace Shop.Models
+{
+    public class Item
+    {
+        [Key]
+        public int Id { get; set; }
+        public string Name { get; set; }
+        public int Quantity { get; set; }
+    }
+}
+"#;
+
+const CONTEXT_MODEL: &str = r#"
+using Microsoft.EntityFrameworkCore;
+
+namespace Shop.Models
+{
+    public class ShopContext : DbContext
+    {
+        public DbSet<Item> Items { get; set; }
+
+        public ShopContext(DbContextOptions<ShopContext> options) : base(options)
+        {
+        }
+    }
+}
+"#;
+
+const REPOSITORY: &str = r#"
+using Shop.Models;
+using Microsoft.EntityFrameworkCore;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        Task<IEnumerable<Item>> GetItemsAsync();
+        Task AddItemAsync(Item item);
+        Task DeleteAsync(int id);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        private readonly ShopContext _context;
+
+        public ItemRepository(ShopContext context)
+        {
+            _context = context;
+        }
+
+        public async Task<IEnumerable<Item>> GetItemsAsync()
+        {
+            return await _context.Items.ToListAsync();
+        }
+
+        public async Task AddItemAsync(Item item)
+        {
+            _context.Items.Add(item);
+
+            await _context.SaveChangesAsync();
+        }
+
+        public async Task DeleteAsync(int id)
+        {
+            await _context.Items.Where(i => i.Id == id).ExecuteDeleteAsync();
+        }
+    }
+}
+"#;
+
+#[test]
+fn db_contexts_become_sqlite_pools_with_table_accessors() {
+    let output = transpile(&project(&[ITEM_MODEL, CONTEXT_MODEL], &[REPOSITORY], &[]));
+
+    assert_contains(&output.code, "pub struct ShopContext {\n    pub pool: SqlitePool,\n    pub changes: Arc<Mutex<Vec<Change>>>,\n    pub items: DbSet<Item>,\n}");
+    assert_contains(&output.code, "CREATE TABLE IF NOT EXISTS \\\"Items\\\" (\\\"Id\\\" INTEGER PRIMARY KEY AUTOINCREMENT, \\\"Name\\\" TEXT NOT NULL, \\\"Quantity\\\" INTEGER NOT NULL)");
+    assert_contains(&output.code, "pub async fn get_items_async(context: &ShopContext) -> Result<Vec<Item>, Error> {\n        Ok(context.items.to_list().await?)");
+    assert_contains(&output.code, "context.items.add(item)?;\n        context.save_changes().await?;");
+    assert_contains(&output.code, "context.items.execute_delete(\"Id\", \"=\", id).await?;");
+}
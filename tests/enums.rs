@@ -0,0 +1,36 @@
+mod common;
+
+use common::{assert_contains, assert_not_contains, project, source, transpile};
+
+const CATEGORY_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public enum ItemCategory
+    {
+        Produce,
+        Dairy
+    }
+}
+"#;
+
+#[test]
+fn string_enum_converter_in_program_serializes_enums_by_name() {
+    let mut project = project(&[CATEGORY_MODEL], &[], &[]);
+
+    project.program = Some(source("Program.cs", r#"
+builder.Services.AddControllers().AddJsonOptions(options =>
+    options.JsonSerializerOptions.Converters.Add(new JsonStringEnumConverter()));
+"#));
+
+    let output = transpile(&project);
+
+    assert_contains(&output.code, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]");
+    assert_not_contains(&output.code, "Serialize_repr");
+}
+
+#[test]
+fn enums_serialize_as_numbers_without_a_converter() {
+    let output = transpile(&project(&[CATEGORY_MODEL], &[], &[]));
+
+    assert_contains(&output.code, "use serde_repr::{Serialize_repr, Deserialize_repr};");
+}
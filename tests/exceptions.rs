@@ -0,0 +1,184 @@
+mod common;
+
+use common::{ITEM_MODEL, assert_contains, assert_not_contains, project, transpile};
+
+const EXCEPTION_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class ItemNotFoundException : Exception
+    {
+        public ItemNotFoundException(string message) : base(message)
+        {
+
+        }
+    }
+}
+"#;
+
+const REPOSITORY: &str = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        Item GetOne(int id);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        public Item GetOne(int id)
+        {
+            throw new ItemNotFoundException("Item not found");
+        }
+    }
+}
+"#;
+
+#[test]
+fn returns_inside_try_and_catch_produce_the_reply() {
+    let controller = r#"
+using Microsoft.AspNetCore.Mvc;
+using Shop.Models;
+using Shop.Repositories;
+
+namespace Shop.Controllers
+{
+    [ApiController]
+    [Route("v1/[controller]")]
+    public class ItemsController : ControllerBase
+    {
+        private readonly IItemRepository _repository;
+
+        public ItemsController(IItemRepository repository)
+        {
+            _repository = repository;
+        }
+
+        [HttpGet("{id}")]
+        public IActionResult GetOne(int id)
+        {
+            try
+            {
+                var item = _repository.GetOne(id);
+                return new OkObjectResult(item);
+            }
+            catch (ItemNotFoundException)
+            {
+                return new NotFoundResult();
+            }
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[ITEM_MODEL, EXCEPTION_MODEL], &[REPOSITORY], &[controller]));
+
+    assert_contains(&output.code, "let result: Result<warp::reply::Response, Error> = async {");
+    assert_contains(&output.code, "let item = ItemRepository::get_one(id)?;");
+    assert_contains(&output.code, ">(warp::Reply::into_response(warp::reply::json(&item)))");
+    assert_contains(&output.code, "Error::ItemNotFound(_) => {");
+    assert_contains(&output.code, "http::StatusCode::NOT_FOUND");
+    assert_contains(&output.code, "    result.map_err(warp::reject::custom)\n}");
+    assert!(output.warnings.iter().all(|a| !a.contains("return inside a try block")), "{:?}", output.warnings);
+}
+
+#[test]
+fn statements_after_a_returning_try_are_reported() {
+    let controller = r#"
+using Microsoft.AspNetCore.Mvc;
+using Shop.Models;
+using Shop.Repositories;
+
+namespace Shop.Controllers
+{
+    [ApiController]
+    [Route("v1/[controller]")]
+    public class ItemsController : ControllerBase
+    {
+        private readonly IItemRepository _repository;
+
+        public ItemsController(IItemRepository repository)
+        {
+            _repository = repository;
+        }
+
+        [HttpGet("{id}")]
+        public IActionResult GetOne(int id)
+        {
+            try
+            {
+                var item = _repository.GetOne(id);
+                return new OkObjectResult(item);
+            }
+            catch (Exception)
+            {
+                throw;
+            }
+
+            return new NoContentResult();
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[ITEM_MODEL, EXCEPTION_MODEL], &[REPOSITORY], &[controller]));
+
+    assert_not_contains(&output.code, "NO_CONTENT");
+    assert!(output.warnings.iter().any(|a| a.contains("statement after the try block in GetOne is unreachable")), "{:?}", output.warnings);
+}
+
+#[test]
+fn methods_that_throw_return_a_result_and_callers_propagate_it() {
+    let model = r#"
+namespace Bank.Models
+{
+    public class Account
+    {
+        public int Id { get; set; }
+        public decimal Balance { get; set; }
+
+        public void Check()
+        {
+            throw new InsufficientFundsException("Insufficient funds");
+        }
+
+        public decimal Close()
+        {
+            Check();
+            return Balance;
+        }
+
+        public static Account Open()
+        {
+            Verify();
+            return new Account();
+        }
+
+        public static void Verify()
+        {
+            throw new InsufficientFundsException("closed");
+        }
+    }
+}
+"#;
+
+    let exception = EXCEPTION_MODEL.replace("ItemNotFoundException", "InsufficientFundsException");
+
+    let output = transpile(&project(&[model, &exception], &[], &[]));
+
+    assert_contains(&output.code, "pub fn check(&self) -> Result<(), Error> {");
+    assert_contains(&output.code, "pub fn close(&self) -> Result<f64, Error> {");
+    assert_contains(&output.code, "self.check()?;");
+    assert_contains(&output.code, "Ok(self.balance)");
+    assert_contains(&output.code, "pub fn open() -> Result<Account, Error> {");
+    assert_contains(&output.code, "Self::verify()?;");
+    assert_contains(&output.code, "pub fn check(&self) -> Result<(), Error> {\n        return Err(Error::InsufficientFunds(String::from(\"Insufficient funds\")));\n    }");
+}
+
+#[test]
+fn repository_methods_ending_in_a_throw_have_no_trailing_ok() {
+    let output = transpile(&project(&[ITEM_MODEL, EXCEPTION_MODEL], &[REPOSITORY], &[]));
+
+    assert_contains(&output.code, "pub fn get_one(id: i32) -> Result<Item, Error> {\n        return Err(Error::ItemNotFound(String::from(\"Item not found\")));\n    }");
+}
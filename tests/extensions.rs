@@ -0,0 +1,50 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+#[test]
+fn extension_methods_become_traits_and_accept_member_access_arithmetic() {
+    let model = r#"
+namespace Shop.Models
+{
+    public class Shelf
+    {
+        public int Capacity { get; set; }
+    }
+
+    public class Item
+    {
+        public int Id { get; set; }
+        public int Price { get; set; }
+        public int Quantity { get; set; }
+        public int Discount { get; set; }
+        public Shelf Shelf { get; set; }
+
+        public int Net()
+        {
+            return Price * Quantity - Discount;
+        }
+    }
+
+    public static class ItemExtensions
+    {
+        public static int Total(this Item item)
+        {
+            return item.Price * item.Quantity;
+        }
+
+        public static int Free(this Item item)
+        {
+            return item.Shelf.Capacity - item.Quantity;
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[], &[]));
+
+    assert_contains(&output.code, "impl ItemExtensions for Item {");
+    assert_contains(&output.code, "item.price * item.quantity");
+    assert_contains(&output.code, "item.shelf.capacity - item.quantity");
+    assert_contains(&output.code, "self.price * self.quantity - self.discount");
+}
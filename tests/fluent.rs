@@ -0,0 +1,61 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+const SHOP_MODEL: &str = r#"
+using Microsoft.EntityFrameworkCore;
+
+namespace Shop.Models
+{
+    public class Aisle
+    {
+        public int Code { get; set; }
+        public string Name { get; set; }
+        public List<Item> Items { get; set; }
+    }
+
+    public class Item
+    {
+        public int Id { get; set; }
+        public string Name { get; set; }
+        public int AisleCode { get; set; }
+        public Aisle Aisle { get; set; }
+    }
+
+    public class ShopContext : DbContext
+    {
+        public DbSet<Item> Items { get; set; }
+        public DbSet<Aisle> Aisles { get; set; }
+
+        public ShopContext(DbContextOptions<ShopContext> options) : base(options)
+        {
+        }
+
+        protected override void OnModelCreating(ModelBuilder modelBuilder)
+        {
+            modelBuilder.Entity<Aisle>().HasKey(c => c.Code);
+
+            modelBuilder.Entity<Aisle>().Property(c => c.Name).IsRequired().HasMaxLength(50);
+
+            modelBuilder.Entity<Aisle>().HasIndex(c => c.Name).IsUnique();
+
+            modelBuilder.Entity<Item>().HasOne(e => e.Aisle).WithMany(c => c.Items).HasForeignKey(e => e.AisleCode);
+
+            modelBuilder.Entity<Aisle>().HasData(new Aisle { Code = 1, Name = "Produce" });
+        }
+    }
+}
+"#;
+
+#[test]
+fn fluent_configuration_sets_keys_constraints_indexes_relationships_and_seed_rows() {
+    let output = transpile(&project(&[SHOP_MODEL], &[], &[]));
+
+    assert_contains(&output.code, r#"const KEY_NAME: &'static str = "Code";"#);
+    assert_contains(&output.code, r#"\"Name\" TEXT NOT NULL CHECK (length(\"Name\") <= 50)"#);
+    assert_contains(&output.code, r#"CREATE UNIQUE INDEX IF NOT EXISTS \"IX_Aisles_Name\" ON \"Aisles\" (\"Name\")"#);
+    assert_contains(&output.code, r#"CONSTRAINT \"FK_Items_Aisles_AisleCode\" FOREIGN KEY (\"AisleCode\") REFERENCES \"Aisles\" (\"Code\") ON DELETE CASCADE"#);
+    assert_contains(&output.code, r#"INSERT OR IGNORE INTO \"Aisles\" (\"Code\", \"Name\") VALUES (1, 'Produce')"#);
+    assert_contains(&output.code, "pub aisle: Option<Box<Aisle>>,");
+    assert_contains(&output.code, "pub items: Vec<Item>,");
+}
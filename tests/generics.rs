@@ -0,0 +1,66 @@
+mod common;
+
+use common::{assert_contains, project, transpile, transpile_errors};
+
+#[test]
+fn generic_classes_and_methods_become_rust_generics_with_bounds() {
+    let model = r#"
+namespace Shop.Models
+{
+    public interface IEntity
+    {
+        int GetId();
+    }
+
+    public class Item : IEntity
+    {
+        public int Id { get; set; }
+
+        public int GetId()
+        {
+            return Id;
+        }
+    }
+
+    public class Catalog<T> where T : class, IEntity, new()
+    {
+        public List<T> Entries { get; set; }
+        public Dictionary<string, List<Item>> Groups { get; set; }
+
+        public T First<U>(U key) where U : IEntity
+        {
+            return new T();
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[], &[]));
+
+    assert_contains(&output.code, "pub struct Catalog<T> {");
+    assert_contains(&output.code, "pub groups: HashMap<String, Vec<Item>>,");
+    assert_contains(&output.code, "impl<T: IEntity + Default + Clone> Catalog<T> {");
+    assert_contains(&output.code, "pub fn first<U: IEntity + Clone>(&self, key: U) -> T {");
+}
+
+#[test]
+fn interface_methods_without_an_implementation_are_an_error() {
+    let model = r#"
+namespace Shop.Models
+{
+    public interface IEntity
+    {
+        int GetId();
+    }
+
+    public class Widget : IEntity
+    {
+        public int Id { get; set; }
+    }
+}
+"#;
+
+    let errors = transpile_errors(&project(&[model], &[], &[])).errors;
+
+    assert_eq!(errors, vec![String::from("Models/Model0.cs: Widget does not implement interface method GetId")]);
+}
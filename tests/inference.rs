@@ -0,0 +1,91 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+const ITEM_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+        public int Price { get; set; }
+        public int Quantity { get; set; }
+
+        public int Total()
+        {
+            return Price * Quantity;
+        }
+    }
+}
+"#;
+
+const CONTEXT_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class DbContext
+    {
+        public List<Item> Items { get; set; }
+
+        public DbContext()
+        {
+            Items = new List<Item>();
+        }
+    }
+}
+"#;
+
+const BASKET_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class Basket
+    {
+        public List<Item> Lines { get; set; }
+
+        public int Size()
+        {
+            var items = Lines;
+            return items.Count;
+        }
+    }
+}
+"#;
+
+#[test]
+fn var_locals_take_the_type_of_member_access_and_method_calls() {
+    let repository = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        string Describe(Item item);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        private readonly DbContext _db;
+
+        public ItemRepository(DbContext db)
+        {
+            _db = db;
+        }
+
+        public string Describe(Item item)
+        {
+            var total = item.Total();
+            var again = total;
+            var items = _db.Items;
+            return $"{again} of {items.Count}";
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[ITEM_MODEL, CONTEXT_MODEL, BASKET_MODEL], &[repository], &[]));
+
+    assert_contains(&output.code, "let again: i32 = total;");
+    assert_contains(&output.code, "let items = db.items.read().clone();");
+    assert_contains(&output.code, "Ok(format!(\"{} of {}\", again, items.len() as i32))");
+    assert_contains(&output.code, "let items = self.lines.read().clone();\n        items.len() as i32");
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+#[test]
+fn object_and_collection_initializers_become_struct_literals_and_vectors() {
+    let model = r#"
+namespace Shop.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+        public string Name { get; set; }
+        public int Quantity { get; set; }
+    }
+}
+"#;
+
+    let repository = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        List<Item> Samples();
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        public List<Item> Samples()
+        {
+            Item a = new() { Name = "Bread" };
+            var b = new Item { Name = "Milk", Quantity = 2 };
+            var list = new List<Item> { a, b };
+            List<Item> more = [a, b];
+            return list;
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[repository], &[]));
+
+    assert_contains(&output.code, "let a: Item = Item {\n            name: String::from(\"Bread\"),\n            ..Default::default()\n        };");
+    assert_contains(&output.code, "let b: Item = Item {\n            name: String::from(\"Milk\"),\n            quantity: 2,\n            ..Default::default()\n        };");
+    assert_contains(&output.code, "let list = vec![a.clone(), b.clone()];");
+    assert_contains(&output.code, "let more = vec![a, b];");
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{assert_contains, assert_not_contains, project, transpile};
+
+const ITEM_MODEL: &str = r#"
+using System.ComponentModel.DataAnnotations;
+
+namespace Shop.Models
+{
+    public class Item
+    {
+        [Key]
+        public int Id { get; set; }
+        public string Name { get; set; }
+    }
+}
+"#;
+
+const ERROR_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class Error
+    {
+        public int Code { get; set; }
+    }
+}
+"#;
+
+const CONTEXT_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class DbContext
+    {
+        public List<Item> Items { get; set; }
+
+        public DbContext()
+        {
+            Items = new List<Item>();
+        }
+    }
+}
+"#;
+
+const REPOSITORY: &str = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        void AddItem(Item item);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        private static readonly DbContext _context = new DbContext();
+
+        public void AddItem(Item item)
+        {
+            _context.Items.Add(item);
+        }
+    }
+}
+"#;
+
+#[test]
+fn key_properties_implement_the_entity_trait() {
+    let output = transpile(&project(&[ITEM_MODEL, CONTEXT_MODEL], &[REPOSITORY], &[]));
+
+    assert_contains(&output.code, "impl Entity for Item {");
+    assert_contains(&output.code, "type Key = i32;");
+    assert_not_contains(&output.code, "pub mod csrust_rt");
+}
+
+#[test]
+fn models_named_like_support_types_move_the_support_code_into_a_module() {
+    let output = transpile(&project(&[ITEM_MODEL, ERROR_MODEL, CONTEXT_MODEL], &[REPOSITORY], &[]));
+
+    assert_contains(&output.code, "pub mod csrust_rt {");
+    assert_contains(&output.code, "use csrust_rt::*;");
+    assert_contains(&output.code, "pub struct Error {");
+    assert_contains(&output.code, "pub fn add_item(item: Item) -> Result<(), csrust_rt::Error> {");
+    assert!(output.warnings.iter().any(|a| a.contains("Error shares its name")), "{:?}", output.warnings);
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+const ACCOUNT_MODEL: &str = r#"
+namespace Bank.Models
+{
+    public class Account : IDepositable
+    {
+        public int Id { get; set; }
+        public decimal Balance { get; set; }
+
+        public void Deposit(decimal amount)
+        {
+            Balance = amount;
+        }
+
+        public void Reset()
+        {
+            Deposit(0.5);
+        }
+
+        public virtual void Freeze()
+        {
+            Id = 0;
+        }
+
+        public decimal Total()
+        {
+            return Balance;
+        }
+
+        public void Transfer(Account other)
+        {
+            other.Deposit(5.5);
+        }
+    }
+}
+"#;
+
+const DEPOSITABLE_MODEL: &str = r#"
+namespace Bank.Models
+{
+    public interface IDepositable
+    {
+        void Deposit(decimal amount);
+    }
+}
+"#;
+
+#[test]
+fn methods_that_assign_to_fields_take_self_mutably() {
+    let output = transpile(&project(&[ACCOUNT_MODEL, DEPOSITABLE_MODEL], &[], &[]));
+
+    assert_contains(&output.code, "pub fn deposit(&mut self, amount: f64) {");
+    assert_contains(&output.code, "pub fn reset(&mut self) {");
+    assert_contains(&output.code, "pub fn total(&self) -> f64 {");
+    assert_contains(&output.code, "pub fn transfer(&self, mut other: Account) {");
+    assert_contains(&output.code, "fn as_account_mut(&mut self) -> &mut Account;");
+    assert_contains(&output.code, "self.as_account_mut().freeze()");
+    assert_contains(&output.code, "    fn deposit(&mut self, amount: f64);");
+}
+
+#[test]
+fn callers_bind_the_receiver_of_a_mutating_method_mutably() {
+    let repository = r#"
+using Bank.Models;
+
+namespace Bank.Repositories
+{
+    public interface IAccountRepository
+    {
+        Account GetOne(int id);
+    }
+
+    public class AccountRepository : IAccountRepository
+    {
+        public Account GetOne(int id)
+        {
+            return new Account();
+        }
+    }
+}
+"#;
+
+    let controller = r#"
+using Microsoft.AspNetCore.Mvc;
+using Bank.Models;
+using Bank.Repositories;
+
+namespace Bank.Controllers
+{
+    [ApiController]
+    [Route("v1/[controller]")]
+    public class AccountsController : ControllerBase
+    {
+        private readonly IAccountRepository _repository;
+
+        public AccountsController(IAccountRepository repository)
+        {
+            _repository = repository;
+        }
+
+        [HttpGet("{id}")]
+        public IActionResult Get(int id)
+        {
+            var account = _repository.GetOne(id);
+            account.Deposit(5.5);
+            return new OkObjectResult(account);
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[ACCOUNT_MODEL, DEPOSITABLE_MODEL], &[repository], &[controller]));
+
+    assert_contains(&output.code, "let mut account = AccountRepository::get_one(id)");
+    assert_contains(&output.code, "account.deposit(5.5);");
+}
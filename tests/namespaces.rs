@@ -0,0 +1,58 @@
+mod common;
+
+use common::{assert_contains, project, transpile, transpile_errors};
+
+#[test]
+fn file_scoped_namespaces_emit_every_class_and_nest_inner_types_in_a_module() {
+    let model = r#"
+namespace Shop.Models;
+
+public class Shelf
+{
+    public int Id { get; set; }
+    public Slot First { get; set; }
+
+    public class Slot
+    {
+        public int Position { get; set; }
+    }
+}
+
+public class Aisle
+{
+    public int Number { get; set; }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[], &[]));
+
+    assert_contains(&output.code, "pub struct Shelf {");
+    assert_contains(&output.code, "pub mod shelf {");
+    assert_contains(&output.code, "pub struct Aisle {");
+}
+
+#[test]
+fn types_with_the_same_name_in_different_namespaces_are_reported_with_both_files() {
+    let first = r#"
+namespace One.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+    }
+}
+"#;
+
+    let second = r#"
+namespace Two.Models;
+
+public class Item
+{
+    public string Name { get; set; }
+}
+"#;
+
+    let errors = transpile_errors(&project(&[first, second], &[], &[])).errors;
+
+    assert_eq!(errors, vec![String::from("Models/Model1.cs: Two.Models.Item translates to the Rust type Item, which One.Models.Item in Models/Model0.cs already declares; rename one of them")]);
+}
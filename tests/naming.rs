@@ -0,0 +1,42 @@
+mod common;
+
+use common::{assert_contains, project, transpile, transpile_errors};
+
+#[test]
+fn members_are_converted_to_snake_case_and_keywords_are_escaped() {
+    let model = r#"
+namespace Shop.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+        public string Type { get; set; }
+        public string ItemCode { get; set; }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[], &[]));
+
+    assert_contains(&output.code, "pub r#type: String,");
+    assert_contains(&output.code, "#[serde(rename = \"itemCode\")]\n    pub item_code: String,");
+}
+
+#[test]
+fn members_that_convert_to_the_same_name_are_an_error() {
+    let model = r#"
+namespace Shop.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+        public string ItemCode { get; set; }
+        public string itemCode { get; set; }
+    }
+}
+"#;
+
+    let errors = transpile_errors(&project(&[model], &[], &[])).errors;
+
+    assert_eq!(errors, vec![String::from("Models/Model0.cs: Item: field names 'ItemCode', 'itemCode' all convert to 'item_code'; rename one of them")]);
+}
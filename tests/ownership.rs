@@ -0,0 +1,91 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+const COUNTER_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class Counter
+    {
+        public int Count { get; set; }
+        public string Label { get; set; }
+        public string Previous { get; set; }
+
+        public void Add(int amount)
+        {
+            Count = Count + amount;
+        }
+
+        public void Rename(string label)
+        {
+            Previous = Label;
+            Label = label;
+        }
+    }
+}
+"#;
+
+const CONTEXT_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class DbContext
+    {
+        public List<Item> Items { get; set; }
+
+        public DbContext()
+        {
+            Items = new List<Item>();
+        }
+    }
+}
+"#;
+
+const REPOSITORY: &str = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        string AddItem(Item item);
+        int AddPair(Item a, Item b);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        private static readonly DbContext _context = new DbContext();
+
+        public string AddItem(Item item)
+        {
+            string name = item.Name;
+            _context.Items.Add(item);
+            return name;
+        }
+
+        public int AddPair(Item a, Item b)
+        {
+            var list = new List<Item> { a, b };
+            List<Item> more = [a, b];
+            return 0;
+        }
+    }
+}
+"#;
+
+#[test]
+fn member_assignments_resolve_members_on_the_right_hand_side() {
+    let output = transpile(&project(&[COUNTER_MODEL], &[], &[]));
+
+    assert_contains(&output.code, "self.count = self.count + amount;");
+    assert_contains(&output.code, "self.previous = self.label.clone();");
+}
+
+#[test]
+fn values_read_again_later_are_cloned_instead_of_moved() {
+    let output = transpile(&project(&[common::ITEM_MODEL, CONTEXT_MODEL], &[REPOSITORY], &[]));
+
+    assert_contains(&output.code, "let name: String = item.name.clone();");
+    assert_contains(&output.code, "CONTEXT.items.insert(item)?;");
+    assert_contains(&output.code, "let list = vec![a.clone(), b.clone()];");
+    assert_contains(&output.code, "let more = vec![a, b];");
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+const BASKET_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class Basket
+    {
+        public List<Item> Items { get; set; }
+        public string Label { get; set; }
+
+        public int Size()
+        {
+            return Items.Count;
+        }
+
+        public int LabelLength()
+        {
+            return Label.Length;
+        }
+    }
+}
+"#;
+
+const POINT_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public record Point(int X, int Y);
+}
+"#;
+
+#[test]
+fn positional_records_derive_value_equality() {
+    let output = transpile(&project(&[POINT_MODEL], &[], &[]));
+
+    assert_contains(&output.code, "#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq, Eq, Hash)]\npub struct Point {");
+    assert_contains(&output.code, "pub fn with_x_and_y(x: i32, y: i32) -> Self {");
+}
+
+#[test]
+fn count_and_length_read_the_length_through_the_list_lock() {
+    let repository = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        int CountPair(Item a, Item b);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        public int CountPair(Item a, Item b)
+        {
+            List<Item> more = [a, b];
+            return more.Count;
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[common::ITEM_MODEL, BASKET_MODEL], &[repository], &[]));
+
+    assert_contains(&output.code, "self.items.read().len() as i32");
+    assert_contains(&output.code, "self.label.len() as i32");
+    assert_contains(&output.code, "Ok(more.len() as i32)");
+}
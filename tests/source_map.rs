@@ -0,0 +1,66 @@
+mod common;
+
+use common::{assert_contains, project, transpile_with};
+use csrust::Options;
+
+const REPOSITORY: &str = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        Item GetOne(int id);
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        public Item GetOne(int id)
+        {
+            return new Item();
+        }
+    }
+}
+"#;
+
+const CONTROLLER: &str = r#"
+using Microsoft.AspNetCore.Mvc;
+using Shop.Models;
+using Shop.Repositories;
+
+namespace Shop.Controllers
+{
+    [ApiController]
+    [Route("v1/[controller]")]
+    public class ItemsController : ControllerBase
+    {
+        private readonly IItemRepository _repository;
+
+        public ItemsController(IItemRepository repository)
+        {
+            _repository = repository;
+        }
+
+        [HttpGet("{id}")]
+        public IActionResult Get(int id)
+        {
+            var item = _repository.GetOne(id);
+            return new OkObjectResult(item);
+        }
+    }
+}
+"#;
+
+#[test]
+fn handler_statements_map_back_to_their_csharp_lines() {
+    let options = Options { source_map: true, source_comments: true, ..Options::default() };
+
+    let output = transpile_with(&project(&[common::ITEM_MODEL], &[REPOSITORY], &[CONTROLLER]), &options);
+
+    let line = output.code.lines().position(|a| a.contains("let item = ItemRepository::get_one(id)")).expect("handler statement") + 1;
+
+    let mapping = output.source_map.iter().filter(|a| a.line <= line && line <= a.end_line).max_by_key(|a| (a.line, a.column)).expect("mapping");
+
+    assert_eq!((mapping.source.as_str(), mapping.source_line), ("Controllers/Controller0.cs", 21));
+    assert_contains(&output.code, "// from Controller0.cs:21\n    let item = ItemRepository::get_one(id)");
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+const CONTEXT_MODEL: &str = r#"
+namespace Shop.Models
+{
+    public class DbContext
+    {
+        public List<Item> Items { get; set; }
+
+        public DbContext()
+        {
+            Items = new List<Item>();
+        }
+    }
+}
+"#;
+
+const REPOSITORY: &str = r#"
+using Shop.Models;
+
+namespace Shop.Repositories
+{
+    public interface IItemRepository
+    {
+        List<Item> GetAll();
+        int Next();
+    }
+
+    public class ItemRepository : IItemRepository
+    {
+        private static readonly DbContext _context = new DbContext();
+
+        private static int _identifier = 0;
+
+        private static List<string> _names = new List<string>();
+
+        public List<Item> GetAll()
+        {
+            var items = _context.Items;
+            var found = _context.Items.Find(1);
+            List<string> names = _names;
+            return items;
+        }
+
+        public int Next()
+        {
+            var current = _identifier;
+            return current;
+        }
+    }
+}
+"#;
+
+#[test]
+fn local_initializers_read_static_fields_through_the_global_state() {
+    let output = transpile(&project(&[common::ITEM_MODEL, CONTEXT_MODEL], &[REPOSITORY], &[]));
+
+    assert_contains(&output.code, "let items = CONTEXT.items.read().clone();");
+    assert_contains(&output.code, "let found = CONTEXT.items.find(&1)?;");
+    assert_contains(&output.code, "let names = NAMES.lock().clone();");
+    assert_contains(&output.code, "let current = IDENTIFIER.load(Ordering::SeqCst);");
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{assert_contains, assert_not_contains, project, transpile, transpile_with};
+use csrust::{Options, Storage};
+
+const ITEM_MODEL: &str = r#"
+using System.ComponentModel.DataAnnotations;
+
+namespace Shop.Models
+{
+    public class Item
+    {
+        [Key]
+        public int Id { get; set; }
+        public string Name { get; set; }
+    }
+
+    public class DbContext
+    {
+        public List<Item> Items { get; set; }
+
+        public DbContext()
+        {
+            Items = new List<Item>();
+        }
+    }
+}
+"#;
+
+#[test]
+fn memory_storage_keeps_entities_in_a_locked_vector() {
+    let output = transpile(&project(&[ITEM_MODEL], &[], &[]));
+
+    assert_contains(&output.code, "pub items: Arc<RwLock<Vec<Item>>>,");
+    assert_not_contains(&output.code, "IndexedStore");
+}
+
+#[test]
+fn selected_storage_backends_replace_the_entity_store() {
+    for (storage, store) in [(Storage::Indexed, "IndexedStore"), (Storage::Json, "JsonStore"), (Storage::Sqlite, "SqliteStore")] {
+        let output = transpile_with(&project(&[ITEM_MODEL], &[], &[]), &Options { storage, ..Options::default() });
+
+        assert_contains(&output.code, &format!("pub items: Arc<{}<Item>>,", store));
+        assert_contains(&output.code, &format!("items: Arc::new({}::from(Vec::new())),", store));
+        assert_contains(&output.code, &format!("EntityStore<T> for {}<T>", store));
+    }
+}
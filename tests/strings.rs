@@ -0,0 +1,45 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+#[test]
+fn string_members_map_to_rust_string_methods_on_self() {
+    let model = r#"
+namespace Shop.Models
+{
+    public class Person
+    {
+        public string Name { get; set; }
+
+        public string Greet()
+        {
+            return $"Hello {Name}";
+        }
+
+        public int Parts()
+        {
+            var parts = Name.Split(' ');
+            return parts.Length;
+        }
+
+        public int Find(string input)
+        {
+            return input.IndexOf("a");
+        }
+
+        public int Size(string input)
+        {
+            return input.Length;
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[], &[]));
+
+    assert_contains(&output.code, "format!(\"Hello {}\", self.name)");
+    assert_contains(&output.code, "let parts = self.name.split(' ').map(String::from).collect::<Vec<String>>();");
+    assert_contains(&output.code, "input.find(\"a\").map_or(-1, |a| a as i32)");
+    assert_contains(&output.code, "input.len() as i32");
+    assert!(output.warnings.iter().any(|a| a.contains("IndexOf counts UTF-16 code units")), "{:?}", output.warnings);
+}
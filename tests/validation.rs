@@ -0,0 +1,53 @@
+mod common;
+
+use common::{assert_contains, project, transpile};
+
+#[test]
+fn data_annotations_validate_request_bodies_before_the_handler() {
+    let model = r#"
+using System.ComponentModel.DataAnnotations;
+
+namespace Shop.Models
+{
+    public class Item
+    {
+        public int Id { get; set; }
+
+        [Required]
+        [StringLength(20, MinimumLength = 2)]
+        public string Name { get; set; }
+
+        [Range(1, 10)]
+        public int Quantity { get; set; }
+    }
+}
+"#;
+
+    let controller = r#"
+using Microsoft.AspNetCore.Mvc;
+using Shop.Models;
+
+namespace Shop.Controllers
+{
+    [ApiController]
+    [Route("v1/[controller]")]
+    public class ItemsController : ControllerBase
+    {
+        [HttpPost]
+        public IActionResult Create([FromBody] Item item)
+        {
+            return new OkObjectResult(item);
+        }
+    }
+}
+"#;
+
+    let output = transpile(&project(&[model], &[], &[controller]));
+
+    assert_contains(&output.code, "impl Validate for Item {");
+    assert_contains(&output.code, ".push(String::from(\"The Name field is required.\"));");
+    assert_contains(&output.code, "if self.name.chars().count() < 2 || self.name.chars().count() > 20 {");
+    assert_contains(&output.code, "if !(1..=10).contains(&self.quantity) {");
+    assert_contains(&output.code, ".and(warp::body::json::<Item>().and_then(validate_request::<Item>))");
+    assert_contains(&output.code, "if let Some(errors) = err.find::<ValidationErrors>() {");
+}